@@ -0,0 +1,286 @@
+use {
+    crate::{
+        EtherscanClient, EtherscanError, EtherscanRequest, Implementation, SelectorLookup,
+        DEFAULT_BATCH_CONCURRENCY,
+    },
+    ethabi::{ethereum_types::U256, Contract},
+    futures::{stream, StreamExt},
+    std::{
+        collections::HashMap,
+        error::Error,
+        fmt,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    },
+};
+
+pub fn is_unverified_error(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    match EtherscanError::find(error) {
+        Some(EtherscanError::Api { result, .. }) => result.to_lowercase().contains("not verified"),
+        _ => false,
+    }
+}
+
+impl EtherscanClient {
+    pub async fn contract_abi(
+        &self,
+        contract_address: U256,
+    ) -> Result<Contract, Box<dyn Error + Send + Sync>> {
+        let abi = match self
+            .fetch::<String>(EtherscanRequest::contract_get_abi(contract_address))
+            .await
+        {
+            Ok(abi) => abi,
+            Err(e) if self.sourcify_url().is_some() && is_unverified_error(&*e) => {
+                match self.sourcify_source(contract_address).await? {
+                    Some(source) => source.abi,
+                    None => return Err(e),
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(Contract::load(abi.as_bytes())?)
+    }
+
+    #[inline]
+    pub async fn fetch_abis<I>(
+        &self,
+        cache: &AbiCache,
+        addresses: I,
+    ) -> HashMap<U256, Result<Arc<Contract>, Box<dyn Error + Send + Sync>>>
+    where
+        I: IntoIterator<Item = U256>,
+    {
+        cache.fetch_abis(addresses, DEFAULT_BATCH_CONCURRENCY).await
+    }
+}
+
+fn unverified(address: U256) -> Box<dyn Error + Send + Sync> {
+    Box::new(EtherscanError::Api {
+        message: "NOTOK".to_string(),
+        result: format!("Contract source code not verified for {address:#x}"),
+    })
+}
+
+fn merge(mut implementation: Contract, proxy: &Contract) -> Contract {
+    for (name, functions) in &proxy.functions {
+        implementation
+            .functions
+            .entry(name.clone())
+            .or_insert_with(|| functions.clone());
+    }
+
+    for (name, events) in &proxy.events {
+        implementation
+            .events
+            .entry(name.clone())
+            .or_insert_with(|| events.clone());
+    }
+
+    for (name, errors) in &proxy.errors {
+        implementation
+            .errors
+            .entry(name.clone())
+            .or_insert_with(|| errors.clone());
+    }
+
+    implementation.receive |= proxy.receive;
+    implementation.fallback |= proxy.fallback;
+    implementation
+}
+
+#[derive(Clone)]
+enum AbiEntry {
+    Verified(Arc<Contract>),
+    Unverified(Instant),
+}
+
+pub struct AbiCache {
+    client: EtherscanClient,
+    unverified_ttl: Duration,
+    entries: Mutex<HashMap<(Option<u64>, U256), AbiEntry>>,
+    selector_lookup: Option<SelectorLookup>,
+    resolve_proxies: bool,
+}
+
+impl fmt::Debug for AbiCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbiCache")
+            .field("client", &self.client)
+            .field("unverified_ttl", &self.unverified_ttl)
+            .field("resolve_proxies", &self.resolve_proxies)
+            .field("entries", &self.len())
+            .finish()
+    }
+}
+
+impl AbiCache {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            unverified_ttl: Duration::from_secs(60 * 60),
+            entries: Mutex::default(),
+            selector_lookup: None,
+            resolve_proxies: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_unverified_ttl(mut self, unverified_ttl: Duration) -> Self {
+        self.unverified_ttl = unverified_ttl;
+        self
+    }
+
+    #[inline]
+    pub fn with_selector_lookup(mut self, selector_lookup: SelectorLookup) -> Self {
+        self.selector_lookup = Some(selector_lookup);
+        self
+    }
+
+    #[inline]
+    pub fn with_proxy_resolution(mut self, resolve_proxies: bool) -> Self {
+        self.resolve_proxies = resolve_proxies;
+        self
+    }
+
+    #[inline]
+    pub fn resolves_proxies(&self) -> bool {
+        self.resolve_proxies
+    }
+
+    #[inline]
+    pub fn selector_lookup(&self) -> Option<&SelectorLookup> {
+        self.selector_lookup.as_ref()
+    }
+
+    #[inline]
+    pub fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn key(&self, address: U256) -> (Option<u64>, U256) {
+        (self.client.chainid(), address)
+    }
+
+    pub fn cached(&self, address: U256) -> Option<Option<Arc<Contract>>> {
+        match self.entries.lock().unwrap().get(&self.key(address))? {
+            AbiEntry::Verified(contract) => Some(Some(contract.clone())),
+            AbiEntry::Unverified(at) if at.elapsed() < self.unverified_ttl => Some(None),
+            AbiEntry::Unverified(_) => None,
+        }
+    }
+
+    pub async fn get(
+        &self,
+        address: U256,
+    ) -> Result<Option<Arc<Contract>>, Box<dyn Error + Send + Sync>> {
+        if let Some(entry) = self.cached(address) {
+            return Ok(entry);
+        }
+
+        let (entry, resolved) = match self.load(address).await {
+            Ok((contract, resolved)) => (AbiEntry::Verified(Arc::new(contract)), resolved),
+            Err(e) if is_unverified_error(&*e) => (AbiEntry::Unverified(Instant::now()), true),
+            Err(e) => return Err(e),
+        };
+
+        if resolved {
+            self.entries
+                .lock()
+                .unwrap()
+                .insert(self.key(address), entry.clone());
+        }
+
+        Ok(match entry {
+            AbiEntry::Verified(contract) => Some(contract),
+            AbiEntry::Unverified(_) => None,
+        })
+    }
+
+    async fn load(&self, address: U256) -> Result<(Contract, bool), Box<dyn Error + Send + Sync>> {
+        if !self.resolve_proxies {
+            return Ok((self.client.contract_abi(address).await?, true));
+        }
+
+        let source = self.client.contract_source(address).await?;
+
+        if !source.is_verified() {
+            return Ok((self.client.contract_abi(address).await?, true));
+        }
+
+        let contract = Contract::load(source.abi.as_bytes())?;
+
+        if !source.is_proxy() {
+            return Ok((contract, true));
+        }
+
+        match self.client.implementation_of(address, &source).await {
+            Ok(Some(Implementation { abi: Some(abi), .. })) => Ok((merge(abi, &contract), true)),
+            Ok(_) => Ok((contract, true)),
+            Err(_e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    address = %format!("{address:#x}"),
+                    error = %_e,
+                    "proxy implementation resolution failed; using the proxy ABI"
+                );
+
+                Ok((contract, false))
+            }
+        }
+    }
+
+    pub async fn fetch_abis<I>(
+        &self,
+        addresses: I,
+        concurrency: usize,
+    ) -> HashMap<U256, Result<Arc<Contract>, Box<dyn Error + Send + Sync>>>
+    where
+        I: IntoIterator<Item = U256>,
+    {
+        stream::iter(addresses)
+            .map(|address| async move {
+                let result = match self.get(address).await {
+                    Ok(Some(contract)) => Ok(contract),
+                    Ok(None) => Err(unverified(address)),
+                    Err(e) => Err(e),
+                };
+
+                (address, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    #[inline]
+    pub fn insert(&self, address: U256, contract: Contract) {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(self.key(address), AbiEntry::Verified(Arc::new(contract)));
+    }
+
+    #[inline]
+    pub fn invalidate(&self, address: U256) {
+        self.entries.lock().unwrap().remove(&self.key(address));
+    }
+
+    #[inline]
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
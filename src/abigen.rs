@@ -0,0 +1,92 @@
+use {
+    crate::{interface::identifier, source::write_file, EtherscanClient},
+    ethabi::ethereum_types::U256,
+    std::{error::Error, path::Path},
+};
+
+#[derive(Clone, Debug)]
+pub struct Abigen {
+    client: EtherscanClient,
+    address: U256,
+    name: Option<String>,
+    sol_path: String,
+    rpc: bool,
+}
+
+impl Abigen {
+    #[inline]
+    pub fn new(client: EtherscanClient, address: U256) -> Self {
+        Self {
+            client,
+            address,
+            name: None,
+            sol_path: "alloy::sol".into(),
+            rpc: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_sol_path<S: Into<String>>(mut self, sol_path: S) -> Self {
+        self.sol_path = sol_path.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_rpc(mut self, rpc: bool) -> Self {
+        self.rpc = rpc;
+        self
+    }
+
+    pub async fn generate(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let source = self.client.contract_source(self.address).await?;
+
+        if !source.is_verified() {
+            return Err(format!("contract {:#x} is not verified", self.address).into());
+        }
+
+        let name = match &self.name {
+            Some(name) => name.clone(),
+            None => identifier(&source.contract_name)
+                .ok_or_else(|| format!("no contract name for {:#x}", self.address))?,
+        };
+
+        let abi = serde_json::to_string(&serde_json::from_str::<serde_json::Value>(&source.abi)?)?;
+
+        let mut fence = String::from("#");
+        while abi.contains(&format!("\"{fence}")) {
+            fence.push('#');
+        }
+
+        let mut attributes = String::from("    #[allow(missing_docs)]\n");
+        if self.rpc {
+            attributes.push_str("    #[sol(rpc)]\n");
+        }
+
+        Ok(format!(
+            "// Generated from the verified ABI of {:#x}. Do not edit.\n\n{}!(\n{attributes}    {name},\n    r{fence}\"{abi}\"{fence}\n);\n",
+            self.address, self.sol_path,
+        ))
+    }
+
+    pub async fn write_to<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let contents = self.generate().await?;
+        write_file(path.as_ref(), contents.into_bytes()).await?;
+        Ok(())
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn abigen(&self, address: U256) -> Abigen {
+        Abigen::new(self.clone(), address)
+    }
+}
@@ -0,0 +1,358 @@
+use {
+    crate::{EtherscanBlockType, EtherscanClient, EtherscanRequest, EtherscanSort, EtherscanTag},
+    chrono::{DateTime, Utc},
+    ethabi::ethereum_types::U256,
+    futures::TryStreamExt,
+    serde::{Deserialize, Serialize},
+    std::error::Error,
+};
+
+pub const BALANCE_MULTI_LIMIT: usize = 20;
+
+const RANGE_PAGE_SIZE: u64 = 1_000;
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AccountSnapshot {
+    pub address: U256,
+    pub balance: U256,
+    pub nonce: U256,
+    pub is_contract: bool,
+    pub code_size: usize,
+    pub first_transaction: Option<U256>,
+    pub last_transaction: Option<U256>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AccountBalance {
+    #[serde(with = "crate::serde_u256::hex")]
+    pub account: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub balance: U256,
+}
+
+impl EtherscanClient {
+    pub async fn balance(
+        &self,
+        address: U256,
+        tag: Option<EtherscanTag>,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let balance: String = self
+            .fetch(EtherscanRequest::account_balance(address, tag))
+            .await?;
+
+        Ok(U256::from_dec_str(&balance)?)
+    }
+
+    pub async fn token_balance(
+        &self,
+        address: U256,
+        contract_address: U256,
+        tag: Option<EtherscanTag>,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let balance: String = self
+            .fetch(EtherscanRequest::account_token_balance(
+                address,
+                contract_address,
+                tag,
+            ))
+            .await?;
+
+        Ok(U256::from_dec_str(&balance)?)
+    }
+
+    pub async fn balances(
+        &self,
+        addresses: Vec<U256>,
+        tag: Option<EtherscanTag>,
+    ) -> Result<Vec<AccountBalance>, Box<dyn Error + Send + Sync>> {
+        let requests = addresses
+            .chunks(BALANCE_MULTI_LIMIT)
+            .map(|chunk| EtherscanRequest::account_balance_multi(chunk.to_vec(), tag))
+            .collect::<Vec<_>>();
+
+        let mut balances = Vec::with_capacity(addresses.len());
+
+        for result in self
+            .batch(1)
+            .fetch::<Vec<AccountBalance>, _>(requests)
+            .await
+        {
+            balances.extend(result?);
+        }
+
+        Ok(balances)
+    }
+
+    pub async fn beacon_withdrawals(
+        &self,
+        address: U256,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+    ) -> Result<Vec<BeaconWithdrawal>, Box<dyn Error + Send + Sync>> {
+        self.fetch(EtherscanRequest::account_txs_beacon_withdrawal(
+            address,
+            startblock,
+            endblock,
+            None,
+            None,
+            Some(EtherscanSort::Ascending),
+        ))
+        .await
+    }
+
+    pub async fn mined_blocks(
+        &self,
+        address: U256,
+        blocktype: EtherscanBlockType,
+    ) -> Result<Vec<MinedBlock>, Box<dyn Error + Send + Sync>> {
+        self.fetch(EtherscanRequest::account_get_mined_blocks(
+            address, blocktype, None, None,
+        ))
+        .await
+    }
+
+    pub async fn account_snapshot(
+        &self,
+        address: U256,
+    ) -> Result<AccountSnapshot, Box<dyn Error + Send + Sync>> {
+        let (balance, nonce, code, first_transaction, last_transaction) = futures::try_join!(
+            self.balance(address, Some(EtherscanTag::Latest)),
+            self.transaction_count(address, EtherscanTag::Latest),
+            self.code(address),
+            self.edge_transaction(address, EtherscanSort::Ascending),
+            self.edge_transaction(address, EtherscanSort::Descending),
+        )?;
+
+        Ok(AccountSnapshot {
+            address,
+            balance,
+            nonce,
+            is_contract: !code.is_empty(),
+            code_size: code.len(),
+            first_transaction,
+            last_transaction,
+        })
+    }
+
+    async fn edge_transaction(
+        &self,
+        address: U256,
+        sort: EtherscanSort,
+    ) -> Result<Option<U256>, Box<dyn Error + Send + Sync>> {
+        let transactions: Vec<Transaction> = self
+            .fetch(EtherscanRequest::account_tx_list(
+                address,
+                None,
+                None,
+                Some(U256::one()),
+                Some(U256::one()),
+                Some(sort),
+            ))
+            .await?;
+
+        Ok(transactions
+            .first()
+            .map(|transaction| transaction.timestamp))
+    }
+
+    pub async fn account_tx_list_between(
+        &self,
+        address: U256,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Transaction>, Box<dyn Error + Send + Sync>> {
+        let range = self.blocks_between(from, to).await?;
+
+        if range.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.paginate::<Transaction>(
+            EtherscanRequest::account_tx_list(
+                address,
+                range.startblock(),
+                range.endblock(),
+                None,
+                None,
+                Some(EtherscanSort::Ascending),
+            ),
+            RANGE_PAGE_SIZE,
+        )
+        .map_ok(|page| page.items)
+        .try_concat()
+        .await
+    }
+
+    pub async fn internal_transactions_in_range(
+        &self,
+        startblock: U256,
+        endblock: U256,
+    ) -> Result<Vec<InternalTransaction>, Box<dyn Error + Send + Sync>> {
+        self.paginate::<InternalTransaction>(
+            EtherscanRequest::account_tx_list_internal_range(
+                startblock,
+                endblock,
+                None,
+                None,
+                Some(EtherscanSort::Ascending),
+            ),
+            RANGE_PAGE_SIZE,
+        )
+        .map_ok(|page| page.items)
+        .try_concat()
+        .await
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub block_number: U256,
+    #[serde(rename = "timeStamp", with = "crate::serde_u256::decimal")]
+    pub timestamp: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub hash: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub nonce: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub block_hash: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub transaction_index: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub from: U256,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub to: Option<U256>,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub value: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas_price: U256,
+    #[serde(default)]
+    pub is_error: String,
+    #[serde(default, rename = "txreceipt_status")]
+    pub txreceipt_status: String,
+    #[serde(default)]
+    pub input: String,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub contract_address: Option<U256>,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub cumulative_gas_used: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas_used: U256,
+    #[serde(default, with = "crate::serde_u256::decimal")]
+    pub confirmations: U256,
+    #[serde(default)]
+    pub method_id: String,
+    #[serde(default)]
+    pub function_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransaction {
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub block_number: U256,
+    #[serde(rename = "timeStamp", with = "crate::serde_u256::decimal")]
+    pub timestamp: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub hash: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub from: U256,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub to: Option<U256>,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub value: U256,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub contract_address: Option<U256>,
+    #[serde(default)]
+    pub input: String,
+    #[serde(default, rename = "type")]
+    pub kind: String,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas_used: U256,
+    #[serde(default)]
+    pub trace_id: String,
+    #[serde(default)]
+    pub is_error: String,
+    #[serde(default)]
+    pub err_code: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BeaconWithdrawal {
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub withdrawal_index: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub validator_index: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub address: U256,
+    #[serde(with = "crate::serde_u256::gwei")]
+    pub amount: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub block_number: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub timestamp: U256,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MinedBlock {
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub block_number: U256,
+    #[serde(rename = "timeStamp", with = "crate::serde_u256::decimal")]
+    pub timestamp: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub block_reward: U256,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransfer {
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub block_number: U256,
+    #[serde(rename = "timeStamp", with = "crate::serde_u256::decimal")]
+    pub timestamp: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub hash: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub nonce: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub block_hash: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub from: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub contract_address: U256,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub to: Option<U256>,
+    #[serde(default, with = "crate::serde_u256::decimal")]
+    pub value: U256,
+    #[serde(default, rename = "tokenID")]
+    pub token_id: Option<String>,
+    #[serde(default)]
+    pub token_value: Option<String>,
+    #[serde(default)]
+    pub token_name: String,
+    #[serde(default)]
+    pub token_symbol: String,
+    #[serde(default)]
+    pub token_decimal: String,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub transaction_index: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas_price: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub gas_used: U256,
+    #[serde(with = "crate::serde_u256::decimal")]
+    pub cumulative_gas_used: U256,
+    #[serde(default)]
+    pub input: String,
+    #[serde(default, with = "crate::serde_u256::decimal")]
+    pub confirmations: U256,
+}
@@ -0,0 +1,245 @@
+use {
+    crate::{Log, Transaction},
+    alloy_primitives::{hex, Address, Bytes, LogData, B256},
+    ethabi::ethereum_types::U256,
+    std::error::Error,
+};
+
+pub trait ToEtherscan {
+    fn to_etherscan(&self) -> U256;
+}
+
+pub trait FromEtherscan: Sized {
+    fn from_etherscan(value: U256) -> Self;
+}
+
+pub trait TryFromEtherscan: Sized {
+    fn try_from_etherscan(value: U256) -> Result<Self, Box<dyn Error + Send + Sync>>;
+}
+
+impl ToEtherscan for Address {
+    #[inline]
+    fn to_etherscan(&self) -> U256 {
+        U256::from_big_endian(self.as_slice())
+    }
+}
+
+impl ToEtherscan for B256 {
+    #[inline]
+    fn to_etherscan(&self) -> U256 {
+        U256::from_big_endian(self.as_slice())
+    }
+}
+
+impl ToEtherscan for alloy_primitives::U256 {
+    #[inline]
+    fn to_etherscan(&self) -> U256 {
+        U256::from_big_endian(&self.to_be_bytes::<32>())
+    }
+}
+
+impl TryFromEtherscan for Address {
+    fn try_from_etherscan(value: U256) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+
+        if bytes[..12].iter().any(|byte| *byte != 0) {
+            return Err(format!("{value:#x} does not fit in an address").into());
+        }
+
+        Ok(Address::from_slice(&bytes[12..]))
+    }
+}
+
+impl FromEtherscan for B256 {
+    #[inline]
+    fn from_etherscan(value: U256) -> Self {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        B256::from(bytes)
+    }
+}
+
+impl FromEtherscan for alloy_primitives::U256 {
+    #[inline]
+    fn from_etherscan(value: U256) -> Self {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        alloy_primitives::U256::from_be_bytes(bytes)
+    }
+}
+
+fn number(value: U256) -> Result<u64, Box<dyn Error + Send + Sync>> {
+    u64::try_from(value).map_err(|_| format!("{value} does not fit in u64").into())
+}
+
+impl TryFrom<&Log> for alloy_rpc_types_eth::Log {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn try_from(log: &Log) -> Result<Self, Self::Error> {
+        let data = LogData::new_unchecked(
+            log.topics
+                .iter()
+                .copied()
+                .map(B256::from_etherscan)
+                .collect(),
+            hex::decode(&log.data)?.into(),
+        );
+
+        Ok(Self {
+            inner: alloy_primitives::Log {
+                address: Address::try_from_etherscan(log.address)?,
+                data,
+            },
+            block_hash: log.block_hash.map(B256::from_etherscan),
+            block_number: log.block_number.map(number).transpose()?,
+            block_timestamp: log.timestamp.map(number).transpose()?,
+            transaction_hash: log.transaction_hash.map(B256::from_etherscan),
+            transaction_index: log.transaction_index.map(number).transpose()?,
+            log_index: log.log_index.map(number).transpose()?,
+            removed: false,
+        })
+    }
+}
+
+impl TryFrom<Log> for alloy_rpc_types_eth::Log {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    #[inline]
+    fn try_from(log: Log) -> Result<Self, Self::Error> {
+        Self::try_from(&log)
+    }
+}
+
+impl From<&alloy_rpc_types_eth::Log> for Log {
+    fn from(log: &alloy_rpc_types_eth::Log) -> Self {
+        Self {
+            address: log.inner.address.to_etherscan(),
+            topics: log.topics().iter().map(ToEtherscan::to_etherscan).collect(),
+            data: hex::encode_prefixed(&log.inner.data.data),
+            block_number: log.block_number.map(U256::from),
+            block_hash: log.block_hash.as_ref().map(ToEtherscan::to_etherscan),
+            timestamp: log.block_timestamp.map(U256::from),
+            transaction_hash: log.transaction_hash.as_ref().map(ToEtherscan::to_etherscan),
+            transaction_index: log.transaction_index.map(U256::from),
+            log_index: log.log_index.map(U256::from),
+        }
+    }
+}
+
+impl From<alloy_rpc_types_eth::Log> for Log {
+    #[inline]
+    fn from(log: alloy_rpc_types_eth::Log) -> Self {
+        Self::from(&log)
+    }
+}
+
+/// An Etherscan transaction with alloy field types.
+///
+/// Etherscan does not report the signature, envelope type or chain id, so this
+/// cannot become an `alloy_rpc_types_eth::Transaction` without inventing them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlloyTransaction {
+    pub block_number: u64,
+    pub timestamp: u64,
+    pub hash: B256,
+    pub nonce: u64,
+    pub block_hash: B256,
+    pub transaction_index: u64,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: alloy_primitives::U256,
+    pub gas: u64,
+    pub gas_price: u128,
+    pub is_error: String,
+    pub txreceipt_status: String,
+    pub input: Bytes,
+    pub contract_address: Option<Address>,
+    pub cumulative_gas_used: u64,
+    pub gas_used: u64,
+    pub confirmations: u64,
+    pub method_id: String,
+    pub function_name: String,
+}
+
+impl TryFrom<&Transaction> for AlloyTransaction {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn try_from(transaction: &Transaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_number: number(transaction.block_number)?,
+            timestamp: number(transaction.timestamp)?,
+            hash: B256::from_etherscan(transaction.hash),
+            nonce: number(transaction.nonce)?,
+            block_hash: B256::from_etherscan(transaction.block_hash),
+            transaction_index: number(transaction.transaction_index)?,
+            from: Address::try_from_etherscan(transaction.from)?,
+            to: transaction
+                .to
+                .map(Address::try_from_etherscan)
+                .transpose()?,
+            value: alloy_primitives::U256::from_etherscan(transaction.value),
+            gas: number(transaction.gas)?,
+            gas_price: u128::try_from(transaction.gas_price)
+                .map_err(|_| format!("{} does not fit in u128", transaction.gas_price))?,
+            is_error: transaction.is_error.clone(),
+            txreceipt_status: transaction.txreceipt_status.clone(),
+            input: hex::decode(&transaction.input)?.into(),
+            contract_address: transaction
+                .contract_address
+                .map(Address::try_from_etherscan)
+                .transpose()?,
+            cumulative_gas_used: number(transaction.cumulative_gas_used)?,
+            gas_used: number(transaction.gas_used)?,
+            confirmations: number(transaction.confirmations)?,
+            method_id: transaction.method_id.clone(),
+            function_name: transaction.function_name.clone(),
+        })
+    }
+}
+
+impl TryFrom<Transaction> for AlloyTransaction {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    #[inline]
+    fn try_from(transaction: Transaction) -> Result<Self, Self::Error> {
+        Self::try_from(&transaction)
+    }
+}
+
+impl From<&AlloyTransaction> for Transaction {
+    fn from(transaction: &AlloyTransaction) -> Self {
+        Self {
+            block_number: U256::from(transaction.block_number),
+            timestamp: U256::from(transaction.timestamp),
+            hash: transaction.hash.to_etherscan(),
+            nonce: U256::from(transaction.nonce),
+            block_hash: transaction.block_hash.to_etherscan(),
+            transaction_index: U256::from(transaction.transaction_index),
+            from: transaction.from.to_etherscan(),
+            to: transaction.to.as_ref().map(ToEtherscan::to_etherscan),
+            value: transaction.value.to_etherscan(),
+            gas: U256::from(transaction.gas),
+            gas_price: U256::from(transaction.gas_price),
+            is_error: transaction.is_error.clone(),
+            txreceipt_status: transaction.txreceipt_status.clone(),
+            input: hex::encode_prefixed(&transaction.input),
+            contract_address: transaction
+                .contract_address
+                .as_ref()
+                .map(ToEtherscan::to_etherscan),
+            cumulative_gas_used: U256::from(transaction.cumulative_gas_used),
+            gas_used: U256::from(transaction.gas_used),
+            confirmations: U256::from(transaction.confirmations),
+            method_id: transaction.method_id.clone(),
+            function_name: transaction.function_name.clone(),
+        }
+    }
+}
+
+impl From<AlloyTransaction> for Transaction {
+    #[inline]
+    fn from(transaction: AlloyTransaction) -> Self {
+        Self::from(&transaction)
+    }
+}
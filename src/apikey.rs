@@ -0,0 +1,77 @@
+use {
+    serde::{Deserialize, Serialize},
+    std::fmt,
+};
+
+#[derive(Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    #[inline]
+    pub fn new(apikey: String) -> Self {
+        Self(apikey)
+    }
+
+    #[inline]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ApiKey {
+    #[inline]
+    fn from(apikey: String) -> Self {
+        Self(apikey)
+    }
+}
+
+impl From<&str> for ApiKey {
+    #[inline]
+    fn from(apikey: &str) -> Self {
+        Self(apikey.into())
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<REDACTED>")
+    }
+}
+
+impl fmt::Display for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<REDACTED>")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ApiKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+pub fn redact_url(url: &str) -> String {
+    let mut redacted = String::with_capacity(url.len());
+    let mut rest = url;
+
+    while let Some(index) = rest.find("apikey=") {
+        let (head, tail) = rest.split_at(index + "apikey=".len());
+        redacted.push_str(head);
+        redacted.push_str("<REDACTED>");
+        rest = &tail[tail.find('&').unwrap_or(tail.len())..];
+    }
+
+    redacted.push_str(rest);
+    redacted
+}
+
+pub fn url_apikey(url: &str) -> Option<ApiKey> {
+    let query = &url[url.find('?')? + 1..];
+
+    query
+        .split('&')
+        .find_map(|param| param.strip_prefix("apikey="))
+        .map(ApiKey::from)
+}
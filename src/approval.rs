@@ -0,0 +1,269 @@
+use {
+    crate::{
+        parse_topic, EtherscanClient, EtherscanRequest, Log, APPROVAL_FOR_ALL_TOPIC,
+        APPROVAL_TOPIC, MAX_RESULT_WINDOW,
+    },
+    ethabi::{
+        ethereum_types::{H160, U256},
+        ParamType, Token,
+    },
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::{BTreeMap, HashSet},
+        error::Error,
+    },
+};
+
+const LOGS_PAGE_SIZE: usize = 1_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(tag = "kind")]
+pub enum ApprovalKind {
+    Erc20 { amount: U256 },
+    Erc721 { token_id: U256 },
+    ForAll { approved: bool },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ApprovalEvent {
+    pub token: U256,
+    pub owner: U256,
+    pub spender: U256,
+    pub kind: ApprovalKind,
+    pub block_number: Option<U256>,
+    pub transaction_hash: Option<U256>,
+    pub log_index: Option<U256>,
+}
+
+impl ApprovalEvent {
+    pub fn from_log(log: &Log) -> Option<Self> {
+        let topic = *log.topics.first()?;
+        let owner = *log.topics.get(1)?;
+        let spender = *log.topics.get(2)?;
+        let data = word(&log.data);
+
+        let kind = if topic == parse_topic(APPROVAL_TOPIC) {
+            match log.topics.get(3) {
+                Some(token_id) => ApprovalKind::Erc721 {
+                    token_id: *token_id,
+                },
+                None => ApprovalKind::Erc20 { amount: data? },
+            }
+        } else if topic == parse_topic(APPROVAL_FOR_ALL_TOPIC) {
+            ApprovalKind::ForAll {
+                approved: !data?.is_zero(),
+            }
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            token: log.address,
+            owner,
+            spender,
+            kind,
+            block_number: log.block_number,
+            transaction_hash: log.transaction_hash,
+            log_index: log.log_index,
+        })
+    }
+
+    #[inline]
+    pub fn is_outstanding(&self) -> bool {
+        match self.kind {
+            ApprovalKind::Erc20 { amount } => !amount.is_zero(),
+            ApprovalKind::Erc721 { .. } => !self.spender.is_zero(),
+            ApprovalKind::ForAll { approved } => approved,
+        }
+    }
+
+    fn slot(&self) -> (U256, Option<U256>, Option<U256>) {
+        match self.kind {
+            ApprovalKind::Erc20 { .. } => (self.token, Some(self.spender), None),
+            ApprovalKind::Erc721 { token_id } => (self.token, None, Some(token_id)),
+            ApprovalKind::ForAll { .. } => (self.token, Some(self.spender), Some(U256::MAX)),
+        }
+    }
+}
+
+fn word(data: &str) -> Option<U256> {
+    let data = data.trim_start_matches("0x");
+    U256::from_str_radix(data.get(..64.min(data.len()))?, 16).ok()
+}
+
+fn to_address(value: U256) -> H160 {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    H160::from_slice(&bytes[12..])
+}
+
+pub fn outstanding_approvals(events: &[ApprovalEvent]) -> BTreeMap<U256, Vec<ApprovalEvent>> {
+    let mut ordered = events.iter().collect::<Vec<_>>();
+    ordered.sort_by_key(|event| (event.block_number, event.log_index));
+
+    let mut latest = BTreeMap::new();
+
+    for event in ordered {
+        latest.insert(event.slot(), event);
+    }
+
+    let mut by_spender = BTreeMap::<U256, Vec<ApprovalEvent>>::new();
+
+    for event in latest.into_values().filter(|event| event.is_outstanding()) {
+        by_spender
+            .entry(event.spender)
+            .or_default()
+            .push(event.clone());
+    }
+
+    by_spender
+}
+
+#[derive(Clone, Debug)]
+pub struct ApprovalScanner {
+    client: EtherscanClient,
+    owner: U256,
+    fromblock: Option<U256>,
+    toblock: Option<U256>,
+}
+
+impl ApprovalScanner {
+    #[inline]
+    pub fn new(client: EtherscanClient, owner: U256) -> Self {
+        Self {
+            client,
+            owner,
+            fromblock: None,
+            toblock: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_block_range(mut self, fromblock: Option<U256>, toblock: Option<U256>) -> Self {
+        self.fromblock = fromblock;
+        self.toblock = toblock;
+        self
+    }
+
+    #[inline]
+    pub fn owner(&self) -> U256 {
+        self.owner
+    }
+
+    pub async fn scan(&self) -> Result<Vec<ApprovalEvent>, Box<dyn Error + Send + Sync>> {
+        let mut events = Vec::new();
+
+        for topic in [APPROVAL_TOPIC, APPROVAL_FOR_ALL_TOPIC] {
+            for log in self.logs(parse_topic(topic)).await? {
+                events.extend(ApprovalEvent::from_log(&log));
+            }
+        }
+
+        events.sort_by_key(|event| (event.block_number, event.log_index));
+        Ok(events)
+    }
+
+    pub async fn outstanding(
+        &self,
+    ) -> Result<BTreeMap<U256, Vec<ApprovalEvent>>, Box<dyn Error + Send + Sync>> {
+        Ok(outstanding_approvals(&self.scan().await?))
+    }
+
+    pub async fn verify_allowances(
+        &self,
+    ) -> Result<Vec<(ApprovalEvent, U256)>, Box<dyn Error + Send + Sync>> {
+        let mut verified = Vec::new();
+
+        for event in self.outstanding().await?.into_values().flatten() {
+            if let ApprovalKind::Erc20 { .. } = event.kind {
+                let allowance = self
+                    .client
+                    .allowance(event.token, event.owner, event.spender)
+                    .await?;
+
+                verified.push((event, allowance));
+            }
+        }
+
+        Ok(verified)
+    }
+
+    async fn logs(&self, topic: U256) -> Result<Vec<Log>, Box<dyn Error + Send + Sync>> {
+        let mut logs = Vec::new();
+        let mut seen = HashSet::new();
+        let mut fromblock = self.fromblock;
+        let mut page = 1;
+
+        loop {
+            let batch: Vec<Log> = self
+                .client
+                .fetch(EtherscanRequest::logs_get_logs(
+                    None,
+                    fromblock,
+                    self.toblock,
+                    [Some(topic), Some(self.owner), None, None],
+                    Some(U256::from(page)),
+                    Some(U256::from(LOGS_PAGE_SIZE)),
+                ))
+                .await?;
+
+            let full = batch.len() >= LOGS_PAGE_SIZE;
+            let last_block = batch.last().and_then(|log| log.block_number);
+
+            for log in batch {
+                if seen.insert((log.transaction_hash, log.log_index)) {
+                    logs.push(log);
+                }
+            }
+
+            if !full {
+                return Ok(logs);
+            }
+
+            match last_block {
+                _ if (page + 1) * LOGS_PAGE_SIZE as u64 <= MAX_RESULT_WINDOW => page += 1,
+                Some(block) if Some(block) != fromblock => {
+                    fromblock = Some(block);
+                    page = 1;
+                }
+                _ => {
+                    return Err(format!(
+                        "block {} has more than {MAX_RESULT_WINDOW} approval logs",
+                        fromblock.unwrap_or_default()
+                    )
+                    .into())
+                }
+            }
+        }
+    }
+}
+
+impl EtherscanClient {
+    pub async fn allowance(
+        &self,
+        token: U256,
+        owner: U256,
+        spender: U256,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let mut data =
+            ethabi::short_signature("allowance", &[ParamType::Address, ParamType::Address])
+                .to_vec();
+
+        data.extend(ethabi::encode(&[
+            Token::Address(to_address(owner)),
+            Token::Address(to_address(spender)),
+        ]));
+
+        let result = self.call(token, &data).await?;
+
+        match ethabi::decode(&[ParamType::Uint(256)], &result)?.pop() {
+            Some(Token::Uint(allowance)) => Ok(allowance),
+            _ => Err("unexpected allowance return data".into()),
+        }
+    }
+
+    #[inline]
+    pub fn approvals(&self, owner: U256) -> ApprovalScanner {
+        ApprovalScanner::new(self.clone(), owner)
+    }
+}
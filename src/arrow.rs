@@ -0,0 +1,319 @@
+use {
+    crate::{AccountBalance, InternalTransaction, Log, TokenTransfer, Transaction},
+    arrow_array::{ArrayRef, RecordBatch, StringArray, UInt64Array},
+    arrow_schema::{DataType, Field, Schema, SchemaRef},
+    ethabi::ethereum_types::U256,
+    parquet::arrow::ArrowWriter,
+    std::{error::Error, io::Write, marker::PhantomData, sync::Arc},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    UInt64,
+    Utf8,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnValue {
+    UInt64(U256),
+    Utf8(Option<String>),
+}
+
+pub trait ArrowRecord {
+    const COLUMNS: &'static [(&'static str, ColumnType)];
+
+    fn values(&self) -> Vec<ColumnValue>;
+}
+
+fn number(value: U256) -> ColumnValue {
+    ColumnValue::UInt64(value)
+}
+
+pub(crate) fn checked_u64(column: &str, value: U256) -> Result<u64, Box<dyn Error + Send + Sync>> {
+    u64::try_from(value).map_err(|_| format!("{column} value {value} does not fit in u64").into())
+}
+
+fn decimal(value: U256) -> ColumnValue {
+    ColumnValue::Utf8(Some(value.to_string()))
+}
+
+fn address(value: U256) -> ColumnValue {
+    ColumnValue::Utf8(Some(format!("{value:#042x}")))
+}
+
+fn optional_address(value: Option<U256>) -> ColumnValue {
+    ColumnValue::Utf8(value.map(|value| format!("{value:#042x}")))
+}
+
+fn hash(value: U256) -> ColumnValue {
+    ColumnValue::Utf8(Some(format!("{value:#066x}")))
+}
+
+fn text(value: &str) -> ColumnValue {
+    ColumnValue::Utf8(Some(value.to_string()))
+}
+
+impl ArrowRecord for Transaction {
+    const COLUMNS: &'static [(&'static str, ColumnType)] = &[
+        ("block_number", ColumnType::UInt64),
+        ("timestamp", ColumnType::UInt64),
+        ("hash", ColumnType::Utf8),
+        ("nonce", ColumnType::UInt64),
+        ("block_hash", ColumnType::Utf8),
+        ("transaction_index", ColumnType::UInt64),
+        ("from", ColumnType::Utf8),
+        ("to", ColumnType::Utf8),
+        ("value", ColumnType::Utf8),
+        ("gas", ColumnType::UInt64),
+        ("gas_price", ColumnType::Utf8),
+        ("is_error", ColumnType::Utf8),
+        ("txreceipt_status", ColumnType::Utf8),
+        ("input", ColumnType::Utf8),
+        ("contract_address", ColumnType::Utf8),
+        ("cumulative_gas_used", ColumnType::UInt64),
+        ("gas_used", ColumnType::UInt64),
+        ("confirmations", ColumnType::UInt64),
+        ("method_id", ColumnType::Utf8),
+        ("function_name", ColumnType::Utf8),
+    ];
+
+    fn values(&self) -> Vec<ColumnValue> {
+        vec![
+            number(self.block_number),
+            number(self.timestamp),
+            hash(self.hash),
+            number(self.nonce),
+            hash(self.block_hash),
+            number(self.transaction_index),
+            address(self.from),
+            optional_address(self.to),
+            decimal(self.value),
+            number(self.gas),
+            decimal(self.gas_price),
+            text(&self.is_error),
+            text(&self.txreceipt_status),
+            text(&self.input),
+            optional_address(self.contract_address),
+            number(self.cumulative_gas_used),
+            number(self.gas_used),
+            number(self.confirmations),
+            text(&self.method_id),
+            text(&self.function_name),
+        ]
+    }
+}
+
+impl ArrowRecord for InternalTransaction {
+    const COLUMNS: &'static [(&'static str, ColumnType)] = &[
+        ("block_number", ColumnType::UInt64),
+        ("timestamp", ColumnType::UInt64),
+        ("hash", ColumnType::Utf8),
+        ("from", ColumnType::Utf8),
+        ("to", ColumnType::Utf8),
+        ("value", ColumnType::Utf8),
+        ("contract_address", ColumnType::Utf8),
+        ("input", ColumnType::Utf8),
+        ("type", ColumnType::Utf8),
+        ("gas", ColumnType::UInt64),
+        ("gas_used", ColumnType::UInt64),
+        ("trace_id", ColumnType::Utf8),
+        ("is_error", ColumnType::Utf8),
+        ("err_code", ColumnType::Utf8),
+    ];
+
+    fn values(&self) -> Vec<ColumnValue> {
+        vec![
+            number(self.block_number),
+            number(self.timestamp),
+            hash(self.hash),
+            address(self.from),
+            optional_address(self.to),
+            decimal(self.value),
+            optional_address(self.contract_address),
+            text(&self.input),
+            text(&self.kind),
+            number(self.gas),
+            number(self.gas_used),
+            text(&self.trace_id),
+            text(&self.is_error),
+            text(&self.err_code),
+        ]
+    }
+}
+
+impl ArrowRecord for TokenTransfer {
+    const COLUMNS: &'static [(&'static str, ColumnType)] = &[
+        ("block_number", ColumnType::UInt64),
+        ("timestamp", ColumnType::UInt64),
+        ("hash", ColumnType::Utf8),
+        ("nonce", ColumnType::UInt64),
+        ("block_hash", ColumnType::Utf8),
+        ("from", ColumnType::Utf8),
+        ("contract_address", ColumnType::Utf8),
+        ("to", ColumnType::Utf8),
+        ("value", ColumnType::Utf8),
+        ("token_id", ColumnType::Utf8),
+        ("token_value", ColumnType::Utf8),
+        ("token_name", ColumnType::Utf8),
+        ("token_symbol", ColumnType::Utf8),
+        ("token_decimal", ColumnType::Utf8),
+        ("transaction_index", ColumnType::UInt64),
+        ("gas", ColumnType::UInt64),
+        ("gas_price", ColumnType::Utf8),
+        ("gas_used", ColumnType::UInt64),
+        ("cumulative_gas_used", ColumnType::UInt64),
+        ("input", ColumnType::Utf8),
+        ("confirmations", ColumnType::UInt64),
+    ];
+
+    fn values(&self) -> Vec<ColumnValue> {
+        vec![
+            number(self.block_number),
+            number(self.timestamp),
+            hash(self.hash),
+            number(self.nonce),
+            hash(self.block_hash),
+            address(self.from),
+            address(self.contract_address),
+            optional_address(self.to),
+            decimal(self.value),
+            ColumnValue::Utf8(self.token_id.clone()),
+            ColumnValue::Utf8(self.token_value.clone()),
+            text(&self.token_name),
+            text(&self.token_symbol),
+            text(&self.token_decimal),
+            number(self.transaction_index),
+            number(self.gas),
+            decimal(self.gas_price),
+            number(self.gas_used),
+            number(self.cumulative_gas_used),
+            text(&self.input),
+            number(self.confirmations),
+        ]
+    }
+}
+
+impl ArrowRecord for AccountBalance {
+    const COLUMNS: &'static [(&'static str, ColumnType)] =
+        &[("account", ColumnType::Utf8), ("balance", ColumnType::Utf8)];
+
+    fn values(&self) -> Vec<ColumnValue> {
+        vec![address(self.account), decimal(self.balance)]
+    }
+}
+
+impl ArrowRecord for Log {
+    const COLUMNS: &'static [(&'static str, ColumnType)] = &[
+        ("address", ColumnType::Utf8),
+        ("topic0", ColumnType::Utf8),
+        ("topic1", ColumnType::Utf8),
+        ("topic2", ColumnType::Utf8),
+        ("topic3", ColumnType::Utf8),
+        ("data", ColumnType::Utf8),
+        ("block_number", ColumnType::UInt64),
+        ("block_hash", ColumnType::Utf8),
+        ("timestamp", ColumnType::UInt64),
+        ("transaction_hash", ColumnType::Utf8),
+        ("transaction_index", ColumnType::UInt64),
+        ("log_index", ColumnType::UInt64),
+    ];
+
+    fn values(&self) -> Vec<ColumnValue> {
+        let topic = |index: usize| {
+            ColumnValue::Utf8(self.topics.get(index).map(|topic| format!("{topic:#066x}")))
+        };
+
+        vec![
+            address(self.address),
+            topic(0),
+            topic(1),
+            topic(2),
+            topic(3),
+            text(&self.data),
+            number(self.block_number.unwrap_or_default()),
+            ColumnValue::Utf8(self.block_hash.map(|hash| format!("{hash:#066x}"))),
+            number(self.timestamp.unwrap_or_default()),
+            ColumnValue::Utf8(self.transaction_hash.map(|hash| format!("{hash:#066x}"))),
+            number(self.transaction_index.unwrap_or_default()),
+            number(self.log_index.unwrap_or_default()),
+        ]
+    }
+}
+
+pub fn schema<T: ArrowRecord>() -> SchemaRef {
+    let fields = T::COLUMNS
+        .iter()
+        .map(|(name, kind)| match kind {
+            ColumnType::UInt64 => Field::new(*name, DataType::UInt64, false),
+            ColumnType::Utf8 => Field::new(*name, DataType::Utf8, true),
+        })
+        .collect::<Vec<_>>();
+
+    Arc::new(Schema::new(fields))
+}
+
+pub fn to_record_batch<T: ArrowRecord>(
+    records: &[T],
+) -> Result<RecordBatch, Box<dyn Error + Send + Sync>> {
+    let mut numbers = vec![Vec::with_capacity(records.len()); T::COLUMNS.len()];
+    let mut strings = vec![Vec::with_capacity(records.len()); T::COLUMNS.len()];
+
+    for record in records {
+        for (index, value) in record.values().into_iter().enumerate() {
+            match value {
+                ColumnValue::UInt64(value) => {
+                    numbers[index].push(checked_u64(T::COLUMNS[index].0, value)?)
+                }
+                ColumnValue::Utf8(value) => strings[index].push(value),
+            }
+        }
+    }
+
+    let columns = T::COLUMNS
+        .iter()
+        .enumerate()
+        .map(|(index, (_, kind))| match kind {
+            ColumnType::UInt64 => {
+                Arc::new(UInt64Array::from(std::mem::take(&mut numbers[index]))) as ArrayRef
+            }
+            ColumnType::Utf8 => {
+                Arc::new(StringArray::from(std::mem::take(&mut strings[index]))) as ArrayRef
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(RecordBatch::try_new(schema::<T>(), columns)?)
+}
+
+pub struct ParquetWriter<T: ArrowRecord, W: Write + Send> {
+    writer: ArrowWriter<W>,
+    records: PhantomData<fn(&T)>,
+}
+
+impl<T: ArrowRecord, W: Write + Send> ParquetWriter<T, W> {
+    pub fn new(writer: W) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self {
+            writer: ArrowWriter::try_new(writer, schema::<T>(), None)?,
+            records: PhantomData,
+        })
+    }
+
+    pub fn write(&mut self, records: &[T]) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.writer.write(&to_record_batch(records)?)?;
+        Ok(())
+    }
+
+    pub fn close(self) -> Result<W, Box<dyn Error + Send + Sync>> {
+        Ok(self.writer.into_inner()?)
+    }
+}
+
+pub fn write_parquet<T, W>(records: &[T], writer: W) -> Result<W, Box<dyn Error + Send + Sync>>
+where
+    T: ArrowRecord,
+    W: Write + Send,
+{
+    let mut writer = ParquetWriter::<T, W>::new(writer)?;
+    writer.write(records)?;
+    writer.close()
+}
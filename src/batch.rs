@@ -0,0 +1,182 @@
+use {
+    crate::{EtherscanClient, EtherscanRequest, HttpResponse, RequestOutcome},
+    ethabi::ethereum_types::U256,
+    futures::{stream, Future, StreamExt},
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    std::{collections::HashMap, error::Error},
+};
+
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BatchSummary {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub rate_limited: usize,
+}
+
+impl BatchSummary {
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.succeeded + self.failed + self.rate_limited
+    }
+
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.failed == 0 && self.rate_limited == 0
+    }
+
+    fn record(&mut self, outcome: RequestOutcome) {
+        match outcome {
+            RequestOutcome::Success | RequestOutcome::CacheHit => self.succeeded += 1,
+            RequestOutcome::RateLimited | RequestOutcome::QuotaExhausted => self.rate_limited += 1,
+            _ => self.failed += 1,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    pub results: Vec<Result<HttpResponse, Box<dyn Error + Send + Sync>>>,
+    pub outcomes: Vec<RequestOutcome>,
+    pub summary: BatchSummary,
+}
+
+impl BatchReport {
+    pub fn new(results: Vec<Result<HttpResponse, Box<dyn Error + Send + Sync>>>) -> Self {
+        let outcomes = results.iter().map(RequestOutcome::of).collect::<Vec<_>>();
+        let mut summary = BatchSummary::default();
+
+        for outcome in &outcomes {
+            summary.record(*outcome);
+        }
+
+        Self {
+            results,
+            outcomes,
+            summary,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = (usize, RequestOutcome)> + '_ {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter(|(_, outcome)| !outcome.is_success())
+            .map(|(index, outcome)| (index, *outcome))
+    }
+
+    pub fn retryable(&self) -> impl Iterator<Item = usize> + '_ {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter(|(_, outcome)| {
+                matches!(
+                    outcome,
+                    RequestOutcome::RateLimited | RequestOutcome::QuotaExhausted
+                )
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BatchExecutor {
+    client: EtherscanClient,
+    concurrency: usize,
+}
+
+impl BatchExecutor {
+    #[inline]
+    pub fn new(client: EtherscanClient, concurrency: usize) -> Self {
+        Self {
+            client,
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    #[inline]
+    pub fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    #[inline]
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    pub async fn run<I, F, Fut, T>(&self, items: I, f: F) -> Vec<T>
+    where
+        I: IntoIterator,
+        F: Fn(EtherscanClient, I::Item) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        stream::iter(items)
+            .map(|item| f(self.client.clone(), item))
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    pub async fn execute<I>(
+        &self,
+        requests: I,
+    ) -> Vec<Result<HttpResponse, Box<dyn Error + Send + Sync>>>
+    where
+        I: IntoIterator<Item = EtherscanRequest>,
+    {
+        self.run(requests, |client, request| async move {
+            client.execute(request).await
+        })
+        .await
+    }
+
+    pub async fn execute_all<I>(&self, requests: I) -> BatchReport
+    where
+        I: IntoIterator<Item = EtherscanRequest>,
+    {
+        BatchReport::new(self.execute(requests).await)
+    }
+
+    pub async fn fetch<T, I>(&self, requests: I) -> Vec<Result<T, Box<dyn Error + Send + Sync>>>
+    where
+        T: DeserializeOwned,
+        I: IntoIterator<Item = EtherscanRequest>,
+    {
+        self.run(requests, |client, request| async move {
+            client.fetch(request).await
+        })
+        .await
+    }
+
+    pub async fn for_each_address<T, I, F>(
+        &self,
+        addresses: I,
+        request_fn: F,
+    ) -> HashMap<U256, Result<T, Box<dyn Error + Send + Sync>>>
+    where
+        T: DeserializeOwned,
+        I: IntoIterator<Item = U256>,
+        F: Fn(U256) -> EtherscanRequest,
+    {
+        stream::iter(addresses)
+            .map(|address| {
+                let client = self.client.clone();
+                let request = request_fn(address);
+                async move { (address, client.fetch(request).await) }
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await
+    }
+}
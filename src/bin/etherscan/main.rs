@@ -0,0 +1,391 @@
+mod output;
+
+use {
+    crate::output::{print, print_records, EventWriter, OutputFormat},
+    clap::{Parser, Subcommand},
+    ethabi::ethereum_types::U256,
+    etherscan::{
+        config::EtherscanConfig, CancellationToken, EtherscanClient, EtherscanRequest,
+        EtherscanSort, EtherscanTag, HistoryEvent, TokenTransfer, Transaction, WatchEvent,
+    },
+    futures::StreamExt,
+    std::{env, error::Error, fs, path::PathBuf, time::Duration},
+};
+
+#[derive(Parser)]
+#[command(name = "etherscan", version, about = "Query the Etherscan API")]
+struct Cli {
+    #[arg(long, global = true, env = "ETHERSCAN_CONFIG")]
+    config: Option<PathBuf>,
+
+    #[arg(long, global = true, env = "ETHERSCAN_PROFILE")]
+    profile: Option<String>,
+
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Json)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    #[command(about = "Show the ether balance of an address")]
+    Balance { address: String },
+    #[command(about = "List normal transactions of an address")]
+    Txlist {
+        address: String,
+        #[arg(long)]
+        startblock: Option<u64>,
+        #[arg(long)]
+        endblock: Option<u64>,
+        #[arg(long)]
+        page: Option<u64>,
+        #[arg(long)]
+        offset: Option<u64>,
+        #[arg(long)]
+        desc: bool,
+    },
+    #[command(about = "List ERC-20 token transfers of an address")]
+    Tokentx {
+        address: String,
+        #[arg(long)]
+        contract: Option<String>,
+        #[arg(long)]
+        startblock: Option<u64>,
+        #[arg(long)]
+        endblock: Option<u64>,
+        #[arg(long)]
+        page: Option<u64>,
+        #[arg(long)]
+        offset: Option<u64>,
+        #[arg(long)]
+        desc: bool,
+    },
+    #[command(about = "Print the ABI of a verified contract, or write it to a file")]
+    Abi {
+        address: String,
+        #[arg(short = 'o', long = "out")]
+        out: Option<PathBuf>,
+    },
+    #[command(about = "Generate alloy sol! bindings from a verified contract's ABI")]
+    Bindings {
+        address: String,
+        #[arg(short = 'o', long = "out")]
+        out: Option<PathBuf>,
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(long)]
+        rpc: bool,
+    },
+    #[command(about = "Generate a Solidity interface from a verified contract's ABI")]
+    Interface {
+        address: String,
+        #[arg(short = 'o', long = "out")]
+        out: Option<PathBuf>,
+        #[arg(long)]
+        human_readable: bool,
+    },
+    #[command(
+        about = "Print the verified source of a contract, or write the project to a directory"
+    )]
+    Source {
+        address: String,
+        #[arg(short = 'o', long = "out-dir")]
+        out_dir: Option<PathBuf>,
+    },
+    #[command(about = "Print new transactions and transfers of an address as they appear")]
+    Watch {
+        address: String,
+        #[arg(long, default_value_t = 0)]
+        min_confirmations: u64,
+        #[arg(long)]
+        token: Option<String>,
+        #[arg(long)]
+        startblock: Option<u64>,
+        #[arg(long, default_value_t = 15)]
+        interval: u64,
+    },
+    #[command(about = "Show the gas oracle")]
+    Gas,
+    #[command(about = "Show the ether supply or a token's total supply")]
+    Supply { contract: Option<String> },
+}
+
+fn parse_address(value: &str) -> Result<U256, Box<dyn Error + Send + Sync>> {
+    let hex = value.trim().trim_start_matches("0x");
+
+    if hex.is_empty() || hex.len() > 40 {
+        return Err(format!("invalid address: {value}").into());
+    }
+
+    Ok(U256::from_str_radix(hex, 16)?)
+}
+
+fn sort(desc: bool) -> Option<EtherscanSort> {
+    match desc {
+        true => Some(EtherscanSort::Descending),
+        false => Some(EtherscanSort::Ascending),
+    }
+}
+
+fn client(
+    config: Option<PathBuf>,
+    profile: Option<&str>,
+) -> Result<EtherscanClient, Box<dyn Error + Send + Sync>> {
+    let config = config.or_else(|| {
+        let path = PathBuf::from(env::var_os("HOME")?).join(".etherscan.toml");
+        path.exists().then_some(path)
+    });
+
+    match config {
+        Some(path) => EtherscanConfig::from_file(path)?.client(profile),
+        None if profile.is_some() => {
+            Err("--profile requires a configuration file (--config or ~/.etherscan.toml)".into())
+        }
+        None => EtherscanClient::from_env(),
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let client = client(cli.config, cli.profile.as_deref())?;
+    let format = cli.output;
+
+    match cli.command {
+        Command::Balance { address } => {
+            let balance = client
+                .balance(parse_address(&address)?, Some(EtherscanTag::Latest))
+                .await?;
+
+            print(&balance.to_string(), format)
+        }
+
+        Command::Txlist {
+            address,
+            startblock,
+            endblock,
+            page,
+            offset,
+            desc,
+        } => {
+            let transactions: Vec<Transaction> = client
+                .fetch(EtherscanRequest::account_tx_list(
+                    parse_address(&address)?,
+                    startblock.map(U256::from),
+                    endblock.map(U256::from),
+                    page.map(U256::from),
+                    offset.map(U256::from),
+                    sort(desc),
+                ))
+                .await?;
+
+            print_records(&transactions, format)
+        }
+
+        Command::Tokentx {
+            address,
+            contract,
+            startblock,
+            endblock,
+            page,
+            offset,
+            desc,
+        } => {
+            let transfers: Vec<TokenTransfer> = client
+                .fetch(EtherscanRequest::account_token_tx(
+                    contract.as_deref().map(parse_address).transpose()?,
+                    Some(parse_address(&address)?),
+                    startblock.map(U256::from),
+                    endblock.map(U256::from),
+                    page.map(U256::from),
+                    offset.map(U256::from),
+                    sort(desc),
+                ))
+                .await?;
+
+            print_records(&transfers, format)
+        }
+
+        Command::Abi { address, out } => {
+            let abi: String = client
+                .fetch(EtherscanRequest::contract_get_abi(parse_address(&address)?))
+                .await?;
+
+            let abi = serde_json::from_str::<serde_json::Value>(&abi)?;
+
+            match out {
+                Some(path) => {
+                    fs::write(&path, serde_json::to_string_pretty(&abi)?)?;
+                    eprintln!("wrote {}", path.display());
+                    Ok(())
+                }
+                None => print(&abi, format),
+            }
+        }
+
+        Command::Bindings {
+            address,
+            out,
+            name,
+            rpc,
+        } => {
+            let mut abigen = client.abigen(parse_address(&address)?).with_rpc(rpc);
+
+            if let Some(name) = name {
+                abigen = abigen.with_name(name);
+            }
+
+            match out {
+                Some(path) => {
+                    abigen.write_to(&path).await?;
+                    eprintln!("wrote {}", path.display());
+                }
+                None => print!("{}", abigen.generate().await?),
+            }
+
+            Ok(())
+        }
+
+        Command::Interface {
+            address,
+            out,
+            human_readable,
+        } => {
+            let address = parse_address(&address)?;
+
+            let interface = match human_readable {
+                true => client
+                    .human_readable_abi(address)
+                    .await?
+                    .into_iter()
+                    .map(|line| format!("{line}\n"))
+                    .collect(),
+                false => client.solidity_interface(address).await?,
+            };
+
+            match out {
+                Some(path) => {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+
+                    fs::write(&path, interface)?;
+                    eprintln!("wrote {}", path.display());
+                }
+                None => print!("{interface}"),
+            }
+
+            Ok(())
+        }
+
+        Command::Source { address, out_dir } => {
+            let address = parse_address(&address)?;
+
+            match out_dir {
+                Some(dir) => {
+                    for path in client.download_sources(address, &dir).await? {
+                        eprintln!("wrote {}", path.display());
+                    }
+
+                    Ok(())
+                }
+                None => print(&client.contract_source(address).await?, format),
+            }
+        }
+
+        Command::Watch {
+            address,
+            min_confirmations,
+            token,
+            startblock,
+            interval,
+        } => {
+            let token = token.as_deref().map(parse_address).transpose()?;
+
+            let startblock = match startblock {
+                Some(startblock) => U256::from(startblock),
+                None => client.block_number().await?,
+            };
+
+            let shutdown = CancellationToken::new();
+
+            tokio::spawn({
+                let shutdown = shutdown.clone();
+
+                async move {
+                    if tokio::signal::ctrl_c().await.is_ok() {
+                        shutdown.cancel();
+                    }
+                }
+            });
+
+            let events = client
+                .watch(parse_address(&address)?)
+                .with_startblock(startblock)
+                .with_interval(Duration::from_secs(interval))
+                .with_min_confirmations(min_confirmations)
+                .with_transactions(token.is_none())
+                .with_shutdown(shutdown)
+                .stream();
+
+            futures::pin_mut!(events);
+
+            let mut writer = EventWriter::new(format);
+
+            while let Some(event) = events.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(e) => {
+                        eprintln!("error: {e}");
+                        continue;
+                    }
+                };
+
+                let (status, event) = match event {
+                    WatchEvent::Added(event) => ("added", event),
+                    WatchEvent::Amended { current, .. } => ("amended", current),
+                    WatchEvent::Retracted(event) => ("retracted", event),
+                };
+
+                if let Some(token) = token {
+                    match &event {
+                        HistoryEvent::Erc20(transfer)
+                        | HistoryEvent::Erc721(transfer)
+                        | HistoryEvent::Erc1155(transfer)
+                            if transfer.contract_address == token => {}
+                        _ => continue,
+                    }
+                }
+
+                writer.write(status, &event)?;
+            }
+
+            Ok(())
+        }
+
+        Command::Gas => print(&client.gas_oracle().await?, format),
+
+        Command::Supply { contract } => {
+            let supply: String = match contract {
+                Some(contract) => {
+                    client
+                        .fetch(EtherscanRequest::stats_token_supply(parse_address(
+                            &contract,
+                        )?))
+                        .await?
+                }
+                None => client.fetch(EtherscanRequest::stats_eth_supply()).await?,
+            };
+
+            print(&supply, format)
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(e) = run(Cli::parse()).await {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,218 @@
+use {
+    clap::ValueEnum,
+    etherscan::csv::CsvRecord,
+    serde::Serialize,
+    std::{error::Error, io},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Json,
+    Table,
+    Csv,
+}
+
+struct Rows {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+fn cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+impl Rows {
+    fn from_records<T: CsvRecord>(records: &[T]) -> Self {
+        Self {
+            headers: T::HEADERS.iter().map(|header| header.to_string()).collect(),
+            rows: records.iter().map(CsvRecord::record).collect(),
+        }
+    }
+
+    fn from_json(value: &serde_json::Value) -> Self {
+        let items = match value {
+            serde_json::Value::Array(items) => items.iter().collect::<Vec<_>>(),
+            value => vec![value],
+        };
+
+        let mut headers = Vec::<String>::new();
+
+        for item in &items {
+            if let serde_json::Value::Object(object) = item {
+                for key in object.keys() {
+                    if !headers.contains(key) {
+                        headers.push(key.clone());
+                    }
+                }
+            }
+        }
+
+        if headers.is_empty() {
+            return Self {
+                headers: vec!["value".to_string()],
+                rows: items.into_iter().map(|item| vec![cell(item)]).collect(),
+            };
+        }
+
+        let rows = items
+            .into_iter()
+            .map(|item| {
+                headers
+                    .iter()
+                    .map(|header| item.get(header).map(cell).unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+
+        Self { headers, rows }
+    }
+
+    fn write_table<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut widths = self.headers.iter().map(String::len).collect::<Vec<_>>();
+
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |cells: &[String]| {
+            cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        writeln!(writer, "{}", line(&self.headers))?;
+        writeln!(
+            writer,
+            "{}",
+            widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>()
+                .join("  ")
+        )?;
+
+        for row in &self.rows {
+            writeln!(writer, "{}", line(row))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_csv<W: io::Write>(&self, writer: W) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut writer = csv::Writer::from_writer(writer);
+
+        writer.write_record(&self.headers)?;
+
+        for row in &self.rows {
+            writer.write_record(row)?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn write(&self, format: OutputFormat) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let stdout = io::stdout().lock();
+
+        match format {
+            OutputFormat::Table => Ok(self.write_table(stdout)?),
+            _ => self.write_csv(stdout),
+        }
+    }
+}
+
+pub fn print<T: Serialize>(
+    value: &T,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(value)?);
+            Ok(())
+        }
+        format => Rows::from_json(&serde_json::to_value(value)?).write(format),
+    }
+}
+
+pub fn print_records<T: CsvRecord + Serialize>(
+    records: &[T],
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    match format {
+        OutputFormat::Json => print(&records, format),
+        format => Rows::from_records(records).write(format),
+    }
+}
+
+pub struct EventWriter {
+    format: OutputFormat,
+    header_written: bool,
+}
+
+impl EventWriter {
+    #[inline]
+    pub fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            header_written: false,
+        }
+    }
+
+    pub fn write<T: CsvRecord + Serialize>(
+        &mut self,
+        status: &str,
+        record: &T,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if self.format == OutputFormat::Json {
+            println!(
+                "{}",
+                serde_json::json!({ "status": status, "event": record })
+            );
+            return Ok(());
+        }
+
+        let mut headers = vec!["Status".to_string()];
+        headers.extend(T::HEADERS.iter().map(|header| header.to_string()));
+
+        let mut row = vec![status.to_string()];
+        row.extend(record.record());
+
+        let header_written = std::mem::replace(&mut self.header_written, true);
+
+        match self.format {
+            OutputFormat::Table => {
+                if !header_written {
+                    println!("{}", headers.join("  "));
+                }
+
+                println!("{}", row.join("  "));
+            }
+            _ => {
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(false)
+                    .from_writer(io::stdout().lock());
+
+                if !header_written {
+                    writer.write_record(&headers)?;
+                }
+
+                writer.write_record(&row)?;
+                writer.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+}
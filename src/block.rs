@@ -0,0 +1,154 @@
+use {
+    crate::{
+        retry, EtherscanAction, EtherscanClient, EtherscanClosest, EtherscanError, EtherscanRequest,
+    },
+    chrono::{DateTime, Utc},
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{error::Error, time::Duration},
+};
+
+const MIN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlockCountdown {
+    #[serde(rename = "CurrentBlock", with = "crate::serde_u256::decimal")]
+    pub current_block: U256,
+    #[serde(rename = "CountdownBlock", with = "crate::serde_u256::decimal")]
+    pub countdown_block: U256,
+    #[serde(rename = "RemainingBlock", with = "crate::serde_u256::decimal")]
+    pub remaining_block: U256,
+    #[serde(rename = "EstimateTimeInSec")]
+    pub estimate_time_in_sec: String,
+}
+
+impl BlockCountdown {
+    pub fn estimate(&self) -> Duration {
+        self.estimate_time_in_sec
+            .parse::<f64>()
+            .ok()
+            .filter(|secs| secs.is_finite() && *secs > 0.0)
+            .map(Duration::from_secs_f64)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BlockRange {
+    pub startblock: U256,
+    pub endblock: U256,
+}
+
+impl BlockRange {
+    #[inline]
+    pub fn new(startblock: U256, endblock: U256) -> Self {
+        Self {
+            startblock,
+            endblock,
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.endblock < self.startblock
+    }
+
+    #[inline]
+    pub fn contains(&self, block_number: U256) -> bool {
+        self.startblock <= block_number && block_number <= self.endblock
+    }
+
+    #[inline]
+    pub fn startblock(&self) -> Option<U256> {
+        Some(self.startblock)
+    }
+
+    #[inline]
+    pub fn endblock(&self) -> Option<U256> {
+        Some(self.endblock)
+    }
+
+    pub fn apply(&self, mut request: EtherscanRequest) -> EtherscanRequest {
+        match request.module_action {
+            Some((_, EtherscanAction::GetLogs)) => {
+                request.fromblock = self.startblock();
+                request.toblock = self.endblock();
+            }
+            _ => {
+                request.startblock = self.startblock();
+                request.endblock = self.endblock();
+            }
+        }
+
+        request
+    }
+}
+
+fn is_block_passed(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    match EtherscanError::find(error) {
+        Some(EtherscanError::Api { result, .. }) => result.to_lowercase().contains("already pass"),
+        _ => false,
+    }
+}
+
+impl EtherscanClient {
+    pub async fn block_number_by_time(
+        &self,
+        time: DateTime<Utc>,
+        closest: EtherscanClosest,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let block_number: String = self
+            .fetch(EtherscanRequest::block_get_number_by_timestamp(
+                U256::from(time.timestamp().max(0) as u64),
+                closest,
+            ))
+            .await?;
+
+        Ok(U256::from_dec_str(&block_number)?)
+    }
+
+    pub async fn blocks_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<BlockRange, Box<dyn Error + Send + Sync>> {
+        let startblock = self
+            .block_number_by_time(from, EtherscanClosest::After)
+            .await?;
+        let endblock = self
+            .block_number_by_time(to, EtherscanClosest::Before)
+            .await?;
+
+        Ok(BlockRange::new(startblock, endblock))
+    }
+
+    pub async fn block_countdown(
+        &self,
+        block_number: U256,
+    ) -> Result<Option<BlockCountdown>, Box<dyn Error + Send + Sync>> {
+        match self
+            .fetch(EtherscanRequest::block_get_countdown(block_number))
+            .await
+        {
+            Ok(countdown) => Ok(Some(countdown)),
+            Err(e) if is_block_passed(e.as_ref()) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn await_block(
+        &self,
+        block_number: U256,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        while let Some(countdown) = self.block_countdown(block_number).await? {
+            if countdown.remaining_block.is_zero() {
+                break;
+            }
+
+            let delay = (countdown.estimate() * 3 / 4).max(MIN_POLL_INTERVAL);
+            retry::sleep(delay, self.cancellation()).await?;
+        }
+
+        Ok(())
+    }
+}
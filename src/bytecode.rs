@@ -0,0 +1,79 @@
+use {
+    crate::{decode_hex, EtherscanClient, EtherscanRequest, EtherscanTag},
+    ethabi::ethereum_types::{H256, U256},
+    serde::{Deserialize, Serialize},
+    sha3::{Digest, Keccak256},
+    std::error::Error,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BytecodeComparison {
+    pub a: U256,
+    pub b: U256,
+    pub a_hash: H256,
+    pub b_hash: H256,
+    pub a_len: usize,
+    pub b_len: usize,
+    pub identical: bool,
+}
+
+pub fn keccak256(data: &[u8]) -> H256 {
+    H256(Keccak256::digest(data).into())
+}
+
+pub fn strip_metadata(code: &[u8]) -> &[u8] {
+    let Some(length) = code
+        .len()
+        .checked_sub(2)
+        .map(|i| u16::from_be_bytes([code[i], code[i + 1]]) as usize)
+    else {
+        return code;
+    };
+
+    match code.len().checked_sub(length + 2) {
+        Some(start) if length > 0 && (0xa0..=0xbf).contains(&code[start]) => &code[..start],
+        _ => code,
+    }
+}
+
+impl EtherscanClient {
+    pub async fn code(&self, address: U256) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        let code = self
+            .fetch_proxy::<String>(EtherscanRequest::proxy_get_code(
+                address,
+                Some(EtherscanTag::Latest),
+            ))
+            .await?
+            .unwrap_or_default();
+
+        decode_hex(&code)
+    }
+
+    pub async fn compare_bytecode(
+        &self,
+        a: U256,
+        b: U256,
+        ignore_metadata: bool,
+    ) -> Result<BytecodeComparison, Box<dyn Error + Send + Sync>> {
+        let a_code = self.code(a).await?;
+        let b_code = self.code(b).await?;
+
+        let (a_stripped, b_stripped) = match ignore_metadata {
+            true => (strip_metadata(&a_code), strip_metadata(&b_code)),
+            false => (a_code.as_slice(), b_code.as_slice()),
+        };
+
+        let a_hash = keccak256(a_stripped);
+        let b_hash = keccak256(b_stripped);
+
+        Ok(BytecodeComparison {
+            a,
+            b,
+            a_hash,
+            b_hash,
+            a_len: a_stripped.len(),
+            b_len: b_stripped.len(),
+            identical: !a_code.is_empty() && a_hash == b_hash,
+        })
+    }
+}
@@ -0,0 +1,519 @@
+use {
+    crate::{request_params, EtherscanAction, HttpRequest, HttpResponse},
+    std::{
+        collections::{BTreeMap, HashMap},
+        fmt,
+        sync::{Arc, Mutex},
+        time::{Duration, SystemTime},
+    },
+};
+
+pub fn cache_key(url: &str) -> String {
+    let base = url.split('?').next().unwrap_or(url);
+
+    let mut params = request_params(&HttpRequest::get(url.to_string()))
+        .into_iter()
+        .filter(|(key, _)| key != "apikey")
+        .map(
+            |(key, value)| match value.starts_with("0x") || value.starts_with("0X") {
+                true => (key, value.to_lowercase()),
+                false => (key, value),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    params.sort();
+
+    let query = params
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{base}?{query}")
+}
+
+#[derive(Clone, Debug)]
+pub struct CachedResponse {
+    pub response: HttpResponse,
+    pub expires_at: SystemTime,
+    pub stale_until: SystemTime,
+}
+
+impl CachedResponse {
+    #[inline]
+    pub fn new(response: HttpResponse, ttl: Duration) -> Self {
+        Self::with_stale(response, ttl, Duration::ZERO)
+    }
+
+    #[inline]
+    pub fn with_stale(response: HttpResponse, ttl: Duration, max_stale: Duration) -> Self {
+        let expires_at = SystemTime::now() + ttl;
+
+        Self {
+            response,
+            expires_at,
+            stale_until: expires_at + max_stale,
+        }
+    }
+
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= SystemTime::now()
+    }
+
+    #[inline]
+    pub fn is_unusable(&self) -> bool {
+        self.stale_until <= SystemTime::now()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CacheMode {
+    #[default]
+    Fresh,
+    StaleWhileRevalidate {
+        max_stale: Duration,
+    },
+}
+
+pub trait ResponseCache: Send + Sync {
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+    fn insert(&self, key: String, entry: CachedResponse);
+    fn remove(&self, key: &str);
+    fn clear(&self);
+}
+
+impl<C: ResponseCache + ?Sized> ResponseCache for Arc<C> {
+    #[inline]
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        (**self).get(key)
+    }
+
+    #[inline]
+    fn insert(&self, key: String, entry: CachedResponse) {
+        (**self).insert(key, entry)
+    }
+
+    #[inline]
+    fn remove(&self, key: &str) {
+        (**self).remove(key)
+    }
+
+    #[inline]
+    fn clear(&self) {
+        (**self).clear()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CachePolicy {
+    pub mode: CacheMode,
+    pub default_ttl: Option<Duration>,
+    pub ttls: HashMap<EtherscanAction, Option<Duration>>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        const HOURS: Duration = Duration::from_secs(6 * 60 * 60);
+        const SECONDS: Duration = Duration::from_secs(15);
+
+        Self {
+            mode: CacheMode::Fresh,
+            default_ttl: None,
+            ttls: HashMap::from([
+                (EtherscanAction::GetABI, Some(HOURS)),
+                (EtherscanAction::GetSourceCode, Some(HOURS)),
+                (EtherscanAction::GetContractCreation, Some(HOURS)),
+                (EtherscanAction::GetAddressTag, Some(HOURS)),
+                (EtherscanAction::Balance, Some(SECONDS)),
+                (EtherscanAction::BalanceMulti, Some(SECONDS)),
+                (EtherscanAction::TokenBalance, Some(SECONDS)),
+                (EtherscanAction::AddressTokenBalance, Some(SECONDS)),
+                (EtherscanAction::AddressTokenNftBalance, Some(SECONDS)),
+                (EtherscanAction::AddressTokenNftInventory, Some(SECONDS)),
+                (EtherscanAction::GetStatus, Some(SECONDS)),
+                (EtherscanAction::GetTxReceiptStatus, Some(SECONDS)),
+                (EtherscanAction::GetBlockCountdown, None),
+                (EtherscanAction::CheckVerifyStatus, None),
+                (EtherscanAction::GasOracle, None),
+                (EtherscanAction::EthPrice, Some(SECONDS)),
+            ]),
+        }
+    }
+}
+
+impl CachePolicy {
+    #[inline]
+    pub fn none() -> Self {
+        Self {
+            mode: CacheMode::Fresh,
+            default_ttl: None,
+            ttls: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_mode(mut self, mode: CacheMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[inline]
+    pub fn stale_while_revalidate(self, max_stale: Duration) -> Self {
+        self.with_mode(CacheMode::StaleWhileRevalidate { max_stale })
+    }
+
+    #[inline]
+    pub fn max_stale(&self) -> Duration {
+        match self.mode {
+            CacheMode::Fresh => Duration::ZERO,
+            CacheMode::StaleWhileRevalidate { max_stale } => max_stale,
+        }
+    }
+
+    #[inline]
+    pub fn with_default_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    #[inline]
+    pub fn with_ttl(mut self, action: EtherscanAction, ttl: Option<Duration>) -> Self {
+        self.ttls.insert(action, ttl);
+        self
+    }
+
+    #[inline]
+    pub fn ttl(&self, action: Option<EtherscanAction>) -> Option<Duration> {
+        match action.and_then(|action| self.ttls.get(&action)) {
+            Some(ttl) => *ttl,
+            None => self.default_ttl,
+        }
+    }
+}
+
+#[derive(Default)]
+struct LruEntries {
+    entries: HashMap<String, (CachedResponse, u64)>,
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl LruEntries {
+    fn touch(&mut self, key: &str) -> Option<&CachedResponse> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (entry, last_used) = self.entries.get_mut(key)?;
+
+        self.order.remove(last_used);
+        self.order.insert(tick, key.to_string());
+        *last_used = tick;
+
+        Some(entry)
+    }
+
+    fn remove(&mut self, key: &str) -> Option<CachedResponse> {
+        let (entry, last_used) = self.entries.remove(key)?;
+        self.order.remove(&last_used);
+        Some(entry)
+    }
+
+    fn evict_lru(&mut self) {
+        if let Some((_, key)) = self.order.pop_first() {
+            self.entries.remove(&key);
+        }
+    }
+
+    fn purge_unusable(&mut self) {
+        let order = &mut self.order;
+
+        self.entries.retain(|_, (entry, last_used)| {
+            let keep = !entry.is_unusable();
+
+            if !keep {
+                order.remove(last_used);
+            }
+
+            keep
+        });
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryCache {
+    capacity: Option<usize>,
+    entries: Mutex<LruEntries>,
+}
+
+impl fmt::Debug for MemoryCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryCache")
+            .field("capacity", &self.capacity)
+            .field("entries", &self.len())
+            .finish()
+    }
+}
+
+impl MemoryCache {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity.max(1)),
+            entries: Mutex::default(),
+        }
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn purge_expired(&self) {
+        self.entries.lock().unwrap().purge_unusable();
+    }
+}
+
+impl ResponseCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.touch(key) {
+            Some(entry) if entry.is_unusable() => {
+                entries.remove(key);
+                None
+            }
+            entry => entry.cloned(),
+        }
+    }
+
+    fn insert(&self, key: String, entry: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap();
+
+        entries.remove(&key);
+
+        if entries.entries.len() % 256 == 255 {
+            entries.purge_unusable();
+        }
+
+        if let Some(capacity) = self.capacity {
+            while entries.entries.len() >= capacity {
+                entries.evict_lru();
+            }
+        }
+
+        entries.tick += 1;
+        let tick = entries.tick;
+        entries.order.insert(tick, key.clone());
+        entries.entries.insert(key, (entry, tick));
+    }
+
+    #[inline]
+    fn remove(&self, key: &str) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    #[inline]
+    fn clear(&self) {
+        *self.entries.lock().unwrap() = LruEntries::default();
+    }
+}
+
+#[cfg(feature = "disk-cache")]
+mod disk {
+    use {
+        super::{CachedResponse, ResponseCache},
+        crate::{keccak256, HttpResponse},
+        serde::{Deserialize, Serialize},
+        std::{
+            fs, io,
+            path::{Path, PathBuf},
+            process,
+            sync::atomic::{AtomicU64, Ordering},
+            time::{Duration, SystemTime, UNIX_EPOCH},
+        },
+    };
+
+    #[derive(Deserialize, Serialize)]
+    struct DiskEntry {
+        expires_at: u64,
+        #[serde(default)]
+        stale_until: Option<u64>,
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    /// A [`ResponseCache`] backed by one JSON file per key.
+    ///
+    /// `ResponseCache` is synchronous, so reads and writes use blocking
+    /// `std::fs` calls on the request path; keep the directory on local disk.
+    #[derive(Clone, Debug)]
+    pub struct DiskCache {
+        dir: PathBuf,
+    }
+
+    impl DiskCache {
+        pub fn new<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+            fs::create_dir_all(dir.as_ref())?;
+
+            Ok(Self {
+                dir: dir.as_ref().to_path_buf(),
+            })
+        }
+
+        #[inline]
+        pub fn dir(&self) -> &Path {
+            &self.dir
+        }
+
+        fn path(&self, key: &str) -> PathBuf {
+            let hash = keccak256(key.as_bytes());
+
+            let name = hash
+                .as_bytes()
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<String>();
+            self.dir.join(format!("{name}.json"))
+        }
+
+        pub fn purge_expired(&self) -> io::Result<()> {
+            for entry in fs::read_dir(&self.dir)? {
+                let path = entry?.path();
+
+                if !is_entry(&path) {
+                    continue;
+                }
+
+                let expired = fs::read(&path)
+                    .ok()
+                    .and_then(|bytes| serde_json::from_slice::<DiskEntry>(&bytes).ok())
+                    .is_some_and(|entry| {
+                        let stale_until = entry.stale_until.unwrap_or(entry.expires_at);
+                        UNIX_EPOCH + Duration::from_secs(stale_until) <= SystemTime::now()
+                    });
+
+                if expired {
+                    fs::remove_file(path)?;
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    fn is_key_hash(name: &str) -> bool {
+        name.len() == 64 && name.bytes().all(|b| b.is_ascii_hexdigit())
+    }
+
+    fn is_entry(path: &Path) -> bool {
+        path.extension().and_then(|x| x.to_str()) == Some("json")
+            && path
+                .file_stem()
+                .and_then(|x| x.to_str())
+                .is_some_and(is_key_hash)
+    }
+
+    fn is_temp(path: &Path) -> bool {
+        path.extension().and_then(|x| x.to_str()) == Some("tmp")
+            && path
+                .file_name()
+                .and_then(|x| x.to_str())
+                .and_then(|name| name.split_once('.'))
+                .is_some_and(|(name, _)| is_key_hash(name))
+    }
+
+    impl ResponseCache for DiskCache {
+        fn get(&self, key: &str) -> Option<CachedResponse> {
+            let entry =
+                serde_json::from_slice::<DiskEntry>(&fs::read(self.path(key)).ok()?).ok()?;
+
+            Some(CachedResponse {
+                response: HttpResponse {
+                    status: entry.status,
+                    headers: entry.headers,
+                    body: entry.body.into_bytes(),
+                },
+                expires_at: UNIX_EPOCH + Duration::from_secs(entry.expires_at),
+                stale_until: UNIX_EPOCH
+                    + Duration::from_secs(entry.stale_until.unwrap_or(entry.expires_at)),
+            })
+        }
+
+        fn insert(&self, key: String, entry: CachedResponse) {
+            let Ok(body) = String::from_utf8(entry.response.body) else {
+                return;
+            };
+
+            let entry = DiskEntry {
+                expires_at: entry
+                    .expires_at
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs(),
+                stale_until: Some(
+                    entry
+                        .stale_until
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs(),
+                ),
+                status: entry.response.status,
+                headers: entry.response.headers,
+                body,
+            };
+
+            static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+            let path = self.path(&key);
+            let temp = path.with_extension(format!(
+                "{}.{}.tmp",
+                process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+
+            if let Ok(bytes) = serde_json::to_vec(&entry) {
+                if fs::write(&temp, bytes).is_ok() {
+                    let _ = fs::rename(&temp, &path);
+                }
+            }
+        }
+
+        #[inline]
+        fn remove(&self, key: &str) {
+            let _ = fs::remove_file(self.path(key));
+        }
+
+        fn clear(&self) {
+            if let Ok(entries) = fs::read_dir(&self.dir) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+
+                    if is_entry(&path) || is_temp(&path) {
+                        let _ = fs::remove_file(path);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "disk-cache")]
+pub use disk::DiskCache;
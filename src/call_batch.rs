@@ -0,0 +1,87 @@
+use {
+    crate::EtherscanClient,
+    ethabi::{ethereum_types::U256, Function, Token},
+    std::error::Error,
+};
+
+#[derive(Clone, Debug)]
+pub struct ReadCall {
+    pub address: U256,
+    pub function: Function,
+    pub args: Vec<Token>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CallBatch {
+    client: EtherscanClient,
+    concurrency: usize,
+    calls: Vec<ReadCall>,
+}
+
+impl CallBatch {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            concurrency: 4,
+            calls: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    #[inline]
+    pub fn with_call(mut self, address: U256, function: Function, args: Vec<Token>) -> Self {
+        self.add(address, function, args);
+        self
+    }
+
+    #[inline]
+    pub fn add(&mut self, address: U256, function: Function, args: Vec<Token>) -> usize {
+        self.calls.push(ReadCall {
+            address,
+            function,
+            args,
+        });
+
+        self.calls.len() - 1
+    }
+
+    #[inline]
+    pub fn calls(&self) -> &[ReadCall] {
+        &self.calls
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    pub async fn execute(&self) -> Vec<Result<Vec<Token>, Box<dyn Error + Send + Sync>>> {
+        self.client
+            .batch(self.concurrency)
+            .run(&self.calls, |client, call| async move {
+                let data = call.function.encode_input(&call.args)?;
+                let result = client.call(call.address, &data).await?;
+
+                Ok(call.function.decode_output(&result)?)
+            })
+            .await
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn call_batch(&self) -> CallBatch {
+        CallBatch::new(self.clone())
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+#[derive(Default)]
+struct Wakers {
+    next: u64,
+    slots: HashMap<u64, Waker>,
+}
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Wakers>,
+}
+
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    state: Arc<CancellationState>,
+}
+
+impl CancellationToken {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::SeqCst);
+
+        for (_, waker) in self.state.wakers.lock().unwrap().slots.drain() {
+            waker.wake();
+        }
+    }
+
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+
+    #[inline]
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+            slot: None,
+        }
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+pub struct Cancelled {
+    token: CancellationToken,
+    slot: Option<u64>,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let this = &mut *self;
+        let mut wakers = this.token.state.wakers.lock().unwrap();
+
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        match this.slot.and_then(|slot| wakers.slots.get_mut(&slot)) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            Some(waker) => *waker = cx.waker().clone(),
+            None => {
+                let slot = wakers.next;
+                wakers.next += 1;
+                wakers.slots.insert(slot, cx.waker().clone());
+                this.slot = Some(slot);
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            self.token.state.wakers.lock().unwrap().slots.remove(&slot);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use {
+    crate::EtherscanError,
+    std::{
+        sync::Mutex,
+        time::{Duration, Instant},
+    },
+};
+
+#[derive(Debug, Default)]
+struct CircuitState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Mutex<CircuitState>,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(30))
+    }
+}
+
+impl CircuitBreaker {
+    #[inline]
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cooldown,
+            state: Mutex::default(),
+        }
+    }
+
+    fn rejection(&self, state: &CircuitState) -> Option<EtherscanError> {
+        let elapsed = state.opened_at?.elapsed();
+
+        (elapsed < self.cooldown || state.probing).then(|| EtherscanError::CircuitOpen {
+            retry_in: self.cooldown.saturating_sub(elapsed),
+        })
+    }
+
+    pub fn ready(&self) -> Result<(), EtherscanError> {
+        match self.rejection(&self.state.lock().unwrap()) {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    pub fn check(&self) -> Result<CircuitProbe<'_>, EtherscanError> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(e) = self.rejection(&state) {
+            return Err(e);
+        }
+
+        let probing = state.opened_at.is_some();
+        state.probing |= probing;
+
+        Ok(CircuitProbe {
+            breaker: self,
+            probing,
+            recorded: false,
+        })
+    }
+
+    pub fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        *state = CircuitState::default();
+    }
+
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+
+        if state.probing || state.consecutive_failures >= self.threshold {
+            state.opened_at = Some(Instant::now());
+            state.probing = false;
+        }
+    }
+
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        self.state.lock().unwrap().opened_at.is_some()
+    }
+
+    pub fn retry_in(&self) -> Option<Duration> {
+        let opened_at = self.state.lock().unwrap().opened_at?;
+        Some(self.cooldown.saturating_sub(opened_at.elapsed()))
+    }
+
+    #[inline]
+    pub fn consecutive_failures(&self) -> u32 {
+        self.state.lock().unwrap().consecutive_failures
+    }
+}
+
+#[derive(Debug)]
+pub struct CircuitProbe<'a> {
+    breaker: &'a CircuitBreaker,
+    probing: bool,
+    recorded: bool,
+}
+
+impl CircuitProbe<'_> {
+    #[inline]
+    pub fn is_probe(&self) -> bool {
+        self.probing
+    }
+
+    #[inline]
+    pub fn success(mut self) {
+        self.recorded = true;
+        self.breaker.record_success();
+    }
+
+    #[inline]
+    pub fn failure(mut self) {
+        self.recorded = true;
+        self.breaker.record_failure();
+    }
+}
+
+impl Drop for CircuitProbe<'_> {
+    fn drop(&mut self) {
+        if self.probing && !self.recorded {
+            self.breaker.state.lock().unwrap().probing = false;
+        }
+    }
+}
@@ -0,0 +1,1173 @@
+use {
+    crate::{
+        cache::{cache_key, CachePolicy, CachedResponse, ResponseCache},
+        is_v1_deprecated, is_v2_url, legacy_chainid, quota, redact_url,
+        retry::{self, ExponentialBackoff, RetryBudget, RetryCause, RetryPolicy},
+        transport::{connection_transport, default_transport},
+        url_apikey, AccountHistory, AddressWatcher, ApiKey, ApiKeyPool, BatchExecutor, BatchReport,
+        CancellationToken, CircuitBreaker, ConnectionOptions, CorrelatedError, EndpointHealth,
+        Endpoints, EtherscanAction, EtherscanError, EtherscanFormat, EtherscanModule,
+        EtherscanRequest, EtherscanResponse, EtherscanSort, EtherscanTag, HttpMethod, HttpRequest,
+        HttpResponse, InFlightRequests, KeyProvider, Metrics, ProxyResponse, QuotaStatus,
+        QuotaTracker, RateLimiter, RequestMetrics, RequestMiddleware, RequestOutcome,
+        RequestPriority, ResponseInfo, ResponseMiddleware, Spawner, TokenTransferWatcher,
+        Transport, DEFAULT_BATCH_CONCURRENCY, DEFAULT_SOURCIFY_URL,
+    },
+    ethabi::ethereum_types::U256,
+    serde::de::DeserializeOwned,
+    std::{
+        collections::{hash_map::RandomState, HashMap, HashSet},
+        env,
+        error::Error,
+        fmt,
+        hash::{BuildHasher, Hasher},
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, Mutex,
+        },
+        time::{Duration, Instant},
+    },
+};
+
+pub const DEFAULT_API_URL: &str = "https://api.etherscan.io/api";
+pub const DEFAULT_API_V2_URL: &str = "https://api.etherscan.io/v2/api";
+
+#[derive(Clone)]
+pub struct EtherscanClient {
+    url: String,
+    chainid: Option<u64>,
+    apikey: Option<ApiKey>,
+    apikeys: Option<Arc<ApiKeyPool>>,
+    key_provider: Option<Arc<dyn KeyProvider>>,
+    transport: Arc<dyn Transport>,
+    request_middleware: Vec<Arc<dyn RequestMiddleware>>,
+    response_middleware: Vec<Arc<dyn ResponseMiddleware>>,
+    cancellation: Option<CancellationToken>,
+    retry_policy: Arc<dyn RetryPolicy>,
+    retry_budget: Option<Arc<RetryBudget>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    priority: RequestPriority,
+    quota: Option<Arc<QuotaTracker>>,
+    in_flight: Option<Arc<InFlightRequests>>,
+    cache: Option<(Arc<dyn ResponseCache>, Arc<CachePolicy>)>,
+    spawner: Option<Arc<dyn Spawner>>,
+    revalidating: Arc<Mutex<HashSet<String>>>,
+    metrics: Vec<Arc<dyn Metrics>>,
+    endpoints: Option<Arc<Endpoints>>,
+    v2_fallback: bool,
+    sourcify_url: Option<String>,
+    default_sort: Option<EtherscanSort>,
+    default_offset: Option<U256>,
+    default_tag: Option<EtherscanTag>,
+    #[cfg(feature = "tracing")]
+    debug_body_limit: Option<usize>,
+}
+
+impl Default for EtherscanClient {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_API_URL.into(),
+            chainid: None,
+            apikey: None,
+            apikeys: None,
+            key_provider: None,
+            transport: default_transport(),
+            request_middleware: Vec::new(),
+            response_middleware: Vec::new(),
+            cancellation: None,
+            retry_policy: Arc::new(ExponentialBackoff::default()),
+            retry_budget: None,
+            circuit_breaker: None,
+            rate_limiter: None,
+            priority: RequestPriority::Interactive,
+            quota: None,
+            in_flight: None,
+            cache: None,
+            spawner: None,
+            revalidating: Default::default(),
+            metrics: Vec::new(),
+            endpoints: None,
+            v2_fallback: false,
+            sourcify_url: None,
+            default_sort: None,
+            default_offset: None,
+            default_tag: None,
+            #[cfg(feature = "tracing")]
+            debug_body_limit: None,
+        }
+    }
+}
+
+impl fmt::Debug for EtherscanClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EtherscanClient")
+            .field("url", &self.url)
+            .field("chainid", &self.chainid)
+            .field("apikey", &self.apikey)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_env() -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let apikey = env::var("ETHERSCAN_API_KEY").map_err(|_| "ETHERSCAN_API_KEY is not set")?;
+
+        let chainid = match env::var("ETHERSCAN_CHAIN") {
+            Ok(chain) => Some(
+                chain
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|e| format!("invalid ETHERSCAN_CHAIN {chain:?}: {e}"))?,
+            ),
+            Err(_) => None,
+        };
+
+        let url = match env::var("ETHERSCAN_API_URL") {
+            Ok(url) => url,
+            Err(_) if chainid.is_some() => DEFAULT_API_V2_URL.into(),
+            Err(_) => DEFAULT_API_URL.into(),
+        };
+
+        Ok(Self {
+            url,
+            chainid,
+            apikey: Some(apikey.into()),
+            ..Default::default()
+        })
+    }
+
+    #[inline]
+    pub fn with_apikey(mut self, apikey: String) -> Self {
+        self.apikey = Some(apikey.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_apikeys(mut self, apikeys: ApiKeyPool) -> Self {
+        self.apikeys = Some(Arc::new(apikeys));
+        self
+    }
+
+    #[inline]
+    pub fn apikeys(&self) -> Option<&ApiKeyPool> {
+        self.apikeys.as_deref()
+    }
+
+    #[inline]
+    pub fn with_key_provider<P: KeyProvider + 'static>(mut self, key_provider: P) -> Self {
+        self.key_provider = Some(Arc::new(key_provider));
+        self
+    }
+
+    #[inline]
+    pub fn key_provider(&self) -> Option<&dyn KeyProvider> {
+        self.key_provider.as_deref()
+    }
+
+    #[inline]
+    pub fn with_chainid(mut self, chainid: u64) -> Self {
+        self.chainid = Some(chainid);
+        self
+    }
+
+    #[inline]
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    #[inline]
+    pub fn with_connection_options(
+        mut self,
+        options: ConnectionOptions,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        self.transport = connection_transport(&options)?;
+        Ok(self)
+    }
+
+    #[inline]
+    pub fn with_request_middleware<M: RequestMiddleware + 'static>(
+        mut self,
+        middleware: M,
+    ) -> Self {
+        self.request_middleware.push(Arc::new(middleware));
+        self
+    }
+
+    #[inline]
+    pub fn with_response_middleware<M: ResponseMiddleware + 'static>(
+        mut self,
+        middleware: M,
+    ) -> Self {
+        self.response_middleware.push(Arc::new(middleware));
+        self
+    }
+
+    #[inline]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    #[inline]
+    pub fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    #[inline]
+    pub fn with_retry_policy<P: RetryPolicy + 'static>(mut self, policy: P) -> Self {
+        self.retry_policy = Arc::new(policy);
+        self
+    }
+
+    #[inline]
+    pub fn with_retry_budget(mut self, retry_budget: RetryBudget) -> Self {
+        self.retry_budget = Some(Arc::new(retry_budget));
+        self
+    }
+
+    #[inline]
+    pub fn retry_budget(&self) -> Option<&RetryBudget> {
+        self.retry_budget.as_deref()
+    }
+
+    #[inline]
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(Arc::new(circuit_breaker));
+        self
+    }
+
+    #[inline]
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_deref()
+    }
+
+    #[inline]
+    pub fn with_rate_limit(self, requests_per_second: u32) -> Self {
+        self.with_rate_limiter(RateLimiter::per_second(requests_per_second))
+    }
+
+    #[inline]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    #[inline]
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    #[cfg(feature = "tracing")]
+    #[inline]
+    pub fn with_debug_logging(mut self, max_body_len: usize) -> Self {
+        self.debug_body_limit = Some(max_body_len);
+        self
+    }
+
+    #[inline]
+    pub fn with_priority(mut self, priority: RequestPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    #[inline]
+    pub fn priority(&self) -> RequestPriority {
+        self.priority
+    }
+
+    #[inline]
+    pub fn with_default_sort(mut self, sort: EtherscanSort) -> Self {
+        self.default_sort = Some(sort);
+        self
+    }
+
+    #[inline]
+    pub fn with_default_offset(mut self, offset: u64) -> Self {
+        self.default_offset = Some(U256::from(offset));
+        self
+    }
+
+    #[inline]
+    pub fn with_default_tag(mut self, tag: EtherscanTag) -> Self {
+        self.default_tag = Some(tag);
+        self
+    }
+
+    #[inline]
+    pub fn with_request_coalescing(mut self, enabled: bool) -> Self {
+        self.in_flight = enabled.then(Default::default);
+        self
+    }
+
+    #[inline]
+    pub fn with_cache<C: ResponseCache + 'static>(mut self, cache: C, policy: CachePolicy) -> Self {
+        self.cache = Some((Arc::new(cache), Arc::new(policy)));
+        self
+    }
+
+    #[inline]
+    pub fn cache(&self) -> Option<&dyn ResponseCache> {
+        self.cache.as_ref().map(|(cache, _)| &**cache)
+    }
+
+    pub fn invalidate(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if let Some((cache, _)) = &self.cache {
+            cache.remove(&cache_key(&self.prepare(request).to_url()?));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn clear_cache(&self) {
+        if let Some((cache, _)) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    #[inline]
+    pub fn with_spawner<S: Spawner + 'static>(mut self, spawner: S) -> Self {
+        self.spawner = Some(Arc::new(spawner));
+        self
+    }
+
+    #[inline]
+    pub fn with_quota(mut self, quota: QuotaTracker) -> Self {
+        self.quota = Some(Arc::new(quota));
+        self
+    }
+
+    #[inline]
+    pub fn quota_tracker(&self) -> Option<&QuotaTracker> {
+        self.quota.as_deref()
+    }
+
+    pub fn quota(&self) -> QuotaStatus {
+        QuotaStatus {
+            tokens_remaining: self
+                .rate_limiter
+                .as_ref()
+                .map(|limiter| limiter.available()),
+            rate_limit_delay: self
+                .rate_limiter
+                .as_ref()
+                .map(|limiter| limiter.delay())
+                .unwrap_or_default(),
+            daily_limit: self.quota.as_ref().map(|quota| quota.daily_limit()),
+            calls_today: self
+                .quota
+                .as_ref()
+                .map(|quota| quota.usage_by_key())
+                .unwrap_or_default(),
+            resets_in: quota::until_reset(),
+            backoff: self
+                .circuit_breaker
+                .as_ref()
+                .and_then(|breaker| breaker.retry_in()),
+        }
+    }
+
+    #[inline]
+    pub fn with_metrics<M: Metrics + 'static>(mut self, metrics: M) -> Self {
+        self.metrics.push(Arc::new(metrics));
+        self
+    }
+
+    #[inline]
+    pub fn with_fallback_urls(self, fallback_urls: Vec<String>) -> Self {
+        let urls = std::iter::once(self.url.clone())
+            .chain(fallback_urls)
+            .collect();
+
+        self.with_endpoints(Endpoints::new(urls))
+    }
+
+    pub fn with_endpoints(mut self, endpoints: Endpoints) -> Self {
+        if let Some(url) = endpoints.urls().first() {
+            self.url = url.clone();
+        }
+
+        self.endpoints = Some(Arc::new(endpoints));
+        self
+    }
+
+    #[inline]
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        match &self.endpoints {
+            Some(endpoints) => endpoints.health(),
+            None => Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_v2_fallback(mut self, v2_fallback: bool) -> Self {
+        self.v2_fallback = v2_fallback;
+        self
+    }
+
+    #[inline]
+    pub fn batch(&self, concurrency: usize) -> BatchExecutor {
+        BatchExecutor::new(self.clone(), concurrency)
+    }
+
+    #[inline]
+    pub async fn execute_all<I>(&self, requests: I) -> BatchReport
+    where
+        I: IntoIterator<Item = EtherscanRequest>,
+    {
+        self.batch(DEFAULT_BATCH_CONCURRENCY)
+            .execute_all(requests)
+            .await
+    }
+
+    #[inline]
+    pub fn history(&self, address: U256) -> AccountHistory {
+        AccountHistory::new(self.clone(), address)
+    }
+
+    #[inline]
+    pub fn watch(&self, address: U256) -> AddressWatcher {
+        AddressWatcher::new(self.clone(), address)
+    }
+
+    #[inline]
+    pub fn token_transfers(&self, contract: U256) -> TokenTransferWatcher {
+        TokenTransferWatcher::new(self.clone(), contract)
+    }
+
+    #[inline]
+    pub async fn for_each_address<T, I, F>(
+        &self,
+        addresses: I,
+        concurrency: usize,
+        request_fn: F,
+    ) -> HashMap<U256, Result<T, Box<dyn Error + Send + Sync>>>
+    where
+        T: DeserializeOwned,
+        I: IntoIterator<Item = U256>,
+        F: Fn(U256) -> EtherscanRequest,
+    {
+        self.batch(concurrency)
+            .for_each_address(addresses, request_fn)
+            .await
+    }
+
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    #[inline]
+    pub fn transport(&self) -> &dyn Transport {
+        &*self.transport
+    }
+
+    #[inline]
+    pub(crate) fn transport_arc(&self) -> Arc<dyn Transport> {
+        self.transport.clone()
+    }
+
+    #[inline]
+    pub fn spawner(&self) -> Option<&dyn Spawner> {
+        self.spawner.as_deref()
+    }
+
+    #[inline]
+    pub fn with_sourcify_fallback(mut self, enabled: bool) -> Self {
+        self.sourcify_url = enabled.then(|| DEFAULT_SOURCIFY_URL.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_sourcify_url(mut self, url: String) -> Self {
+        self.sourcify_url = Some(url);
+        self
+    }
+
+    #[inline]
+    pub fn sourcify_url(&self) -> Option<&str> {
+        self.sourcify_url.as_deref()
+    }
+
+    #[inline]
+    pub fn chainid(&self) -> Option<u64> {
+        self.chainid
+    }
+
+    pub fn prepare(&self, mut request: EtherscanRequest) -> EtherscanRequest {
+        if request.url.is_none() {
+            request.url = Some(self.url.clone());
+        }
+
+        if request.chainid.is_none() {
+            request.chainid = self.chainid;
+        }
+
+        if request.apikey.is_none() && self.key_provider.is_none() {
+            request.apikey = self.default_key();
+        }
+
+        if request.correlation_id.is_none() {
+            request.correlation_id = Some(correlation_id());
+        }
+
+        if let Some((_, action)) = request.module_action {
+            if action.accepts_sort() && request.sort.is_none() {
+                request.sort = self.default_sort;
+            }
+
+            if action.accepts_offset() && request.offset.is_none() {
+                request.offset = self.default_offset;
+            }
+
+            if action.accepts_tag() && request.tag.is_none() {
+                request.tag = self.default_tag;
+            }
+        }
+
+        request
+    }
+
+    fn default_key(&self) -> Option<ApiKey> {
+        self.apikeys
+            .as_ref()
+            .and_then(|apikeys| apikeys.next_key())
+            .or_else(|| self.apikey.clone())
+    }
+
+    async fn provide_key(
+        &self,
+        mut request: EtherscanRequest,
+    ) -> Result<EtherscanRequest, Box<dyn Error + Send + Sync>> {
+        if let (None, Some(key_provider)) = (&request.apikey, &self.key_provider) {
+            request.apikey = match key_provider.key().await? {
+                Some(apikey) => Some(apikey),
+                None => self.default_key(),
+            };
+        }
+
+        Ok(request)
+    }
+
+    pub async fn execute(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        let request = self.prepare(request);
+        let context = request.context();
+        let request = self
+            .provide_key(request)
+            .await
+            .map_err(|e| CorrelatedError::wrap(&context, e))?;
+
+        if let Err(e) = request.check_window() {
+            return Err(CorrelatedError::wrap(&context, Box::new(e)));
+        }
+
+        let response = self
+            .execute_prepared(request.clone())
+            .await
+            .map_err(|e| CorrelatedError::wrap(&context, e))?;
+
+        if self.v2_fallback && is_v1_deprecated(&response) {
+            if let Some(request) = v2_request(request) {
+                return self
+                    .execute_prepared(request)
+                    .await
+                    .map_err(|e| CorrelatedError::wrap(&context, e));
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Sends `request` and returns the response it produced, bypassing the
+    /// response cache and the V2 fallback rewrite.
+    pub async fn execute_raw(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        let request = self.prepare(request);
+        let context = request.context();
+
+        let result = async {
+            let request = self.provide_key(request).await?;
+            request.check_window()?;
+
+            if request.method() == HttpMethod::Post {
+                return self.execute_prepared(request).await;
+            }
+
+            self.execute_url(
+                request.to_url()?,
+                request.module_action,
+                request.correlation_id.clone(),
+            )
+            .await
+        };
+
+        result.await.map_err(|e| CorrelatedError::wrap(&context, e))
+    }
+
+    async fn execute_prepared(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        let module_action = request.module_action;
+        let action = module_action.map(|(_, action)| action);
+        let correlation_id = request.correlation_id.clone();
+        let started = Instant::now();
+
+        if request.method() == HttpMethod::Post {
+            let result = self.send(request.to_http_request()?).await;
+            self.record_metrics(
+                module_action,
+                correlation_id.as_deref(),
+                started,
+                RequestOutcome::of(&result),
+                0,
+            );
+            return result;
+        }
+
+        let url = request.to_url()?;
+
+        let ttl = match &self.cache {
+            Some((cache, policy)) => match policy.ttl(action) {
+                Some(ttl) => {
+                    if let Some(entry) = cache.get(&cache_key(&url)) {
+                        if !entry.is_expired()
+                            || (!entry.is_unusable()
+                                && self.revalidate(url.clone(), module_action, ttl))
+                        {
+                            self.record_metrics(
+                                module_action,
+                                correlation_id.as_deref(),
+                                started,
+                                RequestOutcome::CacheHit,
+                                0,
+                            );
+                            return Ok(entry.response);
+                        }
+                    }
+
+                    Some(ttl)
+                }
+                None => None,
+            },
+            None => None,
+        };
+
+        let response = self
+            .execute_coalesced(url.clone(), module_action, correlation_id)
+            .await?;
+
+        if let Some(ttl) = ttl {
+            self.store(url, &response, ttl);
+        }
+
+        Ok(response)
+    }
+
+    fn store(&self, url: String, response: &HttpResponse, ttl: Duration) {
+        if let Some((cache, policy)) = &self.cache {
+            if is_cacheable(response) {
+                cache.insert(
+                    cache_key(&url),
+                    CachedResponse::with_stale(response.clone(), ttl, policy.max_stale()),
+                );
+            }
+        }
+    }
+
+    fn revalidate(
+        &self,
+        url: String,
+        module_action: Option<(EtherscanModule, EtherscanAction)>,
+        ttl: Duration,
+    ) -> bool {
+        let Some(spawner) = &self.spawner else {
+            return false;
+        };
+
+        if !self.revalidating.lock().unwrap().insert(url.clone()) {
+            return true;
+        }
+
+        let client = self.clone();
+
+        spawner.spawn(Box::pin(async move {
+            if let Ok(response) = client
+                .execute_coalesced(url.clone(), module_action, None)
+                .await
+            {
+                client.store(url.clone(), &response, ttl);
+            }
+
+            client.revalidating.lock().unwrap().remove(&url);
+        }));
+
+        true
+    }
+
+    fn record_metrics(
+        &self,
+        module_action: Option<(EtherscanModule, EtherscanAction)>,
+        correlation_id: Option<&str>,
+        started: Instant,
+        outcome: RequestOutcome,
+        retries: u32,
+    ) {
+        if self.metrics.is_empty() {
+            return;
+        }
+
+        let metrics = RequestMetrics {
+            module: module_action.map(|(module, _)| module),
+            action: module_action.map(|(_, action)| action),
+            duration: started.elapsed(),
+            outcome,
+            retries,
+            correlation_id: correlation_id.map(str::to_string),
+        };
+
+        for sink in &self.metrics {
+            sink.record(&metrics);
+        }
+    }
+
+    async fn execute_coalesced(
+        &self,
+        url: String,
+        module_action: Option<(EtherscanModule, EtherscanAction)>,
+        correlation_id: Option<String>,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        match &self.in_flight {
+            Some(in_flight) => {
+                let client = self.clone();
+
+                in_flight
+                    .coalesce(url.clone(), async move {
+                        client.execute_url(url, module_action, correlation_id).await
+                    })
+                    .await
+            }
+            None => self.execute_url(url, module_action, correlation_id).await,
+        }
+    }
+
+    async fn execute_url(
+        &self,
+        url: String,
+        module_action: Option<(EtherscanModule, EtherscanAction)>,
+        correlation_id: Option<String>,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "etherscan.request",
+            module = ?module_action.map(|(module, _)| module),
+            action = ?module_action.map(|(_, action)| action),
+            chain = ?self.chainid,
+            url = %redact_url(&url),
+            correlation_id = correlation_id.as_deref(),
+        );
+
+        let attempts = self.execute_attempts(url, module_action, correlation_id);
+
+        #[cfg(feature = "tracing")]
+        let attempts = tracing::Instrument::instrument(attempts, span);
+
+        attempts.await
+    }
+
+    async fn execute_attempts(
+        &self,
+        url: String,
+        module_action: Option<(EtherscanModule, EtherscanAction)>,
+        correlation_id: Option<String>,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        let started = Instant::now();
+        let mut attempt = 0;
+        let mut endpoint = None;
+
+        let failover = self
+            .endpoints
+            .as_ref()
+            .and_then(|endpoints| Some((endpoints, url.strip_prefix(self.url.as_str())?)));
+
+        loop {
+            let target = match failover {
+                Some((endpoints, query)) => {
+                    let index = endpoints.select(endpoint);
+                    endpoint = Some(index);
+                    format!("{}{query}", endpoints.url(index))
+                }
+                None => url.clone(),
+            };
+
+            let send = self.send(HttpRequest {
+                correlation_id: correlation_id.clone(),
+                ..HttpRequest::get(target)
+            });
+
+            #[cfg(feature = "tracing")]
+            let send = tracing::Instrument::instrument(
+                send,
+                tracing::debug_span!("etherscan.attempt", attempt),
+            );
+
+            let result = send.await;
+
+            if let (Some((endpoints, _)), Some(index)) = (failover, endpoint) {
+                let success = match &result {
+                    Ok(response) => Some(response.status < 500 && response.status != 404),
+                    Err(e) => match e.downcast_ref::<EtherscanError>() {
+                        Some(EtherscanError::Cancelled | EtherscanError::Vetoed { .. }) => None,
+                        Some(_) => Some(true),
+                        None => Some(false),
+                    },
+                };
+
+                if let Some(success) = success {
+                    endpoints.record(index, success);
+
+                    if success {
+                        endpoint = None;
+                    }
+                }
+            }
+
+            let cause = match &result {
+                Err(e) if e.downcast_ref::<EtherscanError>().is_some() => None,
+                Err(_) => Some(RetryCause::Transport),
+                Ok(response) => RetryCause::classify(response),
+            };
+
+            let delay = cause
+                .and_then(|cause| self.retry_policy.retry_delay(attempt, cause))
+                .filter(|delay| match self.retry_policy.max_elapsed() {
+                    Some(max_elapsed) => started.elapsed() + *delay <= max_elapsed,
+                    None => true,
+                })
+                .filter(|_| {
+                    self.retry_budget
+                        .as_ref()
+                        .is_none_or(|budget| budget.try_spend())
+                });
+
+            match delay {
+                Some(delay) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        attempt,
+                        cause = ?cause,
+                        delay_ms = delay.as_millis() as u64,
+                        "retrying etherscan request"
+                    );
+
+                    if let Err(e) = retry::sleep(delay, self.cancellation.as_ref()).await {
+                        let result = Err(e);
+                        self.record_metrics(
+                            module_action,
+                            correlation_id.as_deref(),
+                            started,
+                            RequestOutcome::of(&result),
+                            attempt,
+                        );
+                        return result;
+                    }
+
+                    attempt += 1;
+                }
+                _ => {
+                    self.record_metrics(
+                        module_action,
+                        correlation_id.as_deref(),
+                        started,
+                        RequestOutcome::of(&result),
+                        attempt,
+                    );
+                    return result;
+                }
+            }
+        }
+    }
+
+    async fn send(
+        &self,
+        mut http_request: HttpRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        self.admit(&mut http_request).await?;
+        self.dispatch(http_request).await
+    }
+
+    async fn admit(
+        &self,
+        http_request: &mut HttpRequest,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        for middleware in &self.request_middleware {
+            middleware
+                .on_request(http_request)
+                .map_err(|e| -> Box<dyn Error + Send + Sync> {
+                    match e.downcast::<EtherscanError>() {
+                        Ok(e) => e,
+                        Err(e) => Box::new(EtherscanError::Vetoed {
+                            reason: e.to_string(),
+                        }),
+                    }
+                })?;
+        }
+
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.ready()?;
+        }
+
+        if let Some(quota) = &self.quota {
+            quota
+                .acquire(
+                    url_apikey(&http_request.url).as_ref(),
+                    self.cancellation.as_ref(),
+                )
+                .await?;
+        }
+
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire_with_priority(self.priority, self.cancellation.as_ref())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn dispatch(
+        &self,
+        http_request: HttpRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        let url = redact_url(&http_request.url);
+        let correlation_id = http_request.correlation_id.clone();
+
+        #[cfg(feature = "tracing")]
+        if let Some(limit) = self.debug_body_limit {
+            tracing::debug!(
+                method = ?http_request.method,
+                url = %url,
+                body = %http_request
+                    .body
+                    .as_deref()
+                    .map(|body| debug_body(body, limit))
+                    .unwrap_or_default(),
+                "sending etherscan request"
+            );
+        }
+
+        if let Some(token) = &self.cancellation {
+            if token.is_cancelled() {
+                return Err(Box::new(EtherscanError::Cancelled));
+            }
+        }
+
+        let probe = self
+            .circuit_breaker
+            .as_deref()
+            .map(CircuitBreaker::check)
+            .transpose()?;
+
+        let started = Instant::now();
+        let response = match &self.cancellation {
+            Some(token) => {
+                let send = self.transport.send(http_request);
+                futures::pin_mut!(send);
+
+                match futures::future::select(send, token.cancelled()).await {
+                    futures::future::Either::Left((response, _)) => response,
+                    futures::future::Either::Right(_) => {
+                        return Err(Box::new(EtherscanError::Cancelled))
+                    }
+                }
+            }
+            None => self.transport.send(http_request).await,
+        };
+
+        if let Some(probe) = probe {
+            match &response {
+                Ok(response) if response.status < 500 => probe.success(),
+                _ => probe.failure(),
+            }
+        }
+
+        let response = response?;
+
+        #[cfg(feature = "tracing")]
+        if let Some(limit) = self.debug_body_limit {
+            tracing::debug!(
+                url = %url,
+                status = response.status,
+                latency_ms = started.elapsed().as_millis() as u64,
+                body = %debug_body(&response.body, limit),
+                "received etherscan response"
+            );
+        }
+
+        if !self.response_middleware.is_empty() {
+            let info = ResponseInfo {
+                url,
+                correlation_id: correlation_id.clone(),
+                status: response.status,
+                latency: started.elapsed(),
+                body_size: response.body.len(),
+            };
+
+            for middleware in &self.response_middleware {
+                middleware.on_response(&info);
+            }
+        }
+
+        Ok(response)
+    }
+
+    pub async fn fetch<T: DeserializeOwned>(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<T, Box<dyn Error + Send + Sync>> {
+        let request = self.prepare(request);
+        let context = request.context();
+        let format = request.format();
+
+        let result = async {
+            let response = self.execute(request).await?.error_for_status()?;
+
+            match format {
+                EtherscanFormat::Json => {
+                    serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body)?
+                        .into_result()
+                }
+                EtherscanFormat::Raw => parse_raw(&response.body),
+            }
+        };
+
+        result.await.map_err(|e| CorrelatedError::wrap(&context, e))
+    }
+
+    /// Probes the client's base URL with `eth_blockNumber`; failover
+    /// endpoints are not tried. The returned latency excludes local rate
+    /// limiting and quota waits.
+    pub async fn healthcheck(&self) -> Result<Duration, Box<dyn Error + Send + Sync>> {
+        let request = self
+            .provide_key(self.prepare(EtherscanRequest::proxy_block_number()))
+            .await?;
+        let mut http_request = request.to_http_request()?;
+        self.admit(&mut http_request).await?;
+
+        let started = Instant::now();
+        let response = self.dispatch(http_request).await?;
+        let latency = started.elapsed();
+        let response = response.error_for_status()?;
+
+        match serde_json::from_slice::<ProxyResponse<String>>(&response.body) {
+            Ok(response) => match response.into_result()? {
+                Some(block) if !block.trim_start_matches("0x").is_empty() => Ok(latency),
+                _ => Err("healthcheck returned no block number".into()),
+            },
+            Err(_) => {
+                serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body)?
+                    .into_result::<serde_json::Value>()?;
+                Err("healthcheck returned an unexpected response".into())
+            }
+        }
+    }
+
+    #[inline]
+    pub async fn execute_with_apikey<K: Into<ApiKey>>(
+        &self,
+        request: EtherscanRequest,
+        apikey: K,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        self.execute(request.with_apikey(apikey)).await
+    }
+}
+
+fn v2_request(mut request: EtherscanRequest) -> Option<EtherscanRequest> {
+    let url = request.url.as_deref().unwrap_or(DEFAULT_API_URL);
+
+    if is_v2_url(url) {
+        return None;
+    }
+
+    request.chainid = Some(request.chainid.or_else(|| legacy_chainid(url))?);
+    request.url = Some(DEFAULT_API_V2_URL.to_string());
+    Some(request)
+}
+
+pub(crate) fn form_urlencode(form: &[(String, String)]) -> String {
+    let encode = |value: &str| {
+        value
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'*' => {
+                    (b as char).to_string()
+                }
+                b' ' => "+".to_string(),
+                b => format!("%{b:02X}"),
+            })
+            .collect::<String>()
+    };
+
+    form.iter()
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn parse_raw<T: DeserializeOwned>(body: &[u8]) -> Result<T, Box<dyn Error + Send + Sync>> {
+    let e = match serde_json::from_slice::<T>(body) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+
+    if let Ok(envelope) = serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(body) {
+        return envelope.into_result();
+    }
+
+    let text = String::from_utf8_lossy(body).into_owned();
+    serde_json::from_value(serde_json::Value::String(text)).map_err(|_| e.into())
+}
+
+fn correlation_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+
+    format!("{:016x}", hasher.finish())
+}
+
+fn is_cacheable(response: &HttpResponse) -> bool {
+    response.is_success()
+        && serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body)
+            .map(|envelope| envelope.status == "1")
+            .unwrap_or(false)
+}
+
+#[cfg(feature = "tracing")]
+fn debug_body(body: &[u8], limit: usize) -> String {
+    let body = redact_url(&String::from_utf8_lossy(body));
+
+    match body.char_indices().nth(limit) {
+        Some((index, _)) => format!("{}... ({} bytes)", &body[..index], body.len()),
+        None => body,
+    }
+}
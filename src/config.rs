@@ -0,0 +1,127 @@
+use {
+    crate::{
+        retry::{ExponentialBackoff, Jitter},
+        EtherscanClient, MultiChainClient, QuotaTracker,
+    },
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, env, error::Error, fs, path::Path, time::Duration},
+};
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct EtherscanConfig {
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, EtherscanProfile>,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct EtherscanProfile {
+    pub url: Option<String>,
+    pub chainid: Option<u64>,
+    pub apikey: Option<String>,
+    pub apikey_env: Option<String>,
+    pub rate_limit: Option<u32>,
+    pub daily_limit: Option<u64>,
+    pub retry: Option<RetryConfig>,
+}
+
+#[derive(Clone, Copy, Deserialize, Serialize)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub backoff_ms: u64,
+    #[serde(default)]
+    pub jitter: Jitter,
+    pub max_elapsed_ms: Option<u64>,
+}
+
+impl EtherscanConfig {
+    #[inline]
+    pub fn parse(source: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(toml::from_str(source)?)
+    }
+
+    #[inline]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn profile(
+        &self,
+        name: Option<&str>,
+    ) -> Result<&EtherscanProfile, Box<dyn Error + Send + Sync>> {
+        let name = match name.or(self.default_profile.as_deref()) {
+            Some(name) => name,
+            None if self.profiles.len() == 1 => self.profiles.keys().next().unwrap(),
+            None => return Err("no profile selected and no default_profile configured".into()),
+        };
+
+        self.profiles
+            .get(name)
+            .ok_or_else(|| format!("profile {name:?} not found in configuration").into())
+    }
+
+    #[inline]
+    pub fn client(
+        &self,
+        name: Option<&str>,
+    ) -> Result<EtherscanClient, Box<dyn Error + Send + Sync>> {
+        self.profile(name)?.client()
+    }
+
+    pub fn multi_chain_client(&self) -> Result<MultiChainClient, Box<dyn Error + Send + Sync>> {
+        self.profiles
+            .values()
+            .filter_map(|profile| Some((profile.chainid?, profile)))
+            .try_fold(MultiChainClient::new(), |multi, (chainid, profile)| {
+                Ok(multi.with_client(chainid, profile.client()?))
+            })
+    }
+}
+
+impl EtherscanProfile {
+    pub fn resolve_apikey(&self) -> Result<Option<String>, Box<dyn Error + Send + Sync>> {
+        match (&self.apikey, &self.apikey_env) {
+            (Some(apikey), _) => Ok(Some(apikey.clone())),
+            (None, Some(var)) => Ok(Some(
+                env::var(var).map_err(|_| format!("{var} is not set"))?,
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+
+    pub fn client(&self) -> Result<EtherscanClient, Box<dyn Error + Send + Sync>> {
+        let mut client = match (&self.url, self.chainid) {
+            (Some(url), _) => EtherscanClient::new(url.clone()),
+            (None, Some(_)) => EtherscanClient::new(crate::client::DEFAULT_API_V2_URL.into()),
+            (None, None) => EtherscanClient::default(),
+        };
+
+        if let Some(chainid) = self.chainid {
+            client = client.with_chainid(chainid);
+        }
+
+        if let Some(apikey) = self.resolve_apikey()? {
+            client = client.with_apikey(apikey);
+        }
+
+        if let Some(rate_limit) = self.rate_limit {
+            client = client.with_rate_limit(rate_limit);
+        }
+
+        if let Some(daily_limit) = self.daily_limit {
+            client = client.with_quota(QuotaTracker::new(daily_limit));
+        }
+
+        if let Some(retry) = self.retry {
+            client = client.with_retry_policy(ExponentialBackoff {
+                max_retries: retry.max_retries,
+                initial: Duration::from_millis(retry.backoff_ms),
+                jitter: retry.jitter,
+                max_elapsed: retry.max_elapsed_ms.map(Duration::from_millis),
+                ..Default::default()
+            });
+        }
+
+        Ok(client)
+    }
+}
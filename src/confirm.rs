@@ -0,0 +1,90 @@
+use {
+    crate::{
+        retry, CancellationToken, EtherscanClient, EtherscanError, EtherscanRequest,
+        TransactionReceipt,
+    },
+    ethabi::ethereum_types::U256,
+    serde::Deserialize,
+    std::{
+        error::Error,
+        time::{Duration, Instant},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Confirmation {
+    Confirmed {
+        receipt: Box<TransactionReceipt>,
+        confirmations: u64,
+    },
+    Failed(Box<TransactionReceipt>),
+}
+
+#[derive(Deserialize)]
+struct ReceiptStatus {
+    status: String,
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub async fn wait_for_confirmation(
+        &self,
+        txhash: U256,
+        confirmations: u64,
+        interval: Duration,
+        timeout: Option<Duration>,
+    ) -> Result<Confirmation, Box<dyn Error + Send + Sync>> {
+        self.wait_for_confirmation_until(txhash, confirmations, interval, timeout, None)
+            .await
+    }
+
+    pub async fn wait_for_confirmation_until(
+        &self,
+        txhash: U256,
+        confirmations: u64,
+        interval: Duration,
+        timeout: Option<Duration>,
+        shutdown: Option<&CancellationToken>,
+    ) -> Result<Confirmation, Box<dyn Error + Send + Sync>> {
+        let started = Instant::now();
+
+        loop {
+            let status = self
+                .fetch::<ReceiptStatus>(EtherscanRequest::transaction_get_receipt_status(txhash))
+                .await?;
+
+            if let Some(receipt) = self.transaction_receipt(txhash).await? {
+                if status.status == "0" || !receipt.is_success() {
+                    return Ok(Confirmation::Failed(Box::new(receipt)));
+                }
+
+                let head = self.block_number().await?;
+                let depth = (head + U256::one())
+                    .saturating_sub(receipt.block_number)
+                    .low_u64();
+
+                if depth >= confirmations {
+                    return Ok(Confirmation::Confirmed {
+                        receipt: Box::new(receipt),
+                        confirmations: depth,
+                    });
+                }
+            }
+
+            let elapsed = started.elapsed();
+
+            if let Some(timeout) = timeout {
+                if elapsed >= timeout {
+                    return Err(Box::new(EtherscanError::Timeout { elapsed }));
+                }
+            }
+
+            let delay = match timeout {
+                Some(timeout) => interval.min(timeout - elapsed),
+                None => interval,
+            };
+
+            retry::idle(delay, self.cancellation(), shutdown).await?;
+        }
+    }
+}
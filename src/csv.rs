@@ -0,0 +1,261 @@
+use {
+    crate::{AccountBalance, HistoryEvent, InternalTransaction, TokenTransfer, Transaction},
+    ethabi::ethereum_types::U256,
+    std::{error::Error, io},
+};
+
+pub trait CsvRecord {
+    const HEADERS: &'static [&'static str];
+
+    fn record(&self) -> Vec<String>;
+}
+
+fn address(value: &U256) -> String {
+    format!("{value:#042x}")
+}
+
+fn optional_address(value: &Option<U256>) -> String {
+    value.as_ref().map(address).unwrap_or_default()
+}
+
+fn hash(value: &U256) -> String {
+    format!("{value:#066x}")
+}
+
+fn status(is_error: &str) -> String {
+    match is_error {
+        "1" => "Error".to_string(),
+        _ => String::new(),
+    }
+}
+
+impl CsvRecord for Transaction {
+    const HEADERS: &'static [&'static str] = &[
+        "Txhash",
+        "Blockno",
+        "UnixTimestamp",
+        "From",
+        "To",
+        "ContractAddress",
+        "Value",
+        "Gas",
+        "GasPrice",
+        "GasUsed",
+        "TxnFee",
+        "Nonce",
+        "Method",
+        "Status",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            hash(&self.hash),
+            self.block_number.to_string(),
+            self.timestamp.to_string(),
+            address(&self.from),
+            optional_address(&self.to),
+            optional_address(&self.contract_address),
+            self.value.to_string(),
+            self.gas.to_string(),
+            self.gas_price.to_string(),
+            self.gas_used.to_string(),
+            self.gas_used.saturating_mul(self.gas_price).to_string(),
+            self.nonce.to_string(),
+            self.function_name.clone(),
+            status(&self.is_error),
+        ]
+    }
+}
+
+impl CsvRecord for InternalTransaction {
+    const HEADERS: &'static [&'static str] = &[
+        "Txhash",
+        "Blockno",
+        "UnixTimestamp",
+        "From",
+        "TxTo",
+        "ContractAddress",
+        "Value",
+        "Type",
+        "TraceId",
+        "Status",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            hash(&self.hash),
+            self.block_number.to_string(),
+            self.timestamp.to_string(),
+            address(&self.from),
+            optional_address(&self.to),
+            optional_address(&self.contract_address),
+            self.value.to_string(),
+            self.kind.clone(),
+            self.trace_id.clone(),
+            status(&self.is_error),
+        ]
+    }
+}
+
+impl CsvRecord for TokenTransfer {
+    const HEADERS: &'static [&'static str] = &[
+        "Txhash",
+        "Blockno",
+        "UnixTimestamp",
+        "From",
+        "To",
+        "ContractAddress",
+        "TokenValue",
+        "TokenId",
+        "TokenName",
+        "TokenSymbol",
+        "TokenDecimal",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            hash(&self.hash),
+            self.block_number.to_string(),
+            self.timestamp.to_string(),
+            address(&self.from),
+            optional_address(&self.to),
+            address(&self.contract_address),
+            self.token_value
+                .clone()
+                .unwrap_or_else(|| self.value.to_string()),
+            self.token_id.clone().unwrap_or_default(),
+            self.token_name.clone(),
+            self.token_symbol.clone(),
+            self.token_decimal.clone(),
+        ]
+    }
+}
+
+impl CsvRecord for AccountBalance {
+    const HEADERS: &'static [&'static str] = &["Address", "Balance"];
+
+    fn record(&self) -> Vec<String> {
+        vec![address(&self.account), self.balance.to_string()]
+    }
+}
+
+impl CsvRecord for HistoryEvent {
+    const HEADERS: &'static [&'static str] = &[
+        "Kind",
+        "Txhash",
+        "Blockno",
+        "UnixTimestamp",
+        "From",
+        "To",
+        "ContractAddress",
+        "Value",
+        "TokenId",
+        "TokenSymbol",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        let (kind, from, to, contract, value, token_id, symbol) = match self {
+            Self::Transaction(tx) => (
+                "transaction",
+                tx.from,
+                tx.to,
+                tx.contract_address,
+                tx.value.to_string(),
+                None,
+                String::new(),
+            ),
+            Self::Internal(tx) => (
+                "internal",
+                tx.from,
+                tx.to,
+                tx.contract_address,
+                tx.value.to_string(),
+                None,
+                String::new(),
+            ),
+            Self::Erc20(transfer) | Self::Erc721(transfer) | Self::Erc1155(transfer) => (
+                match self {
+                    Self::Erc20(_) => "erc20",
+                    Self::Erc721(_) => "erc721",
+                    _ => "erc1155",
+                },
+                transfer.from,
+                transfer.to,
+                Some(transfer.contract_address),
+                transfer
+                    .token_value
+                    .clone()
+                    .unwrap_or_else(|| transfer.value.to_string()),
+                transfer.token_id.clone(),
+                transfer.token_symbol.clone(),
+            ),
+        };
+
+        vec![
+            kind.to_string(),
+            hash(&self.hash()),
+            self.block_number().to_string(),
+            self.timestamp().to_string(),
+            address(&from),
+            optional_address(&to),
+            optional_address(&contract),
+            value,
+            token_id.unwrap_or_default(),
+            symbol,
+        ]
+    }
+}
+
+pub struct CsvWriter<W: io::Write> {
+    writer: csv::Writer<W>,
+    headers_written: bool,
+}
+
+impl<W: io::Write> CsvWriter<W> {
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: csv::Writer::from_writer(writer),
+            headers_written: false,
+        }
+    }
+
+    pub fn write<T: CsvRecord>(&mut self, record: &T) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if !self.headers_written {
+            self.writer.write_record(T::HEADERS)?;
+            self.headers_written = true;
+        }
+
+        self.writer.write_record(record.record())?;
+        Ok(())
+    }
+
+    pub fn write_all<'a, T, I>(&mut self, records: I) -> Result<(), Box<dyn Error + Send + Sync>>
+    where
+        T: CsvRecord + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        for record in records {
+            self.write(record)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> Result<W, Box<dyn Error + Send + Sync>> {
+        self.writer
+            .into_inner()
+            .map_err(|e| Box::new(e.into_error()) as Box<dyn Error + Send + Sync>)
+    }
+}
+
+pub fn to_csv<T: CsvRecord>(records: &[T]) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let mut writer = CsvWriter::new(Vec::new());
+
+    if records.is_empty() {
+        writer.writer.write_record(T::HEADERS)?;
+    }
+
+    writer.write_all(records)?;
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
@@ -0,0 +1,172 @@
+use {
+    crate::{AbiCache, Log, Transaction},
+    ethabi::{ethereum_types::H256, Contract, Function, RawLog, Token},
+    std::error::Error,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedCall {
+    pub name: String,
+    pub signature: String,
+    pub selector: [u8; 4],
+    pub params: Vec<(String, Token)>,
+    pub guessed: bool,
+}
+
+pub fn decode_hex(data: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+    let data = data.trim();
+    let data = data
+        .strip_prefix("0x")
+        .or_else(|| data.strip_prefix("0X"))
+        .unwrap_or(data);
+
+    if !data.is_ascii() || !data.len().is_multiple_of(2) {
+        return Err(format!("invalid hex string: {data}").into());
+    }
+
+    (0..data.len())
+        .step_by(2)
+        .map(|i| Ok(u8::from_str_radix(&data[i..i + 2], 16)?))
+        .collect()
+}
+
+fn decode_function(
+    function: &Function,
+    data: &[u8],
+) -> Result<DecodedCall, Box<dyn Error + Send + Sync>> {
+    let tokens = function.decode_input(&data[4..])?;
+
+    Ok(DecodedCall {
+        name: function.name.clone(),
+        signature: function.signature(),
+        selector: function.short_signature(),
+        params: function
+            .inputs
+            .iter()
+            .map(|param| param.name.clone())
+            .zip(tokens)
+            .collect(),
+        guessed: false,
+    })
+}
+
+pub fn decode_input(
+    contract: &Contract,
+    input: &str,
+) -> Result<Option<DecodedCall>, Box<dyn Error + Send + Sync>> {
+    let data = decode_hex(input)?;
+
+    let Some(selector) = data.get(..4) else {
+        return Ok(None);
+    };
+
+    match contract
+        .functions()
+        .find(|function| function.short_signature() == selector)
+    {
+        Some(function) => decode_function(function, &data).map(Some),
+        None => Ok(None),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedLog {
+    pub name: String,
+    pub signature: H256,
+    pub params: Vec<(String, Token, bool)>,
+}
+
+pub fn decode_log(
+    contract: &Contract,
+    log: &Log,
+) -> Result<Option<DecodedLog>, Box<dyn Error + Send + Sync>> {
+    let topics = log
+        .topics
+        .iter()
+        .map(|topic| {
+            let mut bytes = [0u8; 32];
+            topic.to_big_endian(&mut bytes);
+            H256::from(bytes)
+        })
+        .collect::<Vec<_>>();
+
+    let Some(topic0) = topics.first().copied() else {
+        return Ok(None);
+    };
+
+    let Some(event) = contract
+        .events()
+        .find(|event| !event.anonymous && event.signature() == topic0)
+    else {
+        return Ok(None);
+    };
+
+    let log = event.parse_log(RawLog {
+        topics,
+        data: decode_hex(&log.data)?,
+    })?;
+
+    let params = event
+        .inputs
+        .iter()
+        .zip(log.params)
+        .map(|(input, param)| (param.name, param.value, input.indexed))
+        .collect();
+
+    Ok(Some(DecodedLog {
+        name: event.name.clone(),
+        signature: topic0,
+        params,
+    }))
+}
+
+impl Log {
+    #[inline]
+    pub fn decode(
+        &self,
+        contract: &Contract,
+    ) -> Result<Option<DecodedLog>, Box<dyn Error + Send + Sync>> {
+        decode_log(contract, self)
+    }
+}
+
+impl Transaction {
+    #[inline]
+    pub fn decode_input(
+        &self,
+        contract: &Contract,
+    ) -> Result<Option<DecodedCall>, Box<dyn Error + Send + Sync>> {
+        decode_input(contract, &self.input)
+    }
+}
+
+impl AbiCache {
+    pub async fn decode_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> Result<Option<DecodedCall>, Box<dyn Error + Send + Sync>> {
+        let Some(to) = transaction.to else {
+            return Ok(None);
+        };
+
+        let decoded = match self.get(to).await? {
+            Some(contract) => transaction.decode_input(&contract)?,
+            None => None,
+        };
+
+        match (decoded, self.selector_lookup()) {
+            (None, Some(lookup)) => lookup.decode_input(&transaction.input).await,
+            (decoded, _) => Ok(decoded),
+        }
+    }
+
+    pub async fn decode_log(
+        &self,
+        log: &Log,
+    ) -> Result<Option<DecodedLog>, Box<dyn Error + Send + Sync>> {
+        match self.get(log.address).await? {
+            Some(contract) => log.decode(&contract),
+            None => Ok(None),
+        }
+    }
+}
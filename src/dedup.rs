@@ -0,0 +1,106 @@
+use {
+    crate::HttpResponse,
+    futures::future::{BoxFuture, FutureExt, Shared},
+    std::{
+        collections::HashMap,
+        error::Error,
+        fmt,
+        future::Future,
+        sync::{Arc, Mutex},
+    },
+};
+
+type SharedResult = Result<HttpResponse, SharedError>;
+
+#[derive(Clone)]
+pub struct SharedError(Arc<dyn Error + Send + Sync>);
+
+impl fmt::Debug for SharedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for SharedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for SharedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.0)
+    }
+}
+
+impl SharedError {
+    #[inline]
+    pub fn inner(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.0
+    }
+}
+
+#[derive(Default)]
+pub struct InFlightRequests {
+    pending: Mutex<HashMap<String, Shared<BoxFuture<'static, SharedResult>>>>,
+}
+
+impl fmt::Debug for InFlightRequests {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InFlightRequests")
+            .field("pending", &self.len())
+            .finish()
+    }
+}
+
+impl InFlightRequests {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub async fn coalesce<F>(
+        self: &Arc<Self>,
+        key: String,
+        request: F,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>>
+    where
+        F: Future<Output = Result<HttpResponse, Box<dyn Error + Send + Sync>>> + Send + 'static,
+    {
+        let future = {
+            let mut pending = self.pending.lock().unwrap();
+
+            match pending.get(&key) {
+                Some(future) => future.clone(),
+                None => {
+                    let this = Arc::downgrade(self);
+                    let cleanup_key = key.clone();
+
+                    let future = async move {
+                        let result = request.await.map_err(|e| SharedError(Arc::from(e)));
+
+                        if let Some(this) = this.upgrade() {
+                            this.pending.lock().unwrap().remove(&cleanup_key);
+                        }
+
+                        result
+                    }
+                    .boxed()
+                    .shared();
+
+                    pending.insert(key, future.clone());
+                    future
+                }
+            }
+        };
+
+        future
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+    }
+}
@@ -0,0 +1,186 @@
+use {
+    crate::{
+        is_unverified_error, AddressWatcher, CancellationToken, ContractSource, EtherscanClient,
+        EtherscanRequest, HistoryEvent, Transaction, WatchEvent,
+    },
+    ethabi::{ethereum_types::U256, Contract},
+    futures::{Stream, TryStreamExt},
+    serde::{Deserialize, Serialize},
+    std::{error::Error, time::Duration},
+};
+
+pub const CONTRACT_CREATION_LIMIT: usize = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCreation {
+    #[serde(with = "crate::serde_u256::hex")]
+    pub contract_address: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub contract_creator: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub tx_hash: U256,
+}
+
+#[derive(Clone, Debug)]
+pub struct Deployment {
+    pub contract_address: U256,
+    pub transaction: Transaction,
+    pub source: Option<ContractSource>,
+    pub abi: Option<Contract>,
+}
+
+impl Deployment {
+    #[inline]
+    pub fn is_verified(&self) -> bool {
+        self.source
+            .as_ref()
+            .is_some_and(ContractSource::is_verified)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeploymentWatcher {
+    client: EtherscanClient,
+    watcher: AddressWatcher,
+    fetch_source: bool,
+}
+
+impl DeploymentWatcher {
+    #[inline]
+    pub fn new(client: EtherscanClient, deployer: U256) -> Self {
+        Self {
+            watcher: AddressWatcher::new(client.clone(), deployer).with_token_transfers(false),
+            client,
+            fetch_source: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.watcher = self.watcher.with_interval(interval);
+        self
+    }
+
+    #[inline]
+    pub fn with_startblock(mut self, startblock: U256) -> Self {
+        self.watcher = self.watcher.with_startblock(startblock);
+        self
+    }
+
+    #[inline]
+    pub fn with_min_confirmations(mut self, min_confirmations: u64) -> Self {
+        self.watcher = self.watcher.with_min_confirmations(min_confirmations);
+        self
+    }
+
+    #[inline]
+    pub fn with_dedup_capacity(mut self, capacity: usize) -> Self {
+        self.watcher = self.watcher.with_dedup_capacity(capacity);
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.watcher = self.watcher.with_shutdown(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn with_fetch_source(mut self, fetch_source: bool) -> Self {
+        self.fetch_source = fetch_source;
+        self
+    }
+
+    #[inline]
+    pub fn deployer(&self) -> U256 {
+        self.watcher.address()
+    }
+
+    pub fn stream(
+        self,
+    ) -> impl Stream<Item = Result<Deployment, Box<dyn Error + Send + Sync>>> + Send + 'static {
+        let Self {
+            client,
+            watcher,
+            fetch_source,
+        } = self;
+
+        let deployer = watcher.address();
+
+        watcher.stream().try_filter_map(move |event| {
+            let client = client.clone();
+
+            async move {
+                let transaction = match event {
+                    WatchEvent::Added(HistoryEvent::Transaction(transaction))
+                        if transaction.from == deployer && transaction.is_error != "1" =>
+                    {
+                        transaction
+                    }
+                    _ => return Ok(None),
+                };
+
+                let Some(contract_address) = transaction.contract_address else {
+                    return Ok(None);
+                };
+
+                let (source, abi) = match fetch_source {
+                    true => client.deployment_source(contract_address).await?,
+                    false => (None, None),
+                };
+
+                Ok(Some(Deployment {
+                    contract_address,
+                    transaction,
+                    source,
+                    abi,
+                }))
+            }
+        })
+    }
+}
+
+impl EtherscanClient {
+    pub async fn contract_creation(
+        &self,
+        contract_addresses: Vec<U256>,
+    ) -> Result<Vec<ContractCreation>, Box<dyn Error + Send + Sync>> {
+        let mut creations = Vec::with_capacity(contract_addresses.len());
+
+        for chunk in contract_addresses.chunks(CONTRACT_CREATION_LIMIT) {
+            let batch: Vec<ContractCreation> = self
+                .fetch(EtherscanRequest::contract_get_contract_creation(
+                    chunk.to_vec(),
+                ))
+                .await?;
+
+            creations.extend(batch);
+        }
+
+        Ok(creations)
+    }
+
+    async fn deployment_source(
+        &self,
+        contract_address: U256,
+    ) -> Result<(Option<ContractSource>, Option<Contract>), Box<dyn Error + Send + Sync>> {
+        let source = match self.contract_source(contract_address).await {
+            Ok(source) => source,
+            Err(e) if is_unverified_error(&*e) => return Ok((None, None)),
+            Err(e) => return Err(e),
+        };
+
+        let abi = match source.is_verified() {
+            true => Contract::load(source.abi.as_bytes()).ok(),
+            false => None,
+        };
+
+        Ok((Some(source), abi))
+    }
+
+    #[inline]
+    pub fn watch_deployments(&self, deployer: U256) -> DeploymentWatcher {
+        DeploymentWatcher::new(self.clone(), deployer)
+    }
+}
@@ -0,0 +1,386 @@
+use {
+    crate::{
+        retry, CancellationToken, Cursor, EtherscanClient, EtherscanRequest, EtherscanSort,
+        EtherscanTag, HistoryEvent, InternalTransaction, Transaction, MAX_RESULT_WINDOW,
+    },
+    ethabi::ethereum_types::U256,
+    futures::{stream, Stream},
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::{BTreeSet, HashMap, HashSet, VecDeque},
+        error::Error,
+        time::{Duration, Instant},
+    },
+};
+
+const TRANSACTIONS_PAGE_SIZE: u64 = 1_000;
+
+type Credited = HashSet<(U256, (U256, String))>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Deposit {
+    pub address: U256,
+    pub amount: U256,
+    pub confirmations: u64,
+    pub transaction: HistoryEvent,
+}
+
+struct Candidate {
+    block_number: U256,
+    key: (U256, String),
+    to: Option<U256>,
+    value: U256,
+    failed: bool,
+    event: HistoryEvent,
+}
+
+impl From<Transaction> for Candidate {
+    fn from(transaction: Transaction) -> Self {
+        Self {
+            block_number: transaction.block_number,
+            key: (transaction.hash, String::new()),
+            to: transaction.to,
+            value: transaction.value,
+            failed: transaction.is_error == "1",
+            event: HistoryEvent::Transaction(transaction),
+        }
+    }
+}
+
+impl From<InternalTransaction> for Candidate {
+    fn from(transaction: InternalTransaction) -> Self {
+        Self {
+            block_number: transaction.block_number,
+            key: (
+                transaction.hash,
+                format!("{}:{:x}", transaction.trace_id, transaction.from),
+            ),
+            to: transaction.to,
+            value: transaction.value,
+            failed: transaction.is_error == "1",
+            event: HistoryEvent::Internal(transaction),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DepositMonitor {
+    client: EtherscanClient,
+    addresses: Vec<U256>,
+    interval: Duration,
+    rescan_interval: Duration,
+    min_confirmations: u64,
+    startblock: Option<U256>,
+    concurrency: usize,
+    shutdown: Option<CancellationToken>,
+}
+
+struct DepositState {
+    monitor: DepositMonitor,
+    balances: HashMap<U256, U256>,
+    next_block: HashMap<U256, Cursor>,
+    next_internal_block: HashMap<U256, Cursor>,
+    pending: BTreeSet<U256>,
+    credited: HashMap<(U256, bool), Credited>,
+    deposits: VecDeque<Deposit>,
+    last_rescan: Option<Instant>,
+    polled: bool,
+}
+
+impl DepositMonitor {
+    #[inline]
+    pub fn new(client: EtherscanClient, addresses: Vec<U256>) -> Self {
+        Self {
+            client,
+            addresses,
+            interval: Duration::from_secs(15),
+            rescan_interval: Duration::from_secs(300),
+            min_confirmations: 12,
+            startblock: None,
+            concurrency: 1,
+            shutdown: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets how often every address is re-scanned regardless of balance
+    /// changes, so deposits that are swept within one poll interval are
+    /// still credited.
+    #[inline]
+    pub fn with_rescan_interval(mut self, rescan_interval: Duration) -> Self {
+        self.rescan_interval = rescan_interval;
+        self
+    }
+
+    #[inline]
+    pub fn with_min_confirmations(mut self, min_confirmations: u64) -> Self {
+        self.min_confirmations = min_confirmations;
+        self
+    }
+
+    #[inline]
+    pub fn with_startblock(mut self, startblock: U256) -> Self {
+        self.startblock = Some(startblock);
+        self
+    }
+
+    #[inline]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn addresses(&self) -> &[U256] {
+        &self.addresses
+    }
+
+    pub fn stream(
+        self,
+    ) -> impl Stream<Item = Result<Deposit, Box<dyn Error + Send + Sync>>> + Send + 'static {
+        let state = DepositState {
+            monitor: self,
+            balances: HashMap::new(),
+            next_block: HashMap::new(),
+            next_internal_block: HashMap::new(),
+            pending: BTreeSet::new(),
+            credited: HashMap::new(),
+            deposits: VecDeque::new(),
+            last_rescan: None,
+            polled: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(deposit) = state.deposits.pop_front() {
+                    return Some((Ok(deposit), state));
+                }
+
+                if state.polled {
+                    let cancellation = state.monitor.client.cancellation();
+
+                    let shutdown = state.monitor.shutdown.as_ref();
+
+                    if retry::idle(state.monitor.interval, cancellation, shutdown)
+                        .await
+                        .is_err()
+                    {
+                        return None;
+                    }
+                }
+
+                state.polled = true;
+
+                if let Err(e) = state.poll().await {
+                    return Some((Err(e), state));
+                }
+            }
+        })
+    }
+}
+
+impl DepositState {
+    async fn poll(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let monitor = self.monitor.clone();
+        let head = monitor.client.block_number().await?;
+
+        let balances = monitor
+            .client
+            .balances(monitor.addresses.clone(), Some(EtherscanTag::Latest))
+            .await?;
+
+        for balance in balances {
+            let previous = self.balances.insert(balance.account, balance.balance);
+
+            match (previous, monitor.startblock) {
+                (None, Some(startblock)) => {
+                    self.next_block
+                        .insert(balance.account, Cursor::new(startblock));
+                    self.next_internal_block
+                        .insert(balance.account, Cursor::new(startblock));
+                    self.pending.insert(balance.account);
+                }
+                (None, None) => {
+                    self.next_block
+                        .insert(balance.account, Cursor::new(head + U256::one()));
+                    self.next_internal_block
+                        .insert(balance.account, Cursor::new(head + U256::one()));
+                }
+                (Some(previous), _) if balance.balance != previous => {
+                    self.pending.insert(balance.account);
+                }
+                _ => {}
+            }
+        }
+
+        let rescan = self
+            .last_rescan
+            .is_none_or(|at| at.elapsed() >= monitor.rescan_interval);
+
+        if rescan {
+            self.last_rescan = Some(Instant::now());
+            self.pending.extend(self.next_block.keys().copied());
+        }
+
+        let requests = self
+            .pending
+            .iter()
+            .map(|address| {
+                (
+                    *address,
+                    EtherscanRequest::account_tx_list(
+                        *address,
+                        self.next_block.get(address).map(|cursor| cursor.block),
+                        None,
+                        Some(U256::from(
+                            self.next_block.get(address).map_or(1, |c| c.page),
+                        )),
+                        Some(U256::from(TRANSACTIONS_PAGE_SIZE)),
+                        Some(EtherscanSort::Ascending),
+                    ),
+                    EtherscanRequest::account_tx_list_internal(
+                        *address,
+                        self.next_internal_block
+                            .get(address)
+                            .map(|cursor| cursor.block),
+                        None,
+                        Some(U256::from(
+                            self.next_internal_block
+                                .get(address)
+                                .map_or(1, |cursor| cursor.page),
+                        )),
+                        Some(U256::from(TRANSACTIONS_PAGE_SIZE)),
+                        Some(EtherscanSort::Ascending),
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let results = monitor
+            .client
+            .batch(monitor.concurrency)
+            .run(
+                requests,
+                |client, (address, request, internal_request)| async move {
+                    let (transactions, internal) = futures::join!(
+                        client.fetch::<Vec<Transaction>>(request),
+                        client.fetch::<Vec<InternalTransaction>>(internal_request),
+                    );
+
+                    (address, transactions, internal)
+                },
+            )
+            .await;
+
+        for (address, transactions, internal) in results {
+            let transactions = transactions?.into_iter().map(Candidate::from).collect();
+            let internal = internal?.into_iter().map(Candidate::from).collect();
+
+            let caught_up = self.credit(address, transactions, false, head)?;
+            let internal_caught_up = self.credit(address, internal, true, head)?;
+
+            if caught_up && internal_caught_up {
+                self.pending.remove(&address);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn credit(
+        &mut self,
+        address: U256,
+        candidates: Vec<Candidate>,
+        internal: bool,
+        head: U256,
+    ) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let full = candidates.len() as u64 >= TRANSACTIONS_PAGE_SIZE;
+        let credited = self.credited.entry((address, internal)).or_default();
+        let mut unconfirmed = None;
+        let mut last_block = None;
+
+        for candidate in candidates {
+            last_block = Some(candidate.block_number);
+
+            if candidate.to != Some(address)
+                || candidate.value.is_zero()
+                || candidate.failed
+                || credited.contains(&(candidate.block_number, candidate.key.clone()))
+            {
+                continue;
+            }
+
+            let confirmations = (head + U256::one())
+                .saturating_sub(candidate.block_number)
+                .low_u64();
+
+            if confirmations < self.monitor.min_confirmations {
+                unconfirmed = unconfirmed.or(Some(candidate.block_number));
+                continue;
+            }
+
+            credited.insert((candidate.block_number, candidate.key));
+            self.deposits.push_back(Deposit {
+                address,
+                amount: candidate.value,
+                confirmations,
+                transaction: candidate.event,
+            });
+        }
+
+        let cursors = match internal {
+            true => &mut self.next_internal_block,
+            false => &mut self.next_block,
+        };
+
+        let current = cursors
+            .get(&address)
+            .cloned()
+            .unwrap_or_else(|| Cursor::new(U256::zero()));
+
+        let (next, caught_up) = match (unconfirmed, last_block) {
+            (Some(block), _) if block == current.block => (current, false),
+            (Some(block), _) => (Cursor::new(block), false),
+            (None, Some(block)) if block != current.block => (Cursor::new(block), !full),
+            (None, Some(_)) if !full => (current, true),
+            (None, Some(block)) => {
+                if (current.page + 1) * TRANSACTIONS_PAGE_SIZE > MAX_RESULT_WINDOW {
+                    return Err(
+                        format!("block {block} has more than {MAX_RESULT_WINDOW} results").into(),
+                    );
+                }
+
+                let next = Cursor {
+                    page: current.page + 1,
+                    ..current
+                };
+
+                (next, false)
+            }
+            (None, None) => (current, true),
+        };
+
+        credited.retain(|(block, _)| *block >= next.block);
+        cursors.insert(address, next);
+
+        Ok(caught_up)
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn deposit_monitor(&self, addresses: Vec<U256>) -> DepositMonitor {
+        DepositMonitor::new(self.clone(), addresses)
+    }
+}
@@ -0,0 +1,183 @@
+use {
+    crate::{ContractSource, EtherscanClient},
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    similar::{Algorithm, DiffTag},
+    std::{collections::BTreeSet, error::Error},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Added,
+    Removed,
+    Modified,
+    Unchanged,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineChange {
+    Added,
+    Removed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct DiffLine {
+    pub change: LineChange,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct FileDiff {
+    pub path: String,
+    pub status: FileStatus,
+    pub lines: Vec<DiffLine>,
+}
+
+impl FileDiff {
+    #[inline]
+    pub fn additions(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| line.change == LineChange::Added)
+            .count()
+    }
+
+    #[inline]
+    pub fn deletions(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| line.change == LineChange::Removed)
+            .count()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SourceDiff {
+    pub files: Vec<FileDiff>,
+    pub metadata: Vec<(String, serde_json::Value, serde_json::Value)>,
+}
+
+impl SourceDiff {
+    #[inline]
+    pub fn is_identical(&self) -> bool {
+        self.metadata.is_empty()
+            && self
+                .files
+                .iter()
+                .all(|file| file.status == FileStatus::Unchanged)
+    }
+
+    #[inline]
+    pub fn changed_files(&self) -> impl Iterator<Item = &FileDiff> {
+        self.files
+            .iter()
+            .filter(|file| file.status != FileStatus::Unchanged)
+    }
+}
+
+fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    let removed = |i: usize| DiffLine {
+        change: LineChange::Removed,
+        old_line: Some(prefix + i + 1),
+        new_line: None,
+        text: a[i].to_string(),
+    };
+
+    let added = |j: usize| DiffLine {
+        change: LineChange::Added,
+        old_line: None,
+        new_line: Some(prefix + j + 1),
+        text: b[j].to_string(),
+    };
+
+    let mut lines = Vec::new();
+
+    for op in similar::capture_diff_slices(Algorithm::Myers, a, b) {
+        let (_, old_range, new_range) = op.as_tag_tuple();
+
+        if op.tag() != DiffTag::Equal {
+            lines.extend(old_range.map(removed));
+            lines.extend(new_range.map(added));
+        }
+    }
+
+    lines
+}
+
+pub fn diff_sources(old: &ContractSource, new: &ContractSource) -> SourceDiff {
+    let old_sources = old.sources();
+    let new_sources = new.sources();
+
+    let paths = old_sources
+        .keys()
+        .chain(new_sources.keys())
+        .collect::<BTreeSet<_>>();
+
+    let files = paths
+        .into_iter()
+        .map(|path| {
+            let (status, lines) = match (old_sources.get(path), new_sources.get(path)) {
+                (Some(a), Some(b)) if a == b => (FileStatus::Unchanged, Vec::new()),
+                (Some(a), Some(b)) => (FileStatus::Modified, diff_lines(a, b)),
+                (Some(a), None) => (FileStatus::Removed, diff_lines(a, "")),
+                (None, Some(b)) => (FileStatus::Added, diff_lines("", b)),
+                (None, None) => (FileStatus::Unchanged, Vec::new()),
+            };
+
+            FileDiff {
+                path: path.clone(),
+                status,
+                lines,
+            }
+        })
+        .collect();
+
+    let metadata = match (old.metadata(), new.metadata()) {
+        (serde_json::Value::Object(a), serde_json::Value::Object(b)) => a
+            .iter()
+            .filter(|(key, value)| b.get(*key) != Some(value))
+            .map(|(key, value)| {
+                (
+                    key.clone(),
+                    value.clone(),
+                    b.get(key).cloned().unwrap_or_default(),
+                )
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    SourceDiff { files, metadata }
+}
+
+impl EtherscanClient {
+    pub async fn diff_contract_sources(
+        &self,
+        old: U256,
+        new: U256,
+    ) -> Result<SourceDiff, Box<dyn Error + Send + Sync>> {
+        let old = self.contract_source(old).await?;
+        let new = self.contract_source(new).await?;
+
+        Ok(diff_sources(&old, &new))
+    }
+}
@@ -0,0 +1,107 @@
+use {
+    crate::{keccak256, EtherscanClient},
+    ethabi::{
+        ethereum_types::{H160, H256, U256},
+        ParamType, Token,
+    },
+    std::error::Error,
+};
+
+pub const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+pub fn namehash(name: &str) -> H256 {
+    let name = name.trim().trim_end_matches('.').to_lowercase();
+
+    name.rsplit('.')
+        .filter(|label| !label.is_empty())
+        .fold(H256::zero(), |node, label| {
+            let mut data = node.as_bytes().to_vec();
+            data.extend_from_slice(keccak256(label.as_bytes()).as_bytes());
+            keccak256(&data)
+        })
+}
+
+fn to_address(token: Token) -> Option<U256> {
+    match token {
+        Token::Address(address) if !address.is_zero() => {
+            Some(U256::from_big_endian(address.as_bytes()))
+        }
+        _ => None,
+    }
+}
+
+impl EtherscanClient {
+    async fn ens_call(
+        &self,
+        to: U256,
+        signature: &str,
+        node: H256,
+        output: ParamType,
+    ) -> Result<Option<Token>, Box<dyn Error + Send + Sync>> {
+        let mut data = ethabi::short_signature(signature, &[ParamType::FixedBytes(32)]).to_vec();
+        data.extend(ethabi::encode(&[Token::FixedBytes(
+            node.as_bytes().to_vec(),
+        )]));
+
+        let result = self.call(to, &data).await?;
+
+        match result.is_empty() {
+            true => Ok(None),
+            false => Ok(ethabi::decode(&[output], &result)?.pop()),
+        }
+    }
+
+    async fn ens_resolver(&self, node: H256) -> Result<Option<U256>, Box<dyn Error + Send + Sync>> {
+        let registry = U256::from_str_radix(ENS_REGISTRY.trim_start_matches("0x"), 16)?;
+
+        Ok(self
+            .ens_call(registry, "resolver", node, ParamType::Address)
+            .await?
+            .and_then(to_address))
+    }
+
+    pub async fn resolve_ens(
+        &self,
+        name: &str,
+    ) -> Result<Option<U256>, Box<dyn Error + Send + Sync>> {
+        let node = namehash(name);
+
+        let Some(resolver) = self.ens_resolver(node).await? else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .ens_call(resolver, "addr", node, ParamType::Address)
+            .await?
+            .and_then(to_address))
+    }
+
+    pub async fn lookup_ens(
+        &self,
+        address: U256,
+    ) -> Result<Option<String>, Box<dyn Error + Send + Sync>> {
+        let mut bytes = [0u8; 32];
+        address.to_big_endian(&mut bytes);
+        let node = namehash(&format!(
+            "{:x}.addr.reverse",
+            H160::from_slice(&bytes[12..])
+        ));
+
+        let Some(resolver) = self.ens_resolver(node).await? else {
+            return Ok(None);
+        };
+
+        let name = match self
+            .ens_call(resolver, "name", node, ParamType::String)
+            .await?
+        {
+            Some(Token::String(name)) if !name.is_empty() => name,
+            _ => return Ok(None),
+        };
+
+        match self.resolve_ens(&name).await? {
+            Some(resolved) if resolved == address => Ok(Some(name)),
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use {
+    crate::{EtherscanAction, EtherscanModule},
+    std::{error::Error, fmt, time::Duration},
+};
+
+#[derive(Clone, Debug)]
+pub enum EtherscanError {
+    Api {
+        message: String,
+        result: String,
+    },
+    Cancelled,
+    CircuitOpen {
+        retry_in: Duration,
+    },
+    Http {
+        status: u16,
+        body: String,
+        headers: Vec<(String, String)>,
+    },
+    QuotaExhausted {
+        limit: u64,
+        resets_in: Duration,
+    },
+    Rpc {
+        code: i64,
+        message: String,
+    },
+    Timeout {
+        elapsed: Duration,
+    },
+    Vetoed {
+        reason: String,
+    },
+    WindowLimit {
+        page: u64,
+        offset: u64,
+        max_page: u64,
+    },
+}
+
+impl fmt::Display for EtherscanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Api { message, result } => write!(f, "etherscan error: {message}: {result}"),
+            Self::Cancelled => write!(f, "request cancelled"),
+            Self::CircuitOpen { retry_in } => write!(
+                f,
+                "circuit breaker is open; failing fast for another {}ms",
+                retry_in.as_millis()
+            ),
+            Self::Http { status, body, .. } => match body.trim() {
+                "" => write!(f, "http error {status}"),
+                body => write!(f, "http error {status}: {body}"),
+            },
+            Self::QuotaExhausted { limit, resets_in } => write!(
+                f,
+                "daily quota of {limit} calls exhausted; resets in {}s",
+                resets_in.as_secs()
+            ),
+            Self::Rpc { code, message } => write!(f, "json-rpc error {code}: {message}"),
+            Self::Timeout { elapsed } => {
+                write!(f, "timed out after {}ms", elapsed.as_millis())
+            }
+            Self::Vetoed { reason } => write!(f, "request vetoed by middleware: {reason}"),
+            Self::WindowLimit {
+                page,
+                offset,
+                max_page,
+            } => write!(
+                f,
+                "page {page} with offset {offset} exceeds the {} result window; max page is {max_page}",
+                crate::MAX_RESULT_WINDOW
+            ),
+        }
+    }
+}
+
+impl Error for EtherscanError {}
+
+impl EtherscanError {
+    pub fn find<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a Self> {
+        let mut error = Some(error);
+
+        while let Some(e) = error {
+            if let Some(e) = e.downcast_ref::<Self>() {
+                return Some(e);
+            }
+
+            error = e.source();
+        }
+
+        None
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RequestContext {
+    pub module: Option<EtherscanModule>,
+    pub action: Option<EtherscanAction>,
+    pub chainid: Option<u64>,
+    pub url: Option<String>,
+    pub correlation_id: Option<String>,
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+
+        match (self.module, self.action) {
+            (Some(module), Some(action)) => parts.push(format!("{module}.{action}")),
+            (Some(module), None) => parts.push(module.to_string()),
+            (None, Some(action)) => parts.push(action.to_string()),
+            (None, None) => {}
+        }
+
+        if let Some(chainid) = self.chainid {
+            parts.push(format!("chain {chainid}"));
+        }
+
+        if let Some(url) = &self.url {
+            parts.push(format!("url {url}"));
+        }
+
+        if let Some(correlation_id) = &self.correlation_id {
+            parts.push(format!("correlation id {correlation_id}"));
+        }
+
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+#[derive(Debug)]
+pub struct CorrelatedError {
+    pub context: RequestContext,
+    pub source: Box<dyn Error + Send + Sync>,
+}
+
+impl CorrelatedError {
+    pub fn wrap(
+        context: &RequestContext,
+        source: Box<dyn Error + Send + Sync>,
+    ) -> Box<dyn Error + Send + Sync> {
+        if source.downcast_ref::<Self>().is_some() || *context == RequestContext::default() {
+            return source;
+        }
+
+        Box::new(Self {
+            context: context.clone(),
+            source,
+        })
+    }
+
+    pub fn context<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a RequestContext> {
+        let mut error = Some(error);
+
+        while let Some(e) = error {
+            if let Some(e) = e.downcast_ref::<Self>() {
+                return Some(&e.context);
+            }
+
+            error = e.source();
+        }
+
+        None
+    }
+
+    #[inline]
+    pub fn correlation_id<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a str> {
+        Self::context(error).and_then(|context| context.correlation_id.as_deref())
+    }
+}
+
+impl fmt::Display for CorrelatedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.source, self.context)
+    }
+}
+
+impl Error for CorrelatedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Debug)]
+pub struct EndpointHealth {
+    pub url: String,
+    pub healthy: bool,
+    pub consecutive_failures: u32,
+    pub total_failures: u64,
+    pub total_successes: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+struct EndpointState {
+    consecutive_failures: u32,
+    total_failures: u64,
+    total_successes: u64,
+    unhealthy_until: Option<Instant>,
+}
+
+#[derive(Debug)]
+pub struct Endpoints {
+    urls: Vec<String>,
+    threshold: u32,
+    cooldown: Duration,
+    state: Mutex<Vec<EndpointState>>,
+}
+
+impl Endpoints {
+    pub fn new(urls: Vec<String>) -> Self {
+        let state = vec![EndpointState::default(); urls.len()];
+
+        Self {
+            urls,
+            threshold: 3,
+            cooldown: Duration::from_secs(30),
+            state: Mutex::new(state),
+        }
+    }
+
+    #[inline]
+    pub fn with_threshold(mut self, threshold: u32) -> Self {
+        self.threshold = threshold.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    #[inline]
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+
+    pub fn select(&self, avoid: Option<usize>) -> usize {
+        let now = Instant::now();
+        let state = self.state.lock().unwrap();
+        let healthy = |index: &usize| match state[*index].unhealthy_until {
+            Some(until) => until <= now,
+            None => true,
+        };
+
+        (0..self.urls.len())
+            .filter(|index| Some(*index) != avoid)
+            .find(healthy)
+            .or_else(|| avoid.filter(healthy))
+            .or_else(|| (0..self.urls.len()).min_by_key(|index| state[*index].unhealthy_until))
+            .unwrap_or(0)
+    }
+
+    #[inline]
+    pub fn url(&self, index: usize) -> &str {
+        &self.urls[index]
+    }
+
+    pub fn record(&self, index: usize, success: bool) {
+        let mut state = self.state.lock().unwrap();
+        let Some(endpoint) = state.get_mut(index) else {
+            return;
+        };
+
+        if success {
+            endpoint.consecutive_failures = 0;
+            endpoint.total_successes += 1;
+            endpoint.unhealthy_until = None;
+        } else {
+            endpoint.consecutive_failures += 1;
+            endpoint.total_failures += 1;
+
+            if endpoint.consecutive_failures >= self.threshold {
+                endpoint.unhealthy_until = Some(Instant::now() + self.cooldown);
+            }
+        }
+    }
+
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let now = Instant::now();
+        let state = self.state.lock().unwrap();
+
+        self.urls
+            .iter()
+            .zip(state.iter())
+            .map(|(url, state)| EndpointHealth {
+                url: url.clone(),
+                healthy: state
+                    .unhealthy_until
+                    .map(|until| until <= now)
+                    .unwrap_or(true),
+                consecutive_failures: state.consecutive_failures,
+                total_failures: state.total_failures,
+                total_successes: state.total_successes,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,84 @@
+use {
+    crate::{EtherscanClient, EtherscanRequest, Page, Transaction},
+    chrono::DateTime,
+    ethabi::ethereum_types::U256,
+    futures::TryStreamExt,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+const PAGE_SIZE: u64 = 1_000;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FeeSummary {
+    pub address: U256,
+    pub transactions: u64,
+    pub total: U256,
+    pub by_month: BTreeMap<String, U256>,
+    pub by_destination: BTreeMap<U256, U256>,
+}
+
+impl FeeSummary {
+    #[inline]
+    pub fn new(address: U256) -> Self {
+        Self {
+            address,
+            ..Default::default()
+        }
+    }
+
+    pub fn add(&mut self, transaction: &Transaction, fee: U256) {
+        let month = DateTime::from_timestamp(transaction.timestamp.low_u64() as i64, 0)
+            .map(|time| time.format("%Y-%m").to_string())
+            .unwrap_or_default();
+
+        let destination = transaction
+            .to
+            .or(transaction.contract_address)
+            .unwrap_or_default();
+
+        self.transactions += 1;
+        self.total = self.total.saturating_add(fee);
+
+        let by_month = self.by_month.entry(month).or_default();
+        *by_month = by_month.saturating_add(fee);
+
+        let by_destination = self.by_destination.entry(destination).or_default();
+        *by_destination = by_destination.saturating_add(fee);
+    }
+}
+
+impl EtherscanClient {
+    pub async fn fee_summary(
+        &self,
+        address: U256,
+        use_receipts: bool,
+    ) -> Result<FeeSummary, Box<dyn Error + Send + Sync>> {
+        let transactions = self
+            .paginate::<Transaction>(
+                EtherscanRequest::account_tx_list(address, None, None, None, None, None),
+                PAGE_SIZE,
+            )
+            .map_ok(|page: Page<Transaction>| page.items)
+            .try_concat()
+            .await?;
+
+        let mut summary = FeeSummary::new(address);
+
+        for transaction in transactions.iter().filter(|tx| tx.from == address) {
+            let mut gas_used = transaction.gas_used;
+            let mut gas_price = transaction.gas_price;
+
+            if use_receipts || gas_price.is_zero() {
+                if let Some(receipt) = self.transaction_receipt(transaction.hash).await? {
+                    gas_used = receipt.gas_used;
+                    gas_price = receipt.effective_gas_price.unwrap_or(gas_price);
+                }
+            }
+
+            summary.add(transaction, gas_used.saturating_mul(gas_price));
+        }
+
+        Ok(summary)
+    }
+}
@@ -0,0 +1,139 @@
+use {
+    crate::{EtherscanClient, EtherscanError, EtherscanRequest, EtherscanSort, Transaction},
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{collections::HashSet, error::Error},
+};
+
+const FIRST_TRANSACTIONS_PAGE_SIZE: u64 = 100;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct FundingSource {
+    pub address: U256,
+    pub funder: U256,
+    pub transaction_hash: U256,
+    pub block_number: U256,
+    pub timestamp: U256,
+    pub value: U256,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FundedBy {
+    block: serde_json::Value,
+    time_stamp: serde_json::Value,
+    #[serde(with = "crate::serde_u256::hex")]
+    funding_address: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    funding_txn: U256,
+    value: serde_json::Value,
+}
+
+fn decimal(value: &serde_json::Value) -> U256 {
+    match value {
+        serde_json::Value::String(value) => U256::from_dec_str(value).unwrap_or_default(),
+        serde_json::Value::Number(value) => U256::from(value.as_u64().unwrap_or_default()),
+        _ => U256::zero(),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FundingTracer {
+    client: EtherscanClient,
+    max_depth: usize,
+}
+
+impl FundingTracer {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            max_depth: 5,
+        }
+    }
+
+    #[inline]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub async fn funded_by(
+        &self,
+        address: U256,
+    ) -> Result<Option<FundingSource>, Box<dyn Error + Send + Sync>> {
+        match self
+            .client
+            .fetch::<FundedBy>(EtherscanRequest::account_funded_by(address))
+            .await
+        {
+            Ok(funded_by) => {
+                return Ok(Some(FundingSource {
+                    address,
+                    funder: funded_by.funding_address,
+                    transaction_hash: funded_by.funding_txn,
+                    block_number: decimal(&funded_by.block),
+                    timestamp: decimal(&funded_by.time_stamp),
+                    value: decimal(&funded_by.value),
+                }))
+            }
+            Err(e) if matches!(EtherscanError::find(&*e), Some(EtherscanError::Api { .. })) => {}
+            Err(e) => return Err(e),
+        }
+
+        let transactions: Vec<Transaction> = self
+            .client
+            .fetch(EtherscanRequest::account_tx_list(
+                address,
+                None,
+                None,
+                Some(U256::one()),
+                Some(U256::from(FIRST_TRANSACTIONS_PAGE_SIZE)),
+                Some(EtherscanSort::Ascending),
+            ))
+            .await?;
+
+        Ok(transactions
+            .into_iter()
+            .find(|tx| tx.to == Some(address) && tx.from != address && tx.is_error != "1")
+            .map(|tx| FundingSource {
+                address,
+                funder: tx.from,
+                transaction_hash: tx.hash,
+                block_number: tx.block_number,
+                timestamp: tx.timestamp,
+                value: tx.value,
+            }))
+    }
+
+    pub async fn trace(
+        &self,
+        address: U256,
+    ) -> Result<Vec<FundingSource>, Box<dyn Error + Send + Sync>> {
+        let mut chain = Vec::new();
+        let mut visited = HashSet::from([address]);
+        let mut current = address;
+
+        while chain.len() < self.max_depth {
+            let Some(source) = self.funded_by(current).await? else {
+                break;
+            };
+
+            current = source.funder;
+            chain.push(source);
+
+            if current.is_zero() || !visited.insert(current) {
+                break;
+            }
+        }
+
+        Ok(chain)
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn funding_tracer(&self) -> FundingTracer {
+        FundingTracer::new(self.clone())
+    }
+}
@@ -0,0 +1,203 @@
+use {
+    crate::{
+        publish, retry, CancellationToken, EtherscanClient, EtherscanRequest, NotificationSink,
+        PublishReport,
+    },
+    ethabi::ethereum_types::U256,
+    futures::{stream, Stream},
+    serde::{de, Deserialize, Deserializer, Serialize},
+    std::{
+        collections::VecDeque,
+        error::Error,
+        sync::{Arc, Mutex},
+        time::Duration,
+    },
+};
+
+fn gwei<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    String::deserialize(deserializer)?
+        .trim()
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GasOracle {
+    #[serde(rename = "LastBlock", with = "crate::serde_u256::decimal")]
+    pub last_block: U256,
+    #[serde(rename = "SafeGasPrice", deserialize_with = "gwei")]
+    pub safe_gas_price: f64,
+    #[serde(rename = "ProposeGasPrice", deserialize_with = "gwei")]
+    pub propose_gas_price: f64,
+    #[serde(rename = "FastGasPrice", deserialize_with = "gwei")]
+    pub fast_gas_price: f64,
+    #[serde(rename = "suggestBaseFee", default, deserialize_with = "gwei")]
+    pub suggest_base_fee: f64,
+    #[serde(rename = "gasUsedRatio", default)]
+    pub gas_used_ratio: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum GasLevel {
+    Safe,
+    Propose,
+    Fast,
+}
+
+impl GasOracle {
+    #[inline]
+    pub fn price(&self, level: GasLevel) -> f64 {
+        match level {
+            GasLevel::Safe => self.safe_gas_price,
+            GasLevel::Propose => self.propose_gas_price,
+            GasLevel::Fast => self.fast_gas_price,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum GasCrossing {
+    Below,
+    Above,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum GasEvent {
+    Update(GasOracle),
+    Crossed {
+        level: GasLevel,
+        threshold: f64,
+        price: f64,
+        crossing: GasCrossing,
+    },
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub async fn gas_oracle(&self) -> Result<GasOracle, Box<dyn Error + Send + Sync>> {
+        self.fetch(EtherscanRequest::gas_tracker_gas_oracle()).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GasMonitor {
+    client: EtherscanClient,
+    interval: Duration,
+    thresholds: Vec<(GasLevel, f64)>,
+    latest: Arc<Mutex<Option<GasOracle>>>,
+    shutdown: Option<CancellationToken>,
+}
+
+impl GasMonitor {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            interval: Duration::from_secs(15),
+            thresholds: Vec::new(),
+            latest: Arc::default(),
+            shutdown: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn with_threshold(mut self, level: GasLevel, gwei: f64) -> Self {
+        self.thresholds.push((level, gwei));
+        self
+    }
+
+    #[inline]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    #[inline]
+    pub fn latest(&self) -> Option<GasOracle> {
+        self.latest.lock().unwrap().clone()
+    }
+
+    fn crossings(&self, previous: Option<&GasOracle>, current: &GasOracle) -> Vec<GasEvent> {
+        self.thresholds
+            .iter()
+            .filter_map(|&(level, threshold)| {
+                let price = current.price(level);
+                let was_below = previous.map(|previous| previous.price(level) <= threshold);
+                let is_below = price <= threshold;
+
+                let crossing = match (was_below, is_below) {
+                    (None, true) | (Some(false), true) => GasCrossing::Below,
+                    (Some(true), false) => GasCrossing::Above,
+                    _ => return None,
+                };
+
+                Some(GasEvent::Crossed {
+                    level,
+                    threshold,
+                    price,
+                    crossing,
+                })
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub async fn publish<K: NotificationSink<GasEvent> + ?Sized>(
+        &self,
+        sink: &K,
+    ) -> Result<PublishReport, Box<dyn Error + Send + Sync>> {
+        publish(self.stream(), sink).await
+    }
+
+    pub fn stream(
+        &self,
+    ) -> impl Stream<Item = Result<GasEvent, Box<dyn Error + Send + Sync>>> + Send + 'static {
+        let monitor = self.clone();
+
+        stream::unfold(
+            (monitor, VecDeque::new(), false),
+            |(monitor, mut pending, mut polled)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((Ok(event), (monitor, pending, polled)));
+                    }
+
+                    if polled
+                        && retry::idle(
+                            monitor.interval,
+                            monitor.client.cancellation(),
+                            monitor.shutdown.as_ref(),
+                        )
+                        .await
+                        .is_err()
+                    {
+                        return None;
+                    }
+
+                    polled = true;
+
+                    let oracle = match monitor.client.gas_oracle().await {
+                        Ok(oracle) => oracle,
+                        Err(e) => return Some((Err(e), (monitor, pending, polled))),
+                    };
+
+                    let previous = monitor.latest.lock().unwrap().replace(oracle.clone());
+
+                    pending.push_back(GasEvent::Update(oracle.clone()));
+                    pending.extend(monitor.crossings(previous.as_ref(), &oracle));
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,146 @@
+use {
+    crate::{DailySeries, DailyStat, EtherscanClient, GasLevel, GasOracle, StatsBackfill},
+    chrono::{DateTime, Days},
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{
+        error::Error,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+};
+
+const WEI_PER_GWEI: f64 = 1e9;
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GasSample {
+    pub timestamp: U256,
+    pub gwei: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GasSuggestion {
+    pub percentile: f64,
+    pub window: Duration,
+    pub samples: usize,
+    pub gwei: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GasHistory {
+    level: GasLevel,
+    samples: Vec<GasSample>,
+}
+
+#[inline]
+fn now() -> U256 {
+    U256::from(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+    )
+}
+
+impl GasHistory {
+    #[inline]
+    pub fn new(level: GasLevel) -> Self {
+        Self {
+            level,
+            samples: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn level(&self) -> GasLevel {
+        self.level
+    }
+
+    #[inline]
+    pub fn samples(&self) -> &[GasSample] {
+        &self.samples
+    }
+
+    #[inline]
+    pub fn record(&mut self, timestamp: U256, gwei: f64) {
+        if gwei.is_finite() {
+            self.samples.push(GasSample { timestamp, gwei });
+        }
+    }
+
+    #[inline]
+    pub fn record_oracle(&mut self, timestamp: U256, oracle: &GasOracle) {
+        self.record(timestamp, oracle.price(self.level));
+    }
+
+    pub fn record_daily(&mut self, stats: &[DailyStat]) {
+        for stat in stats {
+            if let Some(wei) = stat.value("avgGasPrice_Wei") {
+                self.record(stat.timestamp, wei / WEI_PER_GWEI);
+            }
+        }
+    }
+
+    pub fn percentile(&self, percentile: f64, since: U256) -> Option<f64> {
+        let mut prices = self
+            .samples
+            .iter()
+            .filter(|sample| sample.timestamp >= since)
+            .map(|sample| sample.gwei)
+            .collect::<Vec<_>>();
+
+        if prices.is_empty() {
+            return None;
+        }
+
+        prices.sort_by(f64::total_cmp);
+
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0) * (prices.len() - 1) as f64;
+        let lower = prices[rank.floor() as usize];
+        let upper = prices[rank.ceil() as usize];
+
+        Some(lower + (upper - lower) * rank.fract())
+    }
+
+    pub fn suggest(&self, percentile: f64, window: Duration) -> Option<GasSuggestion> {
+        let latest = self.samples.iter().map(|sample| sample.timestamp).max()?;
+        let since = latest.saturating_sub(U256::from(window.as_secs()));
+
+        Some(GasSuggestion {
+            percentile,
+            window,
+            samples: self
+                .samples
+                .iter()
+                .filter(|sample| sample.timestamp >= since)
+                .count(),
+            gwei: self.percentile(percentile, since)?,
+        })
+    }
+}
+
+impl EtherscanClient {
+    pub async fn gas_history(
+        &self,
+        level: GasLevel,
+        days: u64,
+    ) -> Result<GasHistory, Box<dyn Error + Send + Sync>> {
+        let mut history = GasHistory::new(level);
+
+        let timestamp = now();
+        let enddate = DateTime::from_timestamp(timestamp.low_u64() as i64, 0)
+            .ok_or("system clock out of range")?
+            .date_naive();
+        let startdate = enddate
+            .checked_sub_days(Days::new(days.max(1)))
+            .unwrap_or(enddate);
+
+        let stats = StatsBackfill::new(self.clone())
+            .fetch(DailySeries::AvgGasPrice, startdate, enddate)
+            .await?;
+
+        history.record_daily(&stats);
+        history.record_oracle(timestamp, &self.gas_oracle().await?);
+
+        Ok(history)
+    }
+}
@@ -0,0 +1,151 @@
+use {
+    crate::{is_unverified_error, EtherscanClient, EtherscanRequest, Page, Transaction},
+    ethabi::ethereum_types::U256,
+    futures::TryStreamExt,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+const PAGE_SIZE: u64 = 1_000;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SelectorGasStats {
+    pub selector: String,
+    pub signature: Option<String>,
+    pub calls: u64,
+    pub failed: u64,
+    pub total_gas_used: U256,
+    pub min_gas_used: U256,
+    pub max_gas_used: U256,
+}
+
+impl SelectorGasStats {
+    #[inline]
+    pub fn average_gas_used(&self) -> U256 {
+        match self.calls {
+            0 => U256::zero(),
+            calls => self.total_gas_used / U256::from(calls),
+        }
+    }
+
+    fn add(&mut self, transaction: &Transaction) {
+        if self.calls == 0 || transaction.gas_used < self.min_gas_used {
+            self.min_gas_used = transaction.gas_used;
+        }
+
+        if transaction.gas_used > self.max_gas_used {
+            self.max_gas_used = transaction.gas_used;
+        }
+
+        self.calls += 1;
+        self.total_gas_used = self.total_gas_used.saturating_add(transaction.gas_used);
+
+        if transaction.is_error == "1" {
+            self.failed += 1;
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GasUsageReport {
+    pub contract_address: U256,
+    pub startblock: Option<U256>,
+    pub endblock: Option<U256>,
+    pub selectors: BTreeMap<String, SelectorGasStats>,
+}
+
+impl GasUsageReport {
+    pub fn from_transactions(
+        contract_address: U256,
+        transactions: &[Transaction],
+        signatures: &BTreeMap<String, String>,
+    ) -> Self {
+        let mut selectors = BTreeMap::<String, SelectorGasStats>::new();
+
+        for transaction in transactions
+            .iter()
+            .filter(|tx| tx.to == Some(contract_address))
+        {
+            let input = transaction.input.to_lowercase();
+            let selector = match input.get(..10) {
+                Some(selector) if selector.starts_with("0x") => selector.to_string(),
+                _ => "0x".to_string(),
+            };
+
+            selectors
+                .entry(selector.clone())
+                .or_insert_with(|| SelectorGasStats {
+                    signature: signatures.get(&selector).cloned().or_else(|| {
+                        Some(transaction.function_name.clone()).filter(|name| !name.is_empty())
+                    }),
+                    selector,
+                    ..Default::default()
+                })
+                .add(transaction);
+        }
+
+        Self {
+            contract_address,
+            startblock: transactions.iter().map(|tx| tx.block_number).min(),
+            endblock: transactions.iter().map(|tx| tx.block_number).max(),
+            selectors,
+        }
+    }
+
+    #[inline]
+    pub fn total_gas_used(&self) -> U256 {
+        self.selectors.values().fold(U256::zero(), |total, stats| {
+            total.saturating_add(stats.total_gas_used)
+        })
+    }
+}
+
+impl EtherscanClient {
+    pub async fn gas_usage_report(
+        &self,
+        contract_address: U256,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+    ) -> Result<GasUsageReport, Box<dyn Error + Send + Sync>> {
+        let transactions = self
+            .paginate::<Transaction>(
+                EtherscanRequest::account_tx_list(
+                    contract_address,
+                    startblock,
+                    endblock,
+                    None,
+                    None,
+                    None,
+                ),
+                PAGE_SIZE,
+            )
+            .map_ok(|page: Page<Transaction>| page.items)
+            .try_concat()
+            .await?;
+
+        let signatures = match self.contract_abi(contract_address).await {
+            Ok(contract) => contract
+                .functions()
+                .map(|function| {
+                    let selector = function
+                        .short_signature()
+                        .iter()
+                        .map(|b| format!("{b:02x}"))
+                        .collect::<String>();
+
+                    (format!("0x{selector}"), function.signature())
+                })
+                .collect(),
+            Err(e) if is_unverified_error(&*e) => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut report =
+            GasUsageReport::from_transactions(contract_address, &transactions, &signatures);
+
+        report.startblock = startblock.or(report.startblock);
+        report.endblock = endblock.or(report.endblock);
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,74 @@
+use {
+    crate::{EtherscanClient, EtherscanRequest, EtherscanTag},
+    ethabi::ethereum_types::U256,
+    std::{error::Error, sync::OnceLock},
+};
+
+static GLOBAL_CLIENT: OnceLock<EtherscanClient> = OnceLock::new();
+
+pub fn set_global_client(client: EtherscanClient) -> Result<(), Box<dyn Error + Send + Sync>> {
+    GLOBAL_CLIENT
+        .set(client)
+        .map_err(|_| "global client is already initialized".into())
+}
+
+pub fn try_global_client() -> Result<&'static EtherscanClient, Box<dyn Error + Send + Sync>> {
+    if let Some(client) = GLOBAL_CLIENT.get() {
+        return Ok(client);
+    }
+
+    let client = EtherscanClient::from_env()?;
+    Ok(GLOBAL_CLIENT.get_or_init(|| client))
+}
+
+pub fn global_client() -> &'static EtherscanClient {
+    GLOBAL_CLIENT.get_or_init(|| {
+        EtherscanClient::from_env().unwrap_or_else(|e| {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %e, "falling back to a default global client");
+
+            eprintln!("etherscan: {e}; falling back to a keyless default global client");
+            EtherscanClient::default()
+        })
+    })
+}
+
+pub async fn balance(address: U256) -> Result<U256, Box<dyn Error + Send + Sync>> {
+    let result: String = try_global_client()?
+        .fetch(EtherscanRequest::account_balance(
+            address,
+            Some(EtherscanTag::Latest),
+        ))
+        .await?;
+
+    Ok(U256::from_dec_str(&result)?)
+}
+
+pub async fn token_balance(
+    account_address: U256,
+    contract_address: U256,
+) -> Result<U256, Box<dyn Error + Send + Sync>> {
+    let result: String = try_global_client()?
+        .fetch(EtherscanRequest::account_token_balance(
+            account_address,
+            contract_address,
+            Some(EtherscanTag::Latest),
+        ))
+        .await?;
+
+    Ok(U256::from_dec_str(&result)?)
+}
+
+pub async fn token_supply(contract_address: U256) -> Result<U256, Box<dyn Error + Send + Sync>> {
+    let result: String = try_global_client()?
+        .fetch(EtherscanRequest::stats_token_supply(contract_address))
+        .await?;
+
+    Ok(U256::from_dec_str(&result)?)
+}
+
+pub async fn abi(contract_address: U256) -> Result<String, Box<dyn Error + Send + Sync>> {
+    try_global_client()?
+        .fetch(EtherscanRequest::contract_get_abi(contract_address))
+        .await
+}
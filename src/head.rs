@@ -0,0 +1,156 @@
+use {
+    crate::{retry, Block, CancellationToken, EtherscanClient},
+    ethabi::ethereum_types::U256,
+    futures::{stream, Stream},
+    serde::{Deserialize, Serialize},
+    std::{error::Error, time::Duration},
+};
+
+pub const SLOT_TIME: Duration = Duration::from_secs(12);
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NewBlock {
+    pub number: U256,
+    pub block: Option<Block>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BlockWatcher {
+    client: EtherscanClient,
+    slot_time: Duration,
+    min_interval: Duration,
+    startblock: Option<U256>,
+    full: bool,
+    shutdown: Option<CancellationToken>,
+}
+
+struct BlockState {
+    watcher: BlockWatcher,
+    next_block: Option<U256>,
+    head: U256,
+    delay: Option<Duration>,
+}
+
+impl BlockWatcher {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            slot_time: SLOT_TIME,
+            min_interval: Duration::from_secs(2),
+            startblock: None,
+            full: false,
+            shutdown: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_slot_time(mut self, slot_time: Duration) -> Self {
+        self.slot_time = slot_time;
+        self
+    }
+
+    #[inline]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    #[inline]
+    pub fn with_startblock(mut self, startblock: U256) -> Self {
+        self.startblock = Some(startblock);
+        self
+    }
+
+    #[inline]
+    pub fn with_full_blocks(mut self, full: bool) -> Self {
+        self.full = full;
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn slot_time(&self) -> Duration {
+        self.slot_time
+    }
+
+    pub fn stream(
+        self,
+    ) -> impl Stream<Item = Result<NewBlock, Box<dyn Error + Send + Sync>>> + Send + 'static {
+        let state = BlockState {
+            next_block: self.startblock,
+            watcher: self,
+            head: U256::zero(),
+            delay: None,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(number) = state.next_block.filter(|number| *number <= state.head) {
+                    state.next_block = Some(number + U256::one());
+
+                    let block = match state.watcher.full {
+                        true => match state.watcher.client.block_by_number(number).await {
+                            Ok(block) => block,
+                            Err(e) => {
+                                state.next_block = Some(number);
+                                return Some((Err(e), state));
+                            }
+                        },
+                        false => None,
+                    };
+
+                    return Some((Ok(NewBlock { number, block }), state));
+                }
+
+                if let Some(delay) = state.delay {
+                    let cancellation = state.watcher.client.cancellation();
+                    let shutdown = state.watcher.shutdown.as_ref();
+
+                    if retry::idle(delay, cancellation, shutdown).await.is_err() {
+                        return None;
+                    }
+                }
+
+                match state.watcher.client.block_number().await {
+                    Ok(head) => state.observe(head),
+                    Err(e) => {
+                        state.delay = Some(state.watcher.slot_time);
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl BlockState {
+    fn observe(&mut self, head: U256) {
+        let advanced = head > self.head;
+        self.head = self.head.max(head);
+
+        if self.next_block.is_none() {
+            self.next_block = Some(head);
+        }
+
+        let min_interval = self.watcher.min_interval.min(self.watcher.slot_time);
+
+        self.delay = Some(match (advanced, self.delay) {
+            (true, _) => self.watcher.slot_time,
+            (false, Some(delay)) => (delay / 2).max(min_interval),
+            (false, None) => min_interval,
+        });
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn blocks(&self) -> BlockWatcher {
+        BlockWatcher::new(self.clone())
+    }
+}
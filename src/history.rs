@@ -0,0 +1,281 @@
+use {
+    crate::{
+        CancellationToken, Cursor, EtherscanClient, EtherscanRequest, InternalTransaction,
+        Paginated, TokenTransfer, Transaction, DEFAULT_PREFETCH_DEPTH,
+    },
+    ethabi::ethereum_types::U256,
+    futures::{
+        future,
+        stream::{self, BoxStream},
+        Stream, StreamExt, TryStreamExt,
+    },
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    std::{collections::VecDeque, error::Error},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HistoryEvent {
+    Transaction(Transaction),
+    Internal(InternalTransaction),
+    Erc20(TokenTransfer),
+    Erc721(TokenTransfer),
+    Erc1155(TokenTransfer),
+}
+
+impl HistoryEvent {
+    pub fn block_number(&self) -> U256 {
+        match self {
+            Self::Transaction(tx) => tx.block_number,
+            Self::Internal(tx) => tx.block_number,
+            Self::Erc20(transfer) | Self::Erc721(transfer) | Self::Erc1155(transfer) => {
+                transfer.block_number
+            }
+        }
+    }
+
+    pub fn timestamp(&self) -> U256 {
+        match self {
+            Self::Transaction(tx) => tx.timestamp,
+            Self::Internal(tx) => tx.timestamp,
+            Self::Erc20(transfer) | Self::Erc721(transfer) | Self::Erc1155(transfer) => {
+                transfer.timestamp
+            }
+        }
+    }
+
+    pub fn block_hash(&self) -> Option<U256> {
+        match self {
+            Self::Transaction(tx) => Some(tx.block_hash),
+            Self::Internal(_) => None,
+            Self::Erc20(transfer) | Self::Erc721(transfer) | Self::Erc1155(transfer) => {
+                Some(transfer.block_hash)
+            }
+        }
+    }
+
+    pub fn confirmations(&self) -> Option<U256> {
+        match self {
+            Self::Transaction(tx) => Some(tx.confirmations),
+            Self::Internal(_) => None,
+            Self::Erc20(transfer) | Self::Erc721(transfer) | Self::Erc1155(transfer) => {
+                Some(transfer.confirmations)
+            }
+        }
+    }
+
+    pub fn hash(&self) -> U256 {
+        match self {
+            Self::Transaction(tx) => tx.hash,
+            Self::Internal(tx) => tx.hash,
+            Self::Erc20(transfer) | Self::Erc721(transfer) | Self::Erc1155(transfer) => {
+                transfer.hash
+            }
+        }
+    }
+}
+
+type EventStream = BoxStream<'static, Result<HistoryEvent, Box<dyn Error + Send + Sync>>>;
+
+#[derive(Clone, Debug)]
+pub struct AccountHistory {
+    client: EtherscanClient,
+    address: U256,
+    startblock: Option<U256>,
+    endblock: Option<U256>,
+    offset: u64,
+    prefetch: Option<usize>,
+    shutdown: Option<CancellationToken>,
+}
+
+impl AccountHistory {
+    #[inline]
+    pub fn new(client: EtherscanClient, address: U256) -> Self {
+        Self {
+            client,
+            address,
+            startblock: None,
+            endblock: None,
+            offset: 1_000,
+            prefetch: None,
+            shutdown: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_block_range(mut self, startblock: Option<U256>, endblock: Option<U256>) -> Self {
+        self.startblock = startblock;
+        self.endblock = endblock;
+        self
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn with_prefetch(mut self, depth: usize) -> Self {
+        self.prefetch = Some(depth);
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn address(&self) -> U256 {
+        self.address
+    }
+
+    pub async fn fetch(&self) -> Result<Vec<HistoryEvent>, Box<dyn Error + Send + Sync>> {
+        self.stream().try_collect().await
+    }
+
+    /// Merges every history source in block order.
+    ///
+    /// A source that fails yields its error once and then ends; the remaining
+    /// sources keep streaming. Resume the failed kind from the last block it
+    /// produced with [`AccountHistory::with_block_range`].
+    pub fn stream(
+        &self,
+    ) -> impl Stream<Item = Result<HistoryEvent, Box<dyn Error + Send + Sync>>> + Send + 'static
+    {
+        let Self {
+            address,
+            startblock,
+            endblock,
+            ..
+        } = *self;
+
+        let sources = vec![
+            self.source(
+                EtherscanRequest::account_tx_list(address, startblock, endblock, None, None, None),
+                HistoryEvent::Transaction,
+            ),
+            self.source(
+                EtherscanRequest::account_tx_list_internal(
+                    address, startblock, endblock, None, None, None,
+                ),
+                HistoryEvent::Internal,
+            ),
+            self.source(
+                EtherscanRequest::account_token_tx(
+                    None,
+                    Some(address),
+                    startblock,
+                    endblock,
+                    None,
+                    None,
+                    None,
+                ),
+                HistoryEvent::Erc20,
+            ),
+            self.source(
+                EtherscanRequest::account_token_nft_tx_filtered(
+                    None,
+                    Some(address),
+                    startblock,
+                    endblock,
+                    None,
+                    None,
+                    None,
+                ),
+                HistoryEvent::Erc721,
+            ),
+            self.source(
+                EtherscanRequest::account_token_1155_tx(
+                    None,
+                    Some(address),
+                    startblock,
+                    endblock,
+                    None,
+                    None,
+                    None,
+                ),
+                HistoryEvent::Erc1155,
+            ),
+        ];
+
+        merge(sources)
+    }
+
+    fn source<T, F>(&self, request: EtherscanRequest, f: F) -> EventStream
+    where
+        T: DeserializeOwned + Paginated + Send + 'static,
+        F: Fn(T) -> HistoryEvent + Send + Sync + 'static,
+    {
+        let cursor = Cursor::new(request.startblock.unwrap_or_default());
+        let pages = self.client.pages::<T>(
+            request,
+            self.offset,
+            cursor,
+            self.prefetch.unwrap_or(DEFAULT_PREFETCH_DEPTH),
+            self.shutdown.clone(),
+        );
+
+        pages
+            .map_ok(move |page| {
+                let events = page.items.into_iter().map(|item| Ok(f(item)));
+                stream::iter(events.collect::<Vec<_>>())
+            })
+            .try_flatten()
+            .boxed()
+    }
+}
+
+struct Merge {
+    sources: Vec<(EventStream, Option<HistoryEvent>, bool)>,
+    errors: VecDeque<Box<dyn Error + Send + Sync>>,
+}
+
+fn merge(sources: Vec<EventStream>) -> EventStream {
+    let state = Merge {
+        sources: sources
+            .into_iter()
+            .map(|source| (source, None, false))
+            .collect(),
+        errors: VecDeque::new(),
+    };
+
+    stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+
+        let refills = state
+            .sources
+            .iter_mut()
+            .filter(|(_, head, done)| head.is_none() && !*done)
+            .map(|(source, head, done)| async move { (source.next().await, head, done) });
+
+        for (next, head, done) in future::join_all(refills).await {
+            match next {
+                Some(Ok(event)) => *head = Some(event),
+                Some(Err(e)) => {
+                    *done = true;
+                    state.errors.push_back(e);
+                }
+                None => *done = true,
+            }
+        }
+
+        if let Some(e) = state.errors.pop_front() {
+            return Some((Err(e), Some(state)));
+        }
+
+        let next = state
+            .sources
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (_, head, _))| Some((head.as_ref()?.block_number(), index)))
+            .min()
+            .map(|(_, index)| index)?;
+
+        let event = state.sources[next].1.take()?;
+        Some((Ok(event), Some(state)))
+    })
+    .boxed()
+}
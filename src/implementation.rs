@@ -0,0 +1,77 @@
+use {
+    crate::{is_unverified_error, ContractSource, EtherscanClient},
+    ethabi::{ethereum_types::U256, Contract},
+    std::error::Error,
+};
+
+pub const EIP1967_IMPLEMENTATION_SLOT: &str =
+    "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+
+pub const EIP1822_PROXIABLE_SLOT: &str =
+    "0xc5f16f0fcc639fa48a6947836d9850f504798523bf8c9a3a87d5876cf622bcf7";
+
+#[derive(Clone, Debug)]
+pub struct Implementation {
+    pub address: U256,
+    pub abi: Option<Contract>,
+}
+
+fn slot(slot: &str) -> U256 {
+    U256::from_str_radix(slot.trim_start_matches("0x"), 16).unwrap_or_default()
+}
+
+fn address_mask() -> U256 {
+    (U256::one() << 160) - U256::one()
+}
+
+impl EtherscanClient {
+    pub async fn resolve_implementation(
+        &self,
+        address: U256,
+    ) -> Result<Option<Implementation>, Box<dyn Error + Send + Sync>> {
+        let source = self.contract_source(address).await?;
+        self.implementation_of(address, &source).await
+    }
+
+    pub(crate) async fn implementation_of(
+        &self,
+        address: U256,
+        source: &ContractSource,
+    ) -> Result<Option<Implementation>, Box<dyn Error + Send + Sync>> {
+        let mut implementation = match source.implementation.trim() {
+            "" => None,
+            implementation => Some(U256::from_str_radix(
+                implementation.trim_start_matches("0x"),
+                16,
+            )?),
+        }
+        .filter(|implementation| !implementation.is_zero());
+
+        for position in [EIP1967_IMPLEMENTATION_SLOT, EIP1822_PROXIABLE_SLOT] {
+            if implementation.is_some() {
+                break;
+            }
+
+            let value = self.storage_at(address, slot(position)).await? & address_mask();
+
+            if !value.is_zero() {
+                implementation = Some(value);
+            }
+        }
+
+        let Some(implementation) = implementation else {
+            return Ok(None);
+        };
+
+        let abi = match self.contract_abi(implementation).await {
+            Ok(abi) => Some(abi),
+            Err(e) if is_unverified_error(e.as_ref()) => None,
+            Err(e) => return Err(e),
+        };
+
+        Ok(Some(Implementation {
+            address: implementation,
+            abi,
+        }))
+    }
+}
@@ -0,0 +1,341 @@
+use {crate::EtherscanClient, ethabi::ethereum_types::U256, serde_json::Value, std::error::Error};
+
+pub const INTERFACE_PRAGMA: &str = "^0.8.4";
+
+fn entries(abi: &str) -> Result<Vec<Value>, Box<dyn Error + Send + Sync>> {
+    match serde_json::from_str::<Value>(abi)? {
+        Value::Array(entries) => Ok(entries),
+        _ => Err("ABI must be a JSON array".into()),
+    }
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).unwrap_or_default()
+}
+
+fn flag(value: &Value, key: &str) -> bool {
+    value.get(key).and_then(Value::as_bool).unwrap_or_default()
+}
+
+fn params<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn kind(entry: &Value) -> &str {
+    match field(entry, "type") {
+        "" => "function",
+        kind => kind,
+    }
+}
+
+fn mutability(entry: &Value) -> &str {
+    match field(entry, "stateMutability") {
+        "" if flag(entry, "payable") => "payable",
+        "" if flag(entry, "constant") => "view",
+        "" => "nonpayable",
+        mutability => mutability,
+    }
+}
+
+fn is_reference(kind: &str) -> bool {
+    kind == "string" || kind == "bytes" || kind.ends_with(']') || kind.starts_with("tuple")
+}
+
+pub(crate) fn identifier(name: &str) -> Option<String> {
+    let name = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect::<String>();
+
+    match name.chars().next()? {
+        c if c.is_ascii_digit() => Some(format!("_{name}")),
+        _ => Some(name),
+    }
+}
+
+#[derive(Default)]
+struct Structs {
+    definitions: Vec<(String, Vec<String>)>,
+}
+
+impl Structs {
+    fn type_name(&mut self, param: &Value) -> String {
+        let kind = field(param, "type");
+
+        let Some(suffix) = kind.strip_prefix("tuple") else {
+            return kind.to_string();
+        };
+
+        let mut fields = Vec::new();
+
+        for (i, component) in params(param, "components").iter().enumerate() {
+            let name = match field(component, "name") {
+                "" => format!("field{i}"),
+                name => name.to_string(),
+            };
+
+            fields.push(format!("{} {name};", self.type_name(component)));
+        }
+
+        let name = field(param, "internalType")
+            .strip_prefix("struct ")
+            .and_then(|internal| internal.split('[').next())
+            .and_then(|internal| internal.rsplit('.').next())
+            .and_then(identifier)
+            .or_else(|| {
+                self.definitions
+                    .iter()
+                    .find(|(_, existing)| *existing == fields)
+                    .map(|(name, _)| name.clone())
+            })
+            .unwrap_or_else(|| format!("Struct{}", self.definitions.len()));
+
+        if !self
+            .definitions
+            .iter()
+            .any(|(existing, _)| *existing == name)
+        {
+            self.definitions.push((name.clone(), fields));
+        }
+
+        format!("{name}{suffix}")
+    }
+
+    fn params(&mut self, params: &[Value], location: Option<&str>, indexed: bool) -> String {
+        let mut rendered = Vec::with_capacity(params.len());
+
+        for param in params {
+            let mut item = self.type_name(param);
+
+            if indexed && flag(param, "indexed") {
+                item.push_str(" indexed");
+            }
+
+            if let Some(location) = location.filter(|_| is_reference(field(param, "type"))) {
+                item.push(' ');
+                item.push_str(location);
+            }
+
+            match field(param, "name") {
+                "" => {}
+                name => {
+                    item.push(' ');
+                    item.push_str(name);
+                }
+            }
+
+            rendered.push(item);
+        }
+
+        rendered.join(", ")
+    }
+}
+
+pub fn solidity_interface(name: &str, abi: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let mut structs = Structs::default();
+    let mut events = Vec::new();
+    let mut errors = Vec::new();
+    let mut functions = Vec::new();
+
+    for entry in entries(abi)? {
+        let entry_name = field(&entry, "name");
+        let inputs = params(&entry, "inputs");
+
+        match kind(&entry) {
+            "event" => events.push(format!(
+                "event {entry_name}({}){};",
+                structs.params(inputs, None, true),
+                if flag(&entry, "anonymous") {
+                    " anonymous"
+                } else {
+                    ""
+                }
+            )),
+            "error" => errors.push(format!(
+                "error {entry_name}({});",
+                structs.params(inputs, None, false)
+            )),
+            "fallback" => functions.push(format!(
+                "fallback() external{};",
+                if mutability(&entry) == "payable" {
+                    " payable"
+                } else {
+                    ""
+                }
+            )),
+            "receive" => functions.push("receive() external payable;".to_string()),
+            "function" => {
+                let mut function = format!(
+                    "function {entry_name}({}) external",
+                    structs.params(inputs, Some("calldata"), false)
+                );
+
+                match mutability(&entry) {
+                    "nonpayable" => {}
+                    mutability => {
+                        function.push(' ');
+                        function.push_str(mutability);
+                    }
+                }
+
+                let outputs = params(&entry, "outputs");
+                if !outputs.is_empty() {
+                    function.push_str(&format!(
+                        " returns ({})",
+                        structs.params(outputs, Some("memory"), false)
+                    ));
+                }
+
+                function.push(';');
+                functions.push(function);
+            }
+            _ => {}
+        }
+    }
+
+    let mut sections = structs
+        .definitions
+        .into_iter()
+        .map(|(name, fields)| {
+            let fields = fields
+                .iter()
+                .map(|field| format!("        {field}\n"))
+                .collect::<String>();
+
+            format!("    struct {name} {{\n{fields}    }}\n")
+        })
+        .collect::<Vec<_>>();
+
+    for section in [events, errors, functions] {
+        if !section.is_empty() {
+            sections.push(
+                section
+                    .iter()
+                    .map(|line| format!("    {line}\n"))
+                    .collect::<String>(),
+            );
+        }
+    }
+
+    Ok(format!(
+        "// SPDX-License-Identifier: UNLICENSED\npragma solidity {INTERFACE_PRAGMA};\n\ninterface {name} {{\n{}}}\n",
+        sections.join("\n")
+    ))
+}
+
+fn human_type(param: &Value) -> String {
+    let kind = field(param, "type");
+
+    match kind.strip_prefix("tuple") {
+        Some(suffix) => format!(
+            "tuple({}){suffix}",
+            human_params(params(param, "components"), false)
+        ),
+        None => kind.to_string(),
+    }
+}
+
+fn human_params(params: &[Value], indexed: bool) -> String {
+    params
+        .iter()
+        .map(|param| {
+            let mut item = human_type(param);
+
+            if indexed && flag(param, "indexed") {
+                item.push_str(" indexed");
+            }
+
+            match field(param, "name") {
+                "" => item,
+                name => format!("{item} {name}"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn human_readable_abi(abi: &str) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+    Ok(entries(abi)?
+        .iter()
+        .filter_map(|entry| {
+            let name = field(entry, "name");
+            let inputs = params(entry, "inputs");
+            let mutability = match mutability(entry) {
+                "nonpayable" => String::new(),
+                mutability => format!(" {mutability}"),
+            };
+
+            Some(match kind(entry) {
+                "constructor" => {
+                    format!("constructor({}){mutability}", human_params(inputs, false))
+                }
+                "fallback" => format!("fallback(){mutability}"),
+                "receive" => "receive() payable".to_string(),
+                "event" => format!(
+                    "event {name}({}){}",
+                    human_params(inputs, true),
+                    if flag(entry, "anonymous") {
+                        " anonymous"
+                    } else {
+                        ""
+                    }
+                ),
+                "error" => format!("error {name}({})", human_params(inputs, false)),
+                "function" => {
+                    let outputs = params(entry, "outputs");
+
+                    match outputs.is_empty() {
+                        true => format!(
+                            "function {name}({}){mutability}",
+                            human_params(inputs, false)
+                        ),
+                        false => format!(
+                            "function {name}({}){mutability} returns ({})",
+                            human_params(inputs, false),
+                            human_params(outputs, false)
+                        ),
+                    }
+                }
+                _ => return None,
+            })
+        })
+        .collect())
+}
+
+impl EtherscanClient {
+    pub async fn solidity_interface(
+        &self,
+        address: U256,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let source = self.contract_source(address).await?;
+
+        if !source.is_verified() {
+            return Err(format!("contract {address:#x} is not verified").into());
+        }
+
+        let name = identifier(&source.contract_name)
+            .ok_or_else(|| format!("no contract name for {address:#x}"))?;
+
+        solidity_interface(&format!("I{name}"), &source.abi)
+    }
+
+    pub async fn human_readable_abi(
+        &self,
+        address: U256,
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+        let source = self.contract_source(address).await?;
+
+        if !source.is_verified() {
+            return Err(format!("contract {address:#x} is not verified").into());
+        }
+
+        human_readable_abi(&source.abi)
+    }
+}
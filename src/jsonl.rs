@@ -0,0 +1,63 @@
+use {
+    crate::Page,
+    futures::{io::AsyncWrite, pin_mut, AsyncWriteExt, Stream, StreamExt},
+    serde::Serialize,
+    std::error::Error,
+};
+
+pub async fn write_jsonl<S, T, W>(
+    stream: S,
+    writer: &mut W,
+) -> Result<u64, Box<dyn Error + Send + Sync>>
+where
+    S: Stream<Item = Result<T, Box<dyn Error + Send + Sync>>>,
+    T: Serialize,
+    W: AsyncWrite + Unpin,
+{
+    pin_mut!(stream);
+
+    let mut written = 0;
+    let mut line = Vec::new();
+
+    while let Some(item) = stream.next().await {
+        line.clear();
+        serde_json::to_writer(&mut line, &item?)?;
+        line.push(b'\n');
+
+        writer.write_all(&line).await?;
+        written += 1;
+    }
+
+    writer.flush().await?;
+    Ok(written)
+}
+
+pub async fn write_pages_jsonl<S, T, W>(
+    pages: S,
+    writer: &mut W,
+) -> Result<u64, Box<dyn Error + Send + Sync>>
+where
+    S: Stream<Item = Result<Page<T>, Box<dyn Error + Send + Sync>>>,
+    T: Serialize,
+    W: AsyncWrite + Unpin,
+{
+    pin_mut!(pages);
+
+    let mut written = 0;
+    let mut line = Vec::new();
+
+    while let Some(page) = pages.next().await {
+        for item in page?.items {
+            line.clear();
+            serde_json::to_writer(&mut line, &item)?;
+            line.push(b'\n');
+
+            writer.write_all(&line).await?;
+            written += 1;
+        }
+
+        writer.flush().await?;
+    }
+
+    Ok(written)
+}
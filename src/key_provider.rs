@@ -0,0 +1,79 @@
+use {
+    crate::{ApiKey, ApiKeyPool},
+    futures::future::{self, BoxFuture},
+    std::{env, error::Error, sync::Arc},
+};
+
+pub trait KeyProvider: Send + Sync {
+    fn key(&self) -> BoxFuture<'_, Result<Option<ApiKey>, Box<dyn Error + Send + Sync>>>;
+}
+
+impl<P: KeyProvider + ?Sized> KeyProvider for Arc<P> {
+    #[inline]
+    fn key(&self) -> BoxFuture<'_, Result<Option<ApiKey>, Box<dyn Error + Send + Sync>>> {
+        (**self).key()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StaticKey(ApiKey);
+
+impl StaticKey {
+    #[inline]
+    pub fn new<K: Into<ApiKey>>(apikey: K) -> Self {
+        Self(apikey.into())
+    }
+}
+
+impl KeyProvider for StaticKey {
+    #[inline]
+    fn key(&self) -> BoxFuture<'_, Result<Option<ApiKey>, Box<dyn Error + Send + Sync>>> {
+        Box::pin(future::ready(Ok(Some(self.0.clone()))))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EnvKey {
+    var: String,
+    required: bool,
+}
+
+impl EnvKey {
+    #[inline]
+    pub fn new<S: Into<String>>(var: S) -> Self {
+        Self {
+            var: var.into(),
+            required: true,
+        }
+    }
+
+    #[inline]
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    #[inline]
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+}
+
+impl KeyProvider for EnvKey {
+    fn key(&self) -> BoxFuture<'_, Result<Option<ApiKey>, Box<dyn Error + Send + Sync>>> {
+        let result = match env::var(&self.var) {
+            Ok(apikey) if !apikey.trim().is_empty() => Ok(Some(ApiKey::from(apikey.trim()))),
+            _ if self.required => Err(format!("{} is not set", self.var).into()),
+            _ => Ok(None),
+        };
+
+        Box::pin(future::ready(result))
+    }
+}
+
+impl KeyProvider for ApiKeyPool {
+    #[inline]
+    fn key(&self) -> BoxFuture<'_, Result<Option<ApiKey>, Box<dyn Error + Send + Sync>>> {
+        Box::pin(future::ready(Ok(self.next_key())))
+    }
+}
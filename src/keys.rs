@@ -0,0 +1,159 @@
+use {
+    crate::ApiKey,
+    std::{fmt, sync::Mutex, time::Instant},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KeySelection {
+    #[default]
+    RoundRobin,
+    LeastRecentlyUsed,
+    Weighted,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyUsage {
+    pub weight: u32,
+    pub requests: u64,
+    pub last_used: Option<Instant>,
+}
+
+#[derive(Clone, Debug)]
+struct PooledKey {
+    apikey: ApiKey,
+    usage: KeyUsage,
+    current_weight: i64,
+}
+
+#[derive(Default)]
+struct PoolState {
+    keys: Vec<PooledKey>,
+    next: usize,
+}
+
+#[derive(Default)]
+pub struct ApiKeyPool {
+    selection: KeySelection,
+    state: Mutex<PoolState>,
+}
+
+impl fmt::Debug for ApiKeyPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKeyPool")
+            .field("selection", &self.selection)
+            .field("keys", &self.len())
+            .finish()
+    }
+}
+
+impl ApiKeyPool {
+    #[inline]
+    pub fn new(selection: KeySelection) -> Self {
+        Self {
+            selection,
+            state: Mutex::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_key<K: Into<ApiKey>>(self, apikey: K) -> Self {
+        self.with_weighted_key(apikey, 1)
+    }
+
+    #[inline]
+    pub fn with_weighted_key<K: Into<ApiKey>>(self, apikey: K, weight: u32) -> Self {
+        self.state.lock().unwrap().keys.push(PooledKey {
+            apikey: apikey.into(),
+            usage: KeyUsage {
+                weight: weight.max(1),
+                ..Default::default()
+            },
+            current_weight: 0,
+        });
+        self
+    }
+
+    #[inline]
+    pub fn selection(&self) -> KeySelection {
+        self.selection
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().keys.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn next_key(&self) -> Option<ApiKey> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.keys.is_empty() {
+            return None;
+        }
+
+        let index = match self.selection {
+            KeySelection::RoundRobin => {
+                let index = state.next % state.keys.len();
+                state.next = index + 1;
+                index
+            }
+            KeySelection::LeastRecentlyUsed => state
+                .keys
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, key)| key.usage.last_used)
+                .map(|(index, _)| index)?,
+            KeySelection::Weighted => {
+                let total = state
+                    .keys
+                    .iter()
+                    .map(|key| key.usage.weight as i64)
+                    .sum::<i64>();
+
+                for key in &mut state.keys {
+                    key.current_weight += key.usage.weight as i64;
+                }
+
+                let index = state
+                    .keys
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(index, key)| (key.current_weight, std::cmp::Reverse(*index)))
+                    .map(|(index, _)| index)?;
+
+                state.keys[index].current_weight -= total;
+                index
+            }
+        };
+
+        let key = &mut state.keys[index];
+        key.usage.requests += 1;
+        key.usage.last_used = Some(Instant::now());
+
+        Some(key.apikey.clone())
+    }
+
+    pub fn usage(&self) -> Vec<(ApiKey, KeyUsage)> {
+        self.state
+            .lock()
+            .unwrap()
+            .keys
+            .iter()
+            .map(|key| (key.apikey.clone(), key.usage))
+            .collect()
+    }
+
+    pub fn usage_of(&self, apikey: &ApiKey) -> Option<KeyUsage> {
+        self.state
+            .lock()
+            .unwrap()
+            .keys
+            .iter()
+            .find(|key| &key.apikey == apikey)
+            .map(|key| key.usage)
+    }
+}
@@ -0,0 +1,143 @@
+use {
+    crate::{EtherscanClient, EtherscanRequest},
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        error::Error,
+        fmt,
+        sync::{Arc, Mutex},
+    },
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AddressTag {
+    #[serde(with = "crate::serde_u256::hex")]
+    pub address: U256,
+    pub nametag: String,
+    pub internal_nametag: String,
+    pub url: String,
+    pub shortdescription: String,
+    pub notes_1: String,
+    pub notes_2: String,
+    pub labels: Vec<String>,
+    pub labels_slug: Vec<String>,
+    pub reputation: i64,
+}
+
+impl AddressTag {
+    #[inline]
+    pub fn label(&self) -> Option<&str> {
+        [self.nametag.as_str(), self.internal_nametag.as_str()]
+            .into_iter()
+            .chain(self.labels.iter().map(String::as_str))
+            .find(|label| !label.is_empty())
+    }
+}
+
+impl EtherscanClient {
+    pub async fn address_tag(
+        &self,
+        address: U256,
+    ) -> Result<Option<AddressTag>, Box<dyn Error + Send + Sync>> {
+        let mut tags: Vec<AddressTag> = self
+            .fetch(EtherscanRequest::nametag_get_address_tag(address))
+            .await?;
+
+        Ok(tags.pop())
+    }
+
+    #[inline]
+    pub fn labels(&self) -> LabelBook {
+        LabelBook::new(self.clone())
+    }
+}
+
+#[derive(Clone)]
+pub struct LabelBook {
+    client: EtherscanClient,
+    overrides: HashMap<U256, String>,
+    tags: Arc<Mutex<HashMap<U256, Option<AddressTag>>>>,
+}
+
+impl fmt::Debug for LabelBook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LabelBook")
+            .field("client", &self.client)
+            .field("overrides", &self.overrides.len())
+            .field("tags", &self.tags.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl LabelBook {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            overrides: HashMap::new(),
+            tags: Arc::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_override(mut self, address: U256, label: String) -> Self {
+        self.overrides.insert(address, label);
+        self
+    }
+
+    #[inline]
+    pub fn with_overrides<I: IntoIterator<Item = (U256, String)>>(mut self, overrides: I) -> Self {
+        self.overrides.extend(overrides);
+        self
+    }
+
+    pub async fn tag(
+        &self,
+        address: U256,
+    ) -> Result<Option<AddressTag>, Box<dyn Error + Send + Sync>> {
+        if let Some(tag) = self.tags.lock().unwrap().get(&address) {
+            return Ok(tag.clone());
+        }
+
+        let tag = self.client.address_tag(address).await?;
+
+        self.tags.lock().unwrap().insert(address, tag.clone());
+
+        Ok(tag)
+    }
+
+    pub async fn label(
+        &self,
+        address: U256,
+    ) -> Result<Option<String>, Box<dyn Error + Send + Sync>> {
+        if let Some(label) = self.overrides.get(&address) {
+            return Ok(Some(label.clone()));
+        }
+
+        Ok(self
+            .tag(address)
+            .await?
+            .and_then(|tag| tag.label().map(String::from)))
+    }
+
+    pub async fn annotate<I: IntoIterator<Item = U256>>(
+        &self,
+        addresses: I,
+    ) -> Result<HashMap<U256, String>, Box<dyn Error + Send + Sync>> {
+        let mut labels = HashMap::new();
+
+        for address in addresses {
+            if labels.contains_key(&address) {
+                continue;
+            }
+
+            if let Some(label) = self.label(address).await? {
+                labels.insert(address, label);
+            }
+        }
+
+        Ok(labels)
+    }
+}
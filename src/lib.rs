@@ -1,28 +1,293 @@
+#[cfg(feature = "abigen")]
+pub mod abigen;
+
+#[cfg(feature = "alloy")]
+pub mod alloy;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "config")]
+pub mod config;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "polars")]
+pub mod polars;
+
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+mod abi;
+pub use abi::{is_unverified_error, AbiCache};
+
+mod account;
+pub use account::{
+    AccountBalance, AccountSnapshot, BeaconWithdrawal, InternalTransaction, MinedBlock,
+    TokenTransfer, Transaction, BALANCE_MULTI_LIMIT,
+};
+
+mod apikey;
+pub use apikey::{redact_url, url_apikey, ApiKey};
+
+mod approval;
+pub use approval::{outstanding_approvals, ApprovalEvent, ApprovalKind, ApprovalScanner};
+
+mod batch;
+pub use batch::{BatchExecutor, BatchReport, BatchSummary, DEFAULT_BATCH_CONCURRENCY};
+
+mod block;
+pub use block::{BlockCountdown, BlockRange};
+
+mod bytecode;
+pub use bytecode::{keccak256, strip_metadata, BytecodeComparison};
+
+pub mod cache;
+
+mod call_batch;
+pub use call_batch::{CallBatch, ReadCall};
+
+mod cancel;
+pub use cancel::{CancellationToken, Cancelled};
+
+mod circuit;
+pub use circuit::{CircuitBreaker, CircuitProbe};
+
+mod client;
+pub use client::EtherscanClient;
+
+mod confirm;
+pub use confirm::Confirmation;
+
+mod decode;
+pub use decode::{decode_hex, decode_input, decode_log, DecodedCall, DecodedLog};
+
+mod dedup;
+pub use dedup::{InFlightRequests, SharedError};
+
+mod deployment;
+pub use deployment::{ContractCreation, Deployment, DeploymentWatcher, CONTRACT_CREATION_LIMIT};
+
+mod deposit;
+pub use deposit::{Deposit, DepositMonitor};
+
+mod diff;
+pub use diff::{diff_sources, DiffLine, FileDiff, FileStatus, LineChange, SourceDiff};
+
+mod ens;
+pub use ens::{namehash, ENS_REGISTRY};
+
+mod error;
+pub use error::{CorrelatedError, EtherscanError, RequestContext};
+
+mod failover;
+pub use failover::{EndpointHealth, Endpoints};
+
+mod fees;
+pub use fees::FeeSummary;
+
+mod funding;
+pub use funding::{FundingSource, FundingTracer};
+
+mod gas;
+pub use gas::{GasCrossing, GasEvent, GasLevel, GasMonitor, GasOracle};
+
+mod gas_history;
+pub use gas_history::{GasHistory, GasSample, GasSuggestion};
+
+mod gas_usage;
+pub use gas_usage::{GasUsageReport, SelectorGasStats};
+
+#[cfg(feature = "global")]
+mod global;
+#[cfg(feature = "global")]
+pub use global::*;
+
+mod head;
+pub use head::{BlockWatcher, NewBlock, SLOT_TIME};
+
+mod history;
+pub use history::{AccountHistory, HistoryEvent};
+
+mod implementation;
+pub use implementation::{Implementation, EIP1822_PROXIABLE_SLOT, EIP1967_IMPLEMENTATION_SLOT};
+
+mod interface;
+pub use interface::{human_readable_abi, solidity_interface, INTERFACE_PRAGMA};
+
+mod jsonl;
+pub use jsonl::{write_jsonl, write_pages_jsonl};
+
+mod key_provider;
+pub use key_provider::{EnvKey, KeyProvider, StaticKey};
+
+mod keys;
+pub use keys::{ApiKeyPool, KeySelection, KeyUsage};
+
+mod labels;
+pub use labels::{AddressTag, LabelBook};
+
+mod log_range;
+pub use log_range::{LogPaginator, GET_LOGS_LIMIT};
+
+mod metrics;
+pub use metrics::{Metrics, RequestMetrics, RequestOutcome};
+
+mod middleware;
+pub use middleware::*;
+
+mod mock;
+pub use mock::{request_params, MockRoute, MockTransport};
+
+mod multicall;
+pub use multicall::{
+    Call, CallResult, Multicall, DEFAULT_MULTICALL_BATCH_SIZE, MULTICALL3_ADDRESS,
+};
+
+mod multichain;
+pub use multichain::MultiChainClient;
+
+mod nft;
+pub use nft::{NftInventory, NftInventorySource, NftToken};
+
+mod nonce;
+pub use nonce::{NonceReplacement, NonceReport};
+
+pub mod pagination;
+pub use pagination::{Cursor, Page, Paginated, DEFAULT_PREFETCH_DEPTH, MAX_RESULT_WINDOW};
+
+mod portfolio;
+pub use portfolio::{NftHolding, Portfolio, TokenHolding};
+
+pub mod prelude;
+
+mod price;
+pub use price::{to_units, EthPrice, PortfolioValuation, TokenInfo, TokenValuation};
+
+#[cfg(feature = "prometheus")]
+mod prometheus;
+#[cfg(feature = "prometheus")]
+pub use crate::prometheus::PrometheusMetrics;
+
+mod proxy;
+pub use proxy::{Block, Log, ProxyError, ProxyResponse, TransactionReceipt};
+
+pub mod quota;
+pub use quota::{QuotaStatus, QuotaTracker};
+
+mod ratelimit;
+pub use ratelimit::{RateLimiter, RequestPriority};
+
+mod record;
+pub use record::StoredRecord;
+
+mod reorg;
+pub use reorg::{Reorg, ReorgDetector};
+
+mod report;
+pub use report::{Asset, AssetFlow, ReportPeriod, TransferReport};
+
+mod response;
+pub use response::EtherscanResponse;
+
+pub mod retry;
+
+mod scheduler;
+pub use scheduler::{BackfillScheduler, DEFAULT_BACKFILL_SHARE};
+
+mod seen;
+pub use seen::{SeenFilter, DEFAULT_SEEN_CAPACITY};
+
+mod selector;
+pub use selector::{
+    SelectorLookup, SignatureDatabase, DEFAULT_FOUR_BYTE_URL, DEFAULT_OPENCHAIN_URL,
+};
+
+mod serde_u256;
+
+mod similar;
+pub use similar::SimilarContract;
+
+mod sink;
+pub use sink::{publish, publish_with, NotificationSink, PublishReport, WebhookSink};
+
+mod source;
+pub use source::{Compiler, CompilerSettings, ContractSource};
+
+mod sourcify;
+pub use sourcify::DEFAULT_SOURCIFY_URL;
+
+mod spawn;
+pub use spawn::Spawner;
+
+mod stats;
+pub use stats::{DailySeries, DailyStat, StatsBackfill};
+
+mod template;
+pub use template::RequestTemplate;
+
+mod tokens;
+pub use tokens::{TokenMetadata, TokenRegistry};
+
+mod topics;
+pub use topics::{
+    approval_for_all_topics, approval_topics, event_topic, parse_topic, transfer_batch_topics,
+    transfer_single_topics, transfer_topics, APPROVAL_FOR_ALL_TOPIC, APPROVAL_TOPIC,
+    TRANSFER_BATCH_TOPIC, TRANSFER_SINGLE_TOPIC, TRANSFER_TOPIC,
+};
+
+mod transport;
+pub use transport::*;
+
+mod v2;
+pub use v2::{is_v1_deprecated, is_v2_url, legacy_chainid};
+
+mod vcr;
+pub use vcr::{fixture_key, RecordingTransport, ReplayTransport};
+
+mod verify;
+pub use verify::{
+    CodeFormat, StandardJson, VerificationOutcome, VerificationRequest, VerificationStatus,
+};
+
+mod watch;
+pub use watch::{AddressWatcher, TokenTransferWatcher, WatchEvent};
+
+mod whale;
+pub use whale::{WhaleAlert, WhaleDetector, WhaleThreshold};
+
 use {
+    chrono::NaiveDate,
     ethabi::ethereum_types::U256,
-    futures::Future,
     serde::{Deserialize, Serialize},
-    std::error::Error,
+    std::{collections::BTreeMap, error::Error, fmt, str::FromStr},
 };
 
-#[derive(Clone, Copy, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EtherscanModule {
+    #[default]
     Unknown,
     Account,
     Contract,
     Transaction,
     Block,
     Stats,
+    Proxy,
+    GasTracker,
+    Token,
+    Logs,
+    Nametag,
 }
 
-impl Default for EtherscanModule {
-    fn default() -> Self {
-        Self::Unknown
-    }
-}
-
-#[derive(Clone, Copy, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum EtherscanSort {
     #[serde(rename = "asc")]
     Ascending,
@@ -31,9 +296,10 @@ pub enum EtherscanSort {
     Descending,
 }
 
-#[derive(Clone, Copy, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EtherscanAction {
+    #[default]
     Unknown,
 
     Balance,
@@ -42,10 +308,20 @@ pub enum EtherscanAction {
     TxListInternal,
     TokenTx,
     TokenNftTx,
+    Token1155Tx,
     TokenBalance,
+    AddressTokenBalance,
+    AddressTokenNftBalance,
+    AddressTokenNftInventory,
+    FundedBy,
+    TxsBeaconWithdrawal,
+    GetMinedBlocks,
 
     GetABI,
     GetSourceCode,
+    GetContractCreation,
+    VerifySourceCode,
+    CheckVerifyStatus,
 
     GetStatus,
     GetTxReceiptStatus,
@@ -59,65 +335,675 @@ pub enum EtherscanAction {
     EthSupply2,
     EthPrice,
     NodeCount,
-}
+    DailyTx,
+    DailyAvgGasPrice,
+    DailyGasUsed,
+    DailyTxnFee,
+    EthDailyPrice,
 
-impl Default for EtherscanAction {
-    fn default() -> Self {
-        Self::Unknown
-    }
+    GasOracle,
+    TokenInfo,
+    GetAddressTag,
+
+    #[serde(rename = "getLogs")]
+    GetLogs,
+
+    #[serde(rename = "eth_blockNumber")]
+    EthBlockNumber,
+    #[serde(rename = "eth_getTransactionReceipt")]
+    EthGetTransactionReceipt,
+    #[serde(rename = "eth_getStorageAt")]
+    EthGetStorageAt,
+    #[serde(rename = "eth_getTransactionCount")]
+    EthGetTransactionCount,
+    #[serde(rename = "eth_call")]
+    EthCall,
+    #[serde(rename = "eth_getCode")]
+    EthGetCode,
+    #[serde(rename = "eth_sendRawTransaction")]
+    EthSendRawTransaction,
+    #[serde(rename = "eth_getBlockByNumber")]
+    EthGetBlockByNumber,
 }
 
-#[derive(Clone, Copy, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EtherscanTag {
     Latest,
+    Pending,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EtherscanClosest {
+    Before,
+    After,
+}
+
+impl EtherscanModule {
+    pub fn actions(&self) -> impl Iterator<Item = EtherscanAction> + '_ {
+        EtherscanAction::ALL
+            .iter()
+            .copied()
+            .filter(move |action| action.module() == *self)
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Account => "account",
+            Self::Contract => "contract",
+            Self::Transaction => "transaction",
+            Self::Block => "block",
+            Self::Stats => "stats",
+            Self::Proxy => "proxy",
+            Self::GasTracker => "gastracker",
+            Self::Token => "token",
+            Self::Logs => "logs",
+            Self::Nametag => "nametag",
+        }
+    }
+}
+
+impl fmt::Display for EtherscanModule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EtherscanModule {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unknown" => Ok(Self::Unknown),
+            "account" => Ok(Self::Account),
+            "contract" => Ok(Self::Contract),
+            "transaction" => Ok(Self::Transaction),
+            "block" => Ok(Self::Block),
+            "stats" => Ok(Self::Stats),
+            "proxy" => Ok(Self::Proxy),
+            "gastracker" => Ok(Self::GasTracker),
+            "token" => Ok(Self::Token),
+            "logs" => Ok(Self::Logs),
+            "nametag" => Ok(Self::Nametag),
+            _ => Err(format!("unknown module: {s}").into()),
+        }
+    }
+}
+
+impl EtherscanSort {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ascending => "asc",
+            Self::Descending => "desc",
+        }
+    }
+}
+
+impl fmt::Display for EtherscanSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EtherscanSort {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(Self::Ascending),
+            "desc" => Ok(Self::Descending),
+            _ => Err(format!("unknown sort: {s}").into()),
+        }
+    }
+}
+
+impl EtherscanAction {
+    pub const ALL: &'static [Self] = &[
+        Self::Balance,
+        Self::BalanceMulti,
+        Self::TxList,
+        Self::TxListInternal,
+        Self::TokenTx,
+        Self::TokenNftTx,
+        Self::Token1155Tx,
+        Self::TokenBalance,
+        Self::AddressTokenBalance,
+        Self::AddressTokenNftBalance,
+        Self::AddressTokenNftInventory,
+        Self::FundedBy,
+        Self::TxsBeaconWithdrawal,
+        Self::GetMinedBlocks,
+        Self::GetABI,
+        Self::GetSourceCode,
+        Self::GetContractCreation,
+        Self::VerifySourceCode,
+        Self::CheckVerifyStatus,
+        Self::GetStatus,
+        Self::GetTxReceiptStatus,
+        Self::GetBlockReward,
+        Self::GetBlockCountdown,
+        Self::GetBlockNoByTime,
+        Self::TokenSupply,
+        Self::EthSupply,
+        Self::EthSupply2,
+        Self::EthPrice,
+        Self::NodeCount,
+        Self::DailyTx,
+        Self::DailyAvgGasPrice,
+        Self::DailyGasUsed,
+        Self::DailyTxnFee,
+        Self::EthDailyPrice,
+        Self::GasOracle,
+        Self::TokenInfo,
+        Self::GetAddressTag,
+        Self::GetLogs,
+        Self::EthBlockNumber,
+        Self::EthGetTransactionReceipt,
+        Self::EthGetStorageAt,
+        Self::EthGetTransactionCount,
+        Self::EthCall,
+        Self::EthGetCode,
+        Self::EthSendRawTransaction,
+        Self::EthGetBlockByNumber,
+    ];
+
+    #[inline]
+    pub fn all() -> &'static [Self] {
+        Self::ALL
+    }
+
+    pub const fn module(&self) -> EtherscanModule {
+        match self {
+            Self::Unknown => EtherscanModule::Unknown,
+            Self::Balance
+            | Self::BalanceMulti
+            | Self::TxList
+            | Self::TxListInternal
+            | Self::TokenTx
+            | Self::TokenNftTx
+            | Self::Token1155Tx
+            | Self::TokenBalance
+            | Self::AddressTokenBalance
+            | Self::AddressTokenNftBalance
+            | Self::AddressTokenNftInventory
+            | Self::FundedBy
+            | Self::TxsBeaconWithdrawal
+            | Self::GetMinedBlocks => EtherscanModule::Account,
+            Self::GetABI
+            | Self::GetSourceCode
+            | Self::GetContractCreation
+            | Self::VerifySourceCode
+            | Self::CheckVerifyStatus => EtherscanModule::Contract,
+            Self::GetStatus | Self::GetTxReceiptStatus => EtherscanModule::Transaction,
+            Self::GetBlockReward | Self::GetBlockCountdown | Self::GetBlockNoByTime => {
+                EtherscanModule::Block
+            }
+            Self::TokenSupply
+            | Self::EthSupply
+            | Self::EthSupply2
+            | Self::EthPrice
+            | Self::NodeCount
+            | Self::DailyTx
+            | Self::DailyAvgGasPrice
+            | Self::DailyGasUsed
+            | Self::DailyTxnFee
+            | Self::EthDailyPrice => EtherscanModule::Stats,
+            Self::GasOracle => EtherscanModule::GasTracker,
+            Self::TokenInfo => EtherscanModule::Token,
+            Self::GetAddressTag => EtherscanModule::Nametag,
+            Self::GetLogs => EtherscanModule::Logs,
+            Self::EthBlockNumber
+            | Self::EthGetTransactionReceipt
+            | Self::EthGetStorageAt
+            | Self::EthGetTransactionCount
+            | Self::EthCall
+            | Self::EthGetCode
+            | Self::EthSendRawTransaction
+            | Self::EthGetBlockByNumber => EtherscanModule::Proxy,
+        }
+    }
+
+    pub const fn method(&self) -> HttpMethod {
+        match self {
+            Self::VerifySourceCode | Self::EthSendRawTransaction => HttpMethod::Post,
+            _ => HttpMethod::Get,
+        }
+    }
+
+    pub const fn accepts_sort(&self) -> bool {
+        matches!(
+            self,
+            Self::TxList
+                | Self::TxListInternal
+                | Self::TokenTx
+                | Self::TokenNftTx
+                | Self::Token1155Tx
+                | Self::TxsBeaconWithdrawal
+                | Self::DailyTx
+                | Self::DailyAvgGasPrice
+                | Self::DailyGasUsed
+                | Self::DailyTxnFee
+                | Self::EthDailyPrice
+        )
+    }
+
+    pub const fn accepts_offset(&self) -> bool {
+        matches!(
+            self,
+            Self::TxList
+                | Self::TxListInternal
+                | Self::TokenTx
+                | Self::TokenNftTx
+                | Self::Token1155Tx
+                | Self::TxsBeaconWithdrawal
+                | Self::AddressTokenBalance
+                | Self::AddressTokenNftBalance
+                | Self::AddressTokenNftInventory
+                | Self::GetMinedBlocks
+                | Self::GetLogs
+        )
+    }
+
+    pub const fn accepts_tag(&self) -> bool {
+        matches!(
+            self,
+            Self::Balance
+                | Self::BalanceMulti
+                | Self::TokenBalance
+                | Self::EthGetStorageAt
+                | Self::EthGetTransactionCount
+                | Self::EthGetCode
+                | Self::EthCall
+        )
+    }
+
+    pub const fn required_params(&self) -> &'static [&'static str] {
+        match self {
+            Self::Balance
+            | Self::BalanceMulti
+            | Self::TxList
+            | Self::TokenTx
+            | Self::TokenNftTx
+            | Self::Token1155Tx
+            | Self::AddressTokenBalance
+            | Self::AddressTokenNftBalance
+            | Self::FundedBy
+            | Self::TxsBeaconWithdrawal
+            | Self::GetABI
+            | Self::GetSourceCode
+            | Self::GetAddressTag
+            | Self::EthGetTransactionCount
+            | Self::EthGetCode => &["address"],
+            Self::TokenBalance => &["contractaddress", "address"],
+            Self::GetMinedBlocks => &["address", "blocktype"],
+            Self::AddressTokenNftInventory => &["address", "contractaddress"],
+            Self::GetContractCreation => &["contractaddresses"],
+            Self::VerifySourceCode => &[
+                "contractaddress",
+                "sourceCode",
+                "codeformat",
+                "contractname",
+                "compilerversion",
+            ],
+            Self::CheckVerifyStatus => &["guid"],
+            Self::EthSendRawTransaction => &["hex"],
+            Self::EthGetBlockByNumber => &["tag", "boolean"],
+            Self::GetStatus | Self::GetTxReceiptStatus | Self::EthGetTransactionReceipt => {
+                &["txhash"]
+            }
+            Self::GetBlockReward | Self::GetBlockCountdown => &["blockno"],
+            Self::GetBlockNoByTime => &["timestamp", "closest"],
+            Self::TokenSupply | Self::TokenInfo => &["contractaddress"],
+            Self::DailyTx
+            | Self::DailyAvgGasPrice
+            | Self::DailyGasUsed
+            | Self::DailyTxnFee
+            | Self::EthDailyPrice => &["startdate", "enddate"],
+            Self::EthGetStorageAt => &["address", "position"],
+            Self::EthCall => &["to", "data"],
+            _ => &[],
+        }
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Balance => "balance",
+            Self::BalanceMulti => "balancemulti",
+            Self::TxList => "txlist",
+            Self::TxListInternal => "txlistinternal",
+            Self::TokenTx => "tokentx",
+            Self::TokenNftTx => "tokennfttx",
+            Self::Token1155Tx => "token1155tx",
+            Self::TokenBalance => "tokenbalance",
+            Self::AddressTokenBalance => "addresstokenbalance",
+            Self::AddressTokenNftBalance => "addresstokennftbalance",
+            Self::AddressTokenNftInventory => "addresstokennftinventory",
+            Self::FundedBy => "fundedby",
+            Self::TxsBeaconWithdrawal => "txsBeaconWithdrawal",
+            Self::GetMinedBlocks => "getminedblocks",
+            Self::GetABI => "getabi",
+            Self::GetSourceCode => "getsourcecode",
+            Self::GetContractCreation => "getcontractcreation",
+            Self::VerifySourceCode => "verifysourcecode",
+            Self::CheckVerifyStatus => "checkverifystatus",
+            Self::GetStatus => "getstatus",
+            Self::GetTxReceiptStatus => "gettxreceiptstatus",
+            Self::GetBlockReward => "getblockreward",
+            Self::GetBlockCountdown => "getblockcountdown",
+            Self::GetBlockNoByTime => "getblocknobytime",
+            Self::TokenSupply => "tokensupply",
+            Self::EthSupply => "ethsupply",
+            Self::EthSupply2 => "ethsupply2",
+            Self::EthPrice => "ethprice",
+            Self::NodeCount => "nodecount",
+            Self::DailyTx => "dailytx",
+            Self::DailyAvgGasPrice => "dailyavggasprice",
+            Self::DailyGasUsed => "dailygasused",
+            Self::DailyTxnFee => "dailytxnfee",
+            Self::EthDailyPrice => "ethdailyprice",
+            Self::GasOracle => "gasoracle",
+            Self::TokenInfo => "tokeninfo",
+            Self::GetAddressTag => "getaddresstag",
+            Self::GetLogs => "getLogs",
+            Self::EthBlockNumber => "eth_blockNumber",
+            Self::EthGetTransactionReceipt => "eth_getTransactionReceipt",
+            Self::EthGetStorageAt => "eth_getStorageAt",
+            Self::EthGetTransactionCount => "eth_getTransactionCount",
+            Self::EthCall => "eth_call",
+            Self::EthGetCode => "eth_getCode",
+            Self::EthSendRawTransaction => "eth_sendRawTransaction",
+            Self::EthGetBlockByNumber => "eth_getBlockByNumber",
+        }
+    }
 }
 
-#[derive(Clone, Copy, Deserialize, Serialize)]
+impl fmt::Display for EtherscanAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EtherscanAction {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unknown" => Ok(Self::Unknown),
+            "balance" => Ok(Self::Balance),
+            "balancemulti" => Ok(Self::BalanceMulti),
+            "txlist" => Ok(Self::TxList),
+            "txlistinternal" => Ok(Self::TxListInternal),
+            "tokentx" => Ok(Self::TokenTx),
+            "tokennfttx" => Ok(Self::TokenNftTx),
+            "token1155tx" => Ok(Self::Token1155Tx),
+            "tokenbalance" => Ok(Self::TokenBalance),
+            "addresstokenbalance" => Ok(Self::AddressTokenBalance),
+            "addresstokennftbalance" => Ok(Self::AddressTokenNftBalance),
+            "addresstokennftinventory" => Ok(Self::AddressTokenNftInventory),
+            "fundedby" => Ok(Self::FundedBy),
+            "txsBeaconWithdrawal" => Ok(Self::TxsBeaconWithdrawal),
+            "getminedblocks" => Ok(Self::GetMinedBlocks),
+            "getabi" => Ok(Self::GetABI),
+            "getsourcecode" => Ok(Self::GetSourceCode),
+            "getcontractcreation" => Ok(Self::GetContractCreation),
+            "verifysourcecode" => Ok(Self::VerifySourceCode),
+            "checkverifystatus" => Ok(Self::CheckVerifyStatus),
+            "getstatus" => Ok(Self::GetStatus),
+            "gettxreceiptstatus" => Ok(Self::GetTxReceiptStatus),
+            "getblockreward" => Ok(Self::GetBlockReward),
+            "getblockcountdown" => Ok(Self::GetBlockCountdown),
+            "getblocknobytime" => Ok(Self::GetBlockNoByTime),
+            "tokensupply" => Ok(Self::TokenSupply),
+            "ethsupply" => Ok(Self::EthSupply),
+            "ethsupply2" => Ok(Self::EthSupply2),
+            "ethprice" => Ok(Self::EthPrice),
+            "nodecount" => Ok(Self::NodeCount),
+            "dailytx" => Ok(Self::DailyTx),
+            "dailyavggasprice" => Ok(Self::DailyAvgGasPrice),
+            "dailygasused" => Ok(Self::DailyGasUsed),
+            "dailytxnfee" => Ok(Self::DailyTxnFee),
+            "ethdailyprice" => Ok(Self::EthDailyPrice),
+            "gasoracle" => Ok(Self::GasOracle),
+            "tokeninfo" => Ok(Self::TokenInfo),
+            "getaddresstag" => Ok(Self::GetAddressTag),
+            "getLogs" => Ok(Self::GetLogs),
+            "eth_blockNumber" => Ok(Self::EthBlockNumber),
+            "eth_getTransactionReceipt" => Ok(Self::EthGetTransactionReceipt),
+            "eth_getStorageAt" => Ok(Self::EthGetStorageAt),
+            "eth_getTransactionCount" => Ok(Self::EthGetTransactionCount),
+            "eth_call" => Ok(Self::EthCall),
+            "eth_getCode" => Ok(Self::EthGetCode),
+            "eth_sendRawTransaction" => Ok(Self::EthSendRawTransaction),
+            "eth_getBlockByNumber" => Ok(Self::EthGetBlockByNumber),
+            _ => Err(format!("unknown action: {s}").into()),
+        }
+    }
+}
+
+impl EtherscanTag {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Latest => "latest",
+            Self::Pending => "pending",
+        }
+    }
+}
+
+impl fmt::Display for EtherscanTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EtherscanTag {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latest" => Ok(Self::Latest),
+            "pending" => Ok(Self::Pending),
+            _ => Err(format!("unknown tag: {s}").into()),
+        }
+    }
+}
+
+impl EtherscanClosest {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Before => "before",
+            Self::After => "after",
+        }
+    }
+}
+
+impl fmt::Display for EtherscanClosest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EtherscanClosest {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "before" => Ok(Self::Before),
+            "after" => Ok(Self::After),
+            _ => Err(format!("unknown closest: {s}").into()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EtherscanBlockType {
+    Blocks,
+    Uncles,
+}
+
+impl EtherscanBlockType {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::Uncles => "uncles",
+        }
+    }
+}
+
+impl fmt::Display for EtherscanBlockType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EtherscanBlockType {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "blocks" => Ok(Self::Blocks),
+            "uncles" => Ok(Self::Uncles),
+            _ => Err(format!("unknown block type: {s}").into()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EtherscanFormat {
+    #[default]
+    Json,
     Raw,
 }
 
-#[derive(Clone, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
+impl EtherscanFormat {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Raw => "raw",
+        }
+    }
+}
+
+impl fmt::Display for EtherscanFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EtherscanFormat {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "raw" => Ok(Self::Raw),
+            _ => Err(format!("unknown format: {s}").into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct EtherscanRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    chainid: Option<u64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "module_action"
+    )]
     module_action: Option<(EtherscanModule, EtherscanAction)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     contractaddress: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    contractaddresses: Option<Vec<U256>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     address: Option<Vec<U256>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     tag: Option<EtherscanTag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     startblock: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     endblock: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     page: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     offset: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     sort: Option<EtherscanSort>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     txhash: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     blockno: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     timestamp: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    closest: Option<EtherscanClosest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    blocktype: Option<EtherscanBlockType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    startdate: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    enddate: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fromblock: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    toblock: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    topics: Option<[Option<U256>; 4]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    position: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    blocktag: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    boolean: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    to: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    guid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     format: Option<EtherscanFormat>,
-    apikey: Option<String>,
+    #[serde(default, skip_serializing)]
+    apikey: Option<ApiKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    form: Option<Vec<(String, String)>>,
+    #[serde(default, skip_serializing)]
+    correlation_id: Option<String>,
 }
 
-impl Default for EtherscanRequest {
-    fn default() -> Self {
-        Self {
-            url: Default::default(),
-            module_action: Default::default(),
-            contractaddress: Default::default(),
-            address: Default::default(),
-            tag: Default::default(),
-            startblock: Default::default(),
-            endblock: Default::default(),
-            page: Default::default(),
-            offset: Default::default(),
-            sort: Default::default(),
-            txhash: Default::default(),
-            blockno: Default::default(),
-            timestamp: Default::default(),
-            format: Default::default(),
-            apikey: Default::default(),
-        }
+mod module_action {
+    use {
+        super::{EtherscanAction, EtherscanModule},
+        serde::{Deserialize, Deserializer, Serialize, Serializer},
+    };
+
+    #[derive(Deserialize, Serialize)]
+    struct ModuleAction {
+        module: EtherscanModule,
+        action: EtherscanAction,
+    }
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<(EtherscanModule, EtherscanAction)>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value
+            .map(|(module, action)| ModuleAction { module, action })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<(EtherscanModule, EtherscanAction)>, D::Error> {
+        Ok(Option::<ModuleAction>::deserialize(deserializer)?
+            .map(|ModuleAction { module, action }| (module, action)))
     }
 }
 
@@ -129,92 +1015,417 @@ impl EtherscanRequest {
     }
 
     #[inline]
-    pub fn with_apikey(mut self, apikey: String) -> Self {
-        self.apikey = Some(apikey);
+    pub fn with_chainid(mut self, chainid: u64) -> Self {
+        self.chainid = Some(chainid);
         self
     }
 
+    /// Sets the key for this request only; it takes precedence over the
+    /// client's key, key pool and key provider.
+    #[inline]
+    pub fn with_apikey<K: Into<ApiKey>>(mut self, apikey: K) -> Self {
+        self.apikey = Some(apikey.into());
+        self
+    }
+
+    #[inline]
+    pub fn apikey(&self) -> Option<&ApiKey> {
+        self.apikey.as_ref()
+    }
+
     #[inline]
     pub fn with_format(mut self, format: EtherscanFormat) -> Self {
         self.format = Some(format);
         self
     }
 
+    pub fn check_window(&self) -> Result<(), EtherscanError> {
+        let (Some(page), Some(offset)) = (self.page, self.offset) else {
+            return Ok(());
+        };
+
+        let page = page.min(U256::from(u64::MAX)).as_u64();
+        let offset = offset.min(U256::from(u64::MAX)).as_u64();
+
+        match page.saturating_mul(offset) > MAX_RESULT_WINDOW {
+            true => Err(EtherscanError::WindowLimit {
+                page,
+                offset,
+                max_page: MAX_RESULT_WINDOW / offset.max(1),
+            }),
+            false => Ok(()),
+        }
+    }
+
+    #[inline]
+    pub fn format(&self) -> EtherscanFormat {
+        self.format.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn with_form(mut self, form: Vec<(String, String)>) -> Self {
+        self.form = Some(form);
+        self
+    }
+
+    #[inline]
+    pub fn form(&self) -> Option<&[(String, String)]> {
+        self.form.as_deref()
+    }
+
+    #[inline]
+    pub fn with_correlation_id<S: Into<String>>(mut self, correlation_id: S) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+
+    #[inline]
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    pub fn context(&self) -> RequestContext {
+        RequestContext {
+            module: self.module_action.map(|(module, _)| module),
+            action: self.module_action.map(|(_, action)| action),
+            chainid: self.chainid,
+            url: self.to_url().ok().map(|url| redact_url(&url)),
+            correlation_id: self.correlation_id.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn method(&self) -> HttpMethod {
+        match (&self.form, self.module_action) {
+            (Some(_), _) => HttpMethod::Post,
+            (None, Some((_, action))) => action.method(),
+            (None, None) => HttpMethod::Get,
+        }
+    }
+
+    #[inline]
+    pub fn set_url(&mut self, url: String) -> &mut Self {
+        self.url = Some(url);
+        self
+    }
+
+    #[inline]
+    pub fn set_chainid(&mut self, chainid: u64) -> &mut Self {
+        self.chainid = Some(chainid);
+        self
+    }
+
+    #[inline]
+    pub fn set_apikey(&mut self, apikey: String) -> &mut Self {
+        self.apikey = Some(apikey.into());
+        self
+    }
+
+    #[inline]
+    pub fn set_format(&mut self, format: EtherscanFormat) -> &mut Self {
+        self.format = Some(format);
+        self
+    }
+
+    #[inline]
+    pub fn set_tag(&mut self, tag: Option<EtherscanTag>) -> &mut Self {
+        self.tag = tag;
+        self
+    }
+
+    #[inline]
+    pub fn set_startblock(&mut self, startblock: Option<U256>) -> &mut Self {
+        self.startblock = startblock;
+        self
+    }
+
+    #[inline]
+    pub fn set_endblock(&mut self, endblock: Option<U256>) -> &mut Self {
+        self.endblock = endblock;
+        self
+    }
+
+    #[inline]
+    pub fn set_page(&mut self, page: Option<U256>) -> &mut Self {
+        self.page = page;
+        self
+    }
+
+    #[inline]
+    pub fn set_offset(&mut self, offset: Option<U256>) -> &mut Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn set_sort(&mut self, sort: Option<EtherscanSort>) -> &mut Self {
+        self.sort = sort;
+        self
+    }
+
     #[inline]
     pub fn account_balance(address: U256, tag: Option<EtherscanTag>) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::Balance)),
-            address: Some(vec![address]),
+            module_action: Some((EtherscanModule::Account, EtherscanAction::Balance)),
+            address: Some(vec![address]),
+            tag,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_balance_multi(addresses: Vec<U256>, tag: Option<EtherscanTag>) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::BalanceMulti)),
+            address: Some(addresses),
+            tag,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_tx_list(
+        address: U256,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+        page: Option<U256>,
+        offset: Option<U256>,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::TxList)),
+            address: Some(vec![address]),
+            startblock,
+            endblock,
+            page,
+            offset,
+            sort,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_tx_list_internal(
+        address: U256,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+        page: Option<U256>,
+        offset: Option<U256>,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::TxListInternal)),
+            address: Some(vec![address]),
+            startblock,
+            endblock,
+            page,
+            offset,
+            sort,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_tx_list_internal_range(
+        startblock: U256,
+        endblock: U256,
+        page: Option<U256>,
+        offset: Option<U256>,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::TxListInternal)),
+            startblock: Some(startblock),
+            endblock: Some(endblock),
+            page,
+            offset,
+            sort,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_tx_list_internal_hash(txhash: U256) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::TxListInternal)),
+            txhash: Some(txhash),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_token_tx(
+        contract_address: Option<U256>,
+        account_address: Option<U256>,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+        page: Option<U256>,
+        offset: Option<U256>,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::TokenTx)),
+            contractaddress: contract_address,
+            address: account_address.map(|x| vec![x]),
+            page,
+            offset,
+            startblock,
+            endblock,
+            sort,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_token_nft_tx(
+        contract_address: U256,
+        address: U256,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+        page: Option<U256>,
+        offset: Option<U256>,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self::account_token_nft_tx_filtered(
+            Some(contract_address),
+            Some(address),
+            startblock,
+            endblock,
+            page,
+            offset,
+            sort,
+        )
+    }
+
+    #[inline]
+    pub fn account_token_nft_tx_filtered(
+        contract_address: Option<U256>,
+        address: Option<U256>,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+        page: Option<U256>,
+        offset: Option<U256>,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::TokenNftTx)),
+            contractaddress: contract_address,
+            address: address.map(|x| vec![x]),
+            startblock,
+            endblock,
+            page,
+            offset,
+            sort,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_token_1155_tx(
+        contract_address: Option<U256>,
+        address: Option<U256>,
+        startblock: Option<U256>,
+        endblock: Option<U256>,
+        page: Option<U256>,
+        offset: Option<U256>,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::Token1155Tx)),
+            contractaddress: contract_address,
+            address: address.map(|x| vec![x]),
+            startblock,
+            endblock,
+            page,
+            offset,
+            sort,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn account_token_balance(
+        account_address: U256,
+        contract_address: U256,
+        tag: Option<EtherscanTag>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Account, EtherscanAction::TokenBalance)),
+            contractaddress: Some(contract_address),
+            address: Some(vec![account_address]),
             tag,
             ..Default::default()
         }
     }
 
     #[inline]
-    pub fn account_balance_multi(addresses: Vec<U256>, tag: Option<EtherscanTag>) -> Self {
+    pub fn account_address_token_balance(
+        address: U256,
+        page: Option<U256>,
+        offset: Option<U256>,
+    ) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::BalanceMulti)),
-            address: Some(addresses),
-            tag,
+            module_action: Some((
+                EtherscanModule::Account,
+                EtherscanAction::AddressTokenBalance,
+            )),
+            address: Some(vec![address]),
+            page,
+            offset,
             ..Default::default()
         }
     }
 
     #[inline]
-    pub fn account_tx_list(
+    pub fn account_address_token_nft_balance(
         address: U256,
-        startblock: Option<U256>,
-        endblock: Option<U256>,
         page: Option<U256>,
         offset: Option<U256>,
-        sort: Option<EtherscanSort>,
     ) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::TxList)),
+            module_action: Some((
+                EtherscanModule::Account,
+                EtherscanAction::AddressTokenNftBalance,
+            )),
             address: Some(vec![address]),
-            startblock,
-            endblock,
             page,
             offset,
-            sort,
             ..Default::default()
         }
     }
 
     #[inline]
-    pub fn account_tx_list_internal(
+    pub fn account_address_token_nft_inventory(
         address: U256,
-        startblock: Option<U256>,
-        endblock: Option<U256>,
+        contract_address: U256,
         page: Option<U256>,
         offset: Option<U256>,
-        sort: Option<EtherscanSort>,
     ) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::TxListInternal)),
+            module_action: Some((
+                EtherscanModule::Account,
+                EtherscanAction::AddressTokenNftInventory,
+            )),
             address: Some(vec![address]),
-            startblock,
-            endblock,
+            contractaddress: Some(contract_address),
             page,
             offset,
-            sort,
             ..Default::default()
         }
     }
 
     #[inline]
-    pub fn account_tx_list_internal_hash(txhash: U256) -> Self {
+    pub fn account_funded_by(address: U256) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::TxListInternal)),
-            txhash: Some(txhash),
+            module_action: Some((EtherscanModule::Account, EtherscanAction::FundedBy)),
+            address: Some(vec![address]),
             ..Default::default()
         }
     }
 
     #[inline]
-    pub fn account_token_tx(
-        contract_address: Option<U256>,
-        account_address: Option<U256>,
+    pub fn account_txs_beacon_withdrawal(
+        address: U256,
         startblock: Option<U256>,
         endblock: Option<U256>,
         page: Option<U256>,
@@ -222,52 +1433,42 @@ impl EtherscanRequest {
         sort: Option<EtherscanSort>,
     ) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::TokenTx)),
-            contractaddress: contract_address,
-            address: account_address.map(|x| vec![x]),
-            page,
-            offset,
+            module_action: Some((
+                EtherscanModule::Account,
+                EtherscanAction::TxsBeaconWithdrawal,
+            )),
+            address: Some(vec![address]),
             startblock,
             endblock,
+            page,
+            offset,
             sort,
             ..Default::default()
         }
     }
 
     #[inline]
-    pub fn account_token_nft_tx(
-        contract_address: U256,
+    pub fn account_get_mined_blocks(
         address: U256,
-        startblock: Option<U256>,
-        endblock: Option<U256>,
+        blocktype: EtherscanBlockType,
         page: Option<U256>,
         offset: Option<U256>,
-        sort: Option<EtherscanSort>,
     ) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::TokenNftTx)),
-            contractaddress: Some(contract_address),
+            module_action: Some((EtherscanModule::Account, EtherscanAction::GetMinedBlocks)),
             address: Some(vec![address]),
-            startblock,
-            endblock,
+            blocktype: Some(blocktype),
             page,
             offset,
-            sort,
             ..Default::default()
         }
     }
 
     #[inline]
-    pub fn account_token_balance(
-        account_address: U256,
-        contract_address: U256,
-        tag: Option<EtherscanTag>,
-    ) -> Self {
+    pub fn nametag_get_address_tag(address: U256) -> Self {
         Self {
-            module_action: Some((EtherscanModule::Account, EtherscanAction::TokenBalance)),
-            contractaddress: Some(contract_address),
-            address: Some(vec![account_address]),
-            tag,
+            module_action: Some((EtherscanModule::Nametag, EtherscanAction::GetAddressTag)),
+            address: Some(vec![address]),
             ..Default::default()
         }
     }
@@ -290,6 +1491,38 @@ impl EtherscanRequest {
         }
     }
 
+    #[inline]
+    pub fn contract_get_contract_creation(contract_addresses: Vec<U256>) -> Self {
+        Self {
+            module_action: Some((
+                EtherscanModule::Contract,
+                EtherscanAction::GetContractCreation,
+            )),
+            contractaddresses: Some(contract_addresses),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn contract_verify_source_code() -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Contract, EtherscanAction::VerifySourceCode)),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn contract_check_verify_status(guid: String) -> Self {
+        Self {
+            module_action: Some((
+                EtherscanModule::Contract,
+                EtherscanAction::CheckVerifyStatus,
+            )),
+            guid: Some(guid),
+            ..Default::default()
+        }
+    }
+
     #[inline]
     pub fn transaction_get_status(transaction_hash: U256) -> Self {
         Self {
@@ -321,10 +1554,11 @@ impl EtherscanRequest {
     }
 
     #[inline]
-    pub fn block_get_number_by_timestamp(timestamp: U256) -> Self {
+    pub fn block_get_number_by_timestamp(timestamp: U256, closest: EtherscanClosest) -> Self {
         Self {
             module_action: Some((EtherscanModule::Block, EtherscanAction::GetBlockNoByTime)),
             timestamp: Some(timestamp),
+            closest: Some(closest),
             ..Default::default()
         }
     }
@@ -370,17 +1604,204 @@ impl EtherscanRequest {
         }
     }
 
+    #[inline]
+    pub fn stats_daily(
+        series: DailySeries,
+        startdate: NaiveDate,
+        enddate: NaiveDate,
+        sort: Option<EtherscanSort>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Stats, series.action())),
+            startdate: Some(startdate),
+            enddate: Some(enddate),
+            sort,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn token_info(contract_address: U256) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Token, EtherscanAction::TokenInfo)),
+            contractaddress: Some(contract_address),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn logs_get_logs(
+        address: Option<U256>,
+        fromblock: Option<U256>,
+        toblock: Option<U256>,
+        topics: [Option<U256>; 4],
+        page: Option<U256>,
+        offset: Option<U256>,
+    ) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Logs, EtherscanAction::GetLogs)),
+            address: address.map(|x| vec![x]),
+            fromblock,
+            toblock,
+            topics: Some(topics),
+            page,
+            offset,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn gas_tracker_gas_oracle() -> Self {
+        Self {
+            module_action: Some((EtherscanModule::GasTracker, EtherscanAction::GasOracle)),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_block_number() -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Proxy, EtherscanAction::EthBlockNumber)),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_get_transaction_receipt(txhash: U256) -> Self {
+        Self {
+            module_action: Some((
+                EtherscanModule::Proxy,
+                EtherscanAction::EthGetTransactionReceipt,
+            )),
+            txhash: Some(txhash),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_get_storage_at(address: U256, position: U256, tag: Option<EtherscanTag>) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Proxy, EtherscanAction::EthGetStorageAt)),
+            address: Some(vec![address]),
+            position: Some(position),
+            tag,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_get_transaction_count(address: U256, tag: Option<EtherscanTag>) -> Self {
+        Self {
+            module_action: Some((
+                EtherscanModule::Proxy,
+                EtherscanAction::EthGetTransactionCount,
+            )),
+            address: Some(vec![address]),
+            tag,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_get_code(address: U256, tag: Option<EtherscanTag>) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Proxy, EtherscanAction::EthGetCode)),
+            address: Some(vec![address]),
+            tag,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_get_block_by_number(block_number: U256, full_transactions: bool) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Proxy, EtherscanAction::EthGetBlockByNumber)),
+            blocktag: Some(block_number),
+            boolean: Some(full_transactions),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_send_raw_transaction(hex: String) -> Self {
+        Self {
+            module_action: Some((
+                EtherscanModule::Proxy,
+                EtherscanAction::EthSendRawTransaction,
+            )),
+            form: Some(vec![("hex".into(), hex)]),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn proxy_call(to: U256, data: String, tag: Option<EtherscanTag>) -> Self {
+        Self {
+            module_action: Some((EtherscanModule::Proxy, EtherscanAction::EthCall)),
+            to: Some(to),
+            data: Some(data),
+            tag,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
     #[inline]
     pub fn build(
         self,
     ) -> Result<
-        impl Future<Output = reqwest::Result<reqwest::Response>>,
+        impl futures::Future<Output = reqwest::Result<reqwest::Response>>,
         Box<dyn Error + Send + Sync>,
     > {
+        let request = self.to_http_request()?;
+        let client = reqwest::Client::new();
+
+        let mut builder = match request.method {
+            HttpMethod::Get => client.get(&request.url),
+            HttpMethod::Post => client.post(&request.url),
+        };
+
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        Ok(builder.send())
+    }
+
+    pub fn to_http_request(&self) -> Result<HttpRequest, Box<dyn Error + Send + Sync>> {
+        let url = self.to_url()?;
+
+        match self.method() {
+            HttpMethod::Get => Ok(HttpRequest {
+                correlation_id: self.correlation_id.clone(),
+                ..HttpRequest::get(url)
+            }),
+            HttpMethod::Post => Ok(HttpRequest {
+                method: HttpMethod::Post,
+                url,
+                headers: vec![(
+                    "Content-Type".into(),
+                    "application/x-www-form-urlencoded".into(),
+                )],
+                body: Some(
+                    client::form_urlencode(self.form.as_deref().unwrap_or_default()).into_bytes(),
+                ),
+                correlation_id: self.correlation_id.clone(),
+            }),
+        }
+    }
+
+    pub fn to_url(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
         let Self {
             url,
+            chainid,
             module_action,
             contractaddress,
+            contractaddresses,
             address,
             tag,
             page,
@@ -391,22 +1812,38 @@ impl EtherscanRequest {
             txhash,
             blockno,
             timestamp,
+            closest,
+            blocktype,
+            startdate,
+            enddate,
+            fromblock,
+            toblock,
+            topics,
+            position,
+            blocktag,
+            boolean,
+            to,
+            data,
+            guid,
             format,
             apikey,
+            form: _,
+            correlation_id: _,
         } = self;
 
-        Ok(reqwest::get(format!(
-            "{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}",
-            match url {
-                Some(url) => url,
+        Ok(format!(
+            "{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}",
+            url.as_deref().unwrap_or_default(),
+            match module_action {
+                Some((module, _)) => format!("?module={}", module.as_str()),
                 None => String::new(),
             },
             match module_action {
-                Some((module, _)) => format!("?module={}", serde_plain::to_string(&module)?),
+                Some((_, action)) => format!("&action={}", action.as_str()),
                 None => String::new(),
             },
-            match module_action {
-                Some((_, action)) => format!("&action={}", serde_plain::to_string(&action)?),
+            match chainid {
+                Some(chainid) => format!("&chainid={chainid}"),
                 None => String::new(),
             },
             match contractaddress {
@@ -418,7 +1855,18 @@ impl EtherscanRequest {
                     }
 
                     format!("&contractaddress=0x{address}")
-                },
+                }
+                None => String::new(),
+            },
+            match contractaddresses {
+                Some(addresses) => format!(
+                    "&contractaddresses={}",
+                    addresses
+                        .iter()
+                        .map(|x| format!("{x:#042x}"))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ),
                 None => String::new(),
             },
             match address {
@@ -441,7 +1889,7 @@ impl EtherscanRequest {
                 None => String::new(),
             },
             match tag {
-                Some(tag) => format!("&tag={}", serde_plain::to_string(&tag)?),
+                Some(tag) => format!("&tag={}", tag.as_str()),
                 None => String::new(),
             },
             match page {
@@ -461,11 +1909,11 @@ impl EtherscanRequest {
                 None => String::new(),
             },
             match sort {
-                Some(sort) => format!("&sort={}", serde_plain::to_string(&sort)?),
+                Some(sort) => format!("&sort={}", sort.as_str()),
                 None => String::new(),
             },
             match txhash {
-                Some(txhash) => format!("&txhash=0x{txhash:X}"),
+                Some(txhash) => format!("&txhash={txhash:#066x}"),
                 None => String::new(),
             },
             match blockno {
@@ -476,14 +1924,107 @@ impl EtherscanRequest {
                 Some(timestamp) => format!("&timestamp={timestamp}"),
                 None => String::new(),
             },
+            match closest {
+                Some(closest) => format!("&closest={}", closest.as_str()),
+                None => String::new(),
+            },
+            match blocktype {
+                Some(blocktype) => format!("&blocktype={}", blocktype.as_str()),
+                None => String::new(),
+            },
+            match startdate {
+                Some(startdate) => format!("&startdate={}", startdate.format("%Y-%m-%d")),
+                None => String::new(),
+            },
+            match enddate {
+                Some(enddate) => format!("&enddate={}", enddate.format("%Y-%m-%d")),
+                None => String::new(),
+            },
+            match fromblock {
+                Some(fromblock) => format!("&fromBlock={fromblock}"),
+                None => String::new(),
+            },
+            match toblock {
+                Some(toblock) => format!("&toBlock={toblock}"),
+                None => String::new(),
+            },
+            match topics {
+                Some(topics) => {
+                    let mut query = String::new();
+
+                    for (i, topic) in topics.iter().enumerate() {
+                        if let Some(topic) = topic {
+                            query.push_str(&format!("&topic{i}={topic:#066x}"));
+                        }
+                    }
+
+                    for i in 0..topics.len() {
+                        for j in i + 1..topics.len() {
+                            if topics[i].is_some() && topics[j].is_some() {
+                                query.push_str(&format!("&topic{i}_{j}_opr=and"));
+                            }
+                        }
+                    }
+
+                    query
+                }
+                None => String::new(),
+            },
+            match position {
+                Some(position) => format!("&position={position:#x}"),
+                None => String::new(),
+            },
+            match blocktag {
+                Some(blocktag) => format!("&tag={blocktag:#x}"),
+                None => String::new(),
+            },
+            match boolean {
+                Some(boolean) => format!("&boolean={boolean}"),
+                None => String::new(),
+            },
+            match to {
+                Some(to) => format!("&to={to:#042x}"),
+                None => String::new(),
+            },
+            match data {
+                Some(data) => format!("&data={data}"),
+                None => String::new(),
+            },
+            match guid {
+                Some(guid) => format!("&guid={guid}"),
+                None => String::new(),
+            },
             match format {
-                Some(format) => format!("&format={}", serde_plain::to_string(&format)?),
+                Some(format) => format!("&format={}", format.as_str()),
                 None => String::new(),
             },
             match apikey {
-                Some(apikey) => format!("&apikey={apikey}"),
+                Some(apikey) => format!("&apikey={}", apikey.expose()),
                 None => String::new(),
             }
-        )))
+        ))
+    }
+
+    pub fn params(&self) -> Result<BTreeMap<String, String>, Box<dyn Error + Send + Sync>> {
+        let url = self.to_url()?;
+
+        Ok(request_params(&HttpRequest::get(url))
+            .into_iter()
+            .filter(|(key, _)| key != "apikey")
+            .collect())
+    }
+
+    pub fn snapshot(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let params = self
+            .params()?
+            .into_iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        Ok(format!(
+            "{}?{params}",
+            self.url.as_deref().unwrap_or_default()
+        ))
     }
 }
@@ -0,0 +1,158 @@
+use {
+    crate::{EtherscanClient, EtherscanRequest, Log, SeenFilter},
+    ethabi::ethereum_types::U256,
+    futures::{stream, Stream},
+    std::{collections::VecDeque, error::Error},
+};
+
+pub const GET_LOGS_LIMIT: usize = 1_000;
+
+#[derive(Clone, Debug)]
+pub struct LogPaginator {
+    client: EtherscanClient,
+    address: Option<U256>,
+    topics: [Option<U256>; 4],
+    fromblock: U256,
+    toblock: U256,
+    limit: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LogRange {
+    fromblock: U256,
+    toblock: U256,
+    page: Option<u64>,
+}
+
+struct LogPaginatorState {
+    paginator: LogPaginator,
+    ranges: VecDeque<LogRange>,
+    pending: VecDeque<Log>,
+    seen: SeenFilter<(Option<U256>, Option<U256>)>,
+}
+
+impl LogPaginator {
+    #[inline]
+    pub fn new(client: EtherscanClient, fromblock: U256, toblock: U256) -> Self {
+        Self {
+            client,
+            address: None,
+            topics: [None; 4],
+            fromblock,
+            toblock,
+            limit: GET_LOGS_LIMIT,
+        }
+    }
+
+    #[inline]
+    pub fn with_address(mut self, address: U256) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    #[inline]
+    pub fn with_topics(mut self, topics: [Option<U256>; 4]) -> Self {
+        self.topics = topics;
+        self
+    }
+
+    #[inline]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.max(1);
+        self
+    }
+
+    pub fn stream(
+        self,
+    ) -> impl Stream<Item = Result<Log, Box<dyn Error + Send + Sync>>> + Send + 'static {
+        let ranges = match self.fromblock <= self.toblock {
+            true => VecDeque::from([LogRange {
+                fromblock: self.fromblock,
+                toblock: self.toblock,
+                page: None,
+            }]),
+            false => VecDeque::new(),
+        };
+
+        let state = LogPaginatorState {
+            seen: SeenFilter::new(self.limit * 4),
+            paginator: self,
+            ranges,
+            pending: VecDeque::new(),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(log) = state.pending.pop_front() {
+                    return Some((Ok(log), state));
+                }
+
+                let range = state.ranges.pop_front()?;
+
+                if let Err(e) = state.fetch(range).await {
+                    state.ranges.clear();
+                    return Some((Err(e), state));
+                }
+            }
+        })
+    }
+}
+
+impl LogPaginatorState {
+    async fn fetch(&mut self, range: LogRange) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let paginator = &self.paginator;
+
+        let logs: Vec<Log> = paginator
+            .client
+            .fetch(EtherscanRequest::logs_get_logs(
+                paginator.address,
+                Some(range.fromblock),
+                Some(range.toblock),
+                paginator.topics,
+                range.page.map(U256::from),
+                range.page.map(|_| U256::from(paginator.limit)),
+            ))
+            .await?;
+
+        if logs.len() >= paginator.limit {
+            if range.fromblock < range.toblock {
+                let middle = range.fromblock + (range.toblock - range.fromblock) / 2;
+
+                self.ranges.push_front(LogRange {
+                    fromblock: middle + 1,
+                    ..range
+                });
+                self.ranges.push_front(LogRange {
+                    toblock: middle,
+                    ..range
+                });
+
+                return Ok(());
+            }
+
+            self.ranges.push_front(LogRange {
+                page: Some(range.page.map_or(1, |page| page + 1)),
+                ..range
+            });
+
+            if range.page.is_none() {
+                return Ok(());
+            }
+        }
+
+        for log in logs {
+            if self.seen.insert((log.transaction_hash, log.log_index)) {
+                self.pending.push_back(log);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn log_paginator(&self, fromblock: U256, toblock: U256) -> LogPaginator {
+        LogPaginator::new(self.clone(), fromblock, toblock)
+    }
+}
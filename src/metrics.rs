@@ -0,0 +1,72 @@
+use {
+    crate::{
+        retry, EtherscanAction, EtherscanError, EtherscanModule, EtherscanResponse, HttpResponse,
+    },
+    std::{error::Error, time::Duration},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RequestOutcome {
+    Success,
+    CacheHit,
+    ApiError,
+    HttpError(u16),
+    RateLimited,
+    TransportError,
+    Cancelled,
+    CircuitOpen,
+    QuotaExhausted,
+}
+
+impl RequestOutcome {
+    pub fn of(result: &Result<HttpResponse, Box<dyn Error + Send + Sync>>) -> Self {
+        match result {
+            Ok(response) if retry::is_rate_limited(response) => Self::RateLimited,
+            Ok(response) if !response.is_success() => Self::HttpError(response.status),
+            Ok(response) => {
+                match serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body)
+                {
+                    Ok(envelope) if envelope.status != "1" && !envelope.result.is_array() => {
+                        Self::ApiError
+                    }
+                    _ => Self::Success,
+                }
+            }
+            Err(e) => match EtherscanError::find(e.as_ref()) {
+                Some(EtherscanError::Cancelled) => Self::Cancelled,
+                Some(EtherscanError::CircuitOpen { .. }) => Self::CircuitOpen,
+                Some(EtherscanError::QuotaExhausted { .. }) => Self::QuotaExhausted,
+                _ => Self::TransportError,
+            },
+        }
+    }
+
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success | Self::CacheHit)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RequestMetrics {
+    pub module: Option<EtherscanModule>,
+    pub action: Option<EtherscanAction>,
+    pub duration: Duration,
+    pub outcome: RequestOutcome,
+    pub retries: u32,
+    pub correlation_id: Option<String>,
+}
+
+pub trait Metrics: Send + Sync {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> Metrics for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync,
+{
+    #[inline]
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
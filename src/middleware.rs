@@ -0,0 +1,41 @@
+use {
+    crate::HttpRequest,
+    std::{error::Error, time::Duration},
+};
+
+pub trait RequestMiddleware: Send + Sync {
+    fn on_request(&self, request: &mut HttpRequest) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+impl<F> RequestMiddleware for F
+where
+    F: Fn(&mut HttpRequest) -> Result<(), Box<dyn Error + Send + Sync>> + Send + Sync,
+{
+    #[inline]
+    fn on_request(&self, request: &mut HttpRequest) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self(request)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ResponseInfo {
+    pub url: String,
+    pub status: u16,
+    pub latency: Duration,
+    pub body_size: usize,
+    pub correlation_id: Option<String>,
+}
+
+pub trait ResponseMiddleware: Send + Sync {
+    fn on_response(&self, info: &ResponseInfo);
+}
+
+impl<F> ResponseMiddleware for F
+where
+    F: Fn(&ResponseInfo) + Send + Sync,
+{
+    #[inline]
+    fn on_response(&self, info: &ResponseInfo) {
+        self(info)
+    }
+}
@@ -0,0 +1,237 @@
+use {
+    crate::{HttpMethod, HttpRequest, HttpResponse, Transport},
+    futures::future::BoxFuture,
+    std::{
+        error::Error,
+        sync::{Arc, Mutex},
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct MockRoute {
+    module: Option<String>,
+    action: Option<String>,
+    params: Vec<(String, String)>,
+    response: HttpResponse,
+}
+
+impl MockRoute {
+    #[inline]
+    pub fn new(module: &str, action: &str) -> Self {
+        Self {
+            module: Some(module.to_string()),
+            action: Some(action.to_string()),
+            params: Vec::new(),
+            response: HttpResponse {
+                status: 200,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[inline]
+    pub fn any() -> Self {
+        Self {
+            module: None,
+            action: None,
+            ..Self::new("", "")
+        }
+    }
+
+    #[inline]
+    pub fn with_param<V: ToString>(mut self, name: &str, value: V) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    #[inline]
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.response.status = status;
+        self
+    }
+
+    #[inline]
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.response
+            .headers
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+
+    #[inline]
+    pub fn with_body(mut self, body: serde_json::Value) -> Self {
+        self.response.body = body.to_string().into_bytes();
+        self
+    }
+
+    #[inline]
+    pub fn with_raw_body<B: Into<Vec<u8>>>(mut self, body: B) -> Self {
+        self.response.body = body.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_result(self, result: serde_json::Value) -> Self {
+        self.with_body(serde_json::json!({
+            "status": "1",
+            "message": "OK",
+            "result": result,
+        }))
+    }
+
+    #[inline]
+    pub fn with_error(self, message: &str, result: &str) -> Self {
+        self.with_body(serde_json::json!({
+            "status": "0",
+            "message": message,
+            "result": result,
+        }))
+    }
+
+    fn matches(&self, params: &[(String, String)]) -> bool {
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        self.module
+            .as_deref()
+            .is_none_or(|module| param("module") == Some(module))
+            && self
+                .action
+                .as_deref()
+                .is_none_or(|action| param("action") == Some(action))
+            && self.params.iter().all(|(name, value)| {
+                param(name).is_some_and(|actual| actual.eq_ignore_ascii_case(value))
+            })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    routes: Arc<Mutex<Vec<MockRoute>>>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl MockTransport {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_route(self, route: MockRoute) -> Self {
+        self.add(route);
+        self
+    }
+
+    #[inline]
+    pub fn with_result(self, module: &str, action: &str, result: serde_json::Value) -> Self {
+        self.with_route(MockRoute::new(module, action).with_result(result))
+    }
+
+    pub fn add(&self, route: MockRoute) {
+        self.routes.lock().unwrap().push(route);
+    }
+
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn request_params(&self) -> Vec<Vec<(String, String)>> {
+        self.requests().iter().map(request_params).collect()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>> {
+        let params = request_params(&request);
+
+        self.requests.lock().unwrap().push(request);
+
+        let response = self
+            .routes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|route| route.matches(&params))
+            .map(|route| route.response.clone());
+
+        Box::pin(async move {
+            response.ok_or_else(|| {
+                let param = |name: &str| {
+                    params
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or_default()
+                };
+
+                format!(
+                    "no mock response for module={} action={}",
+                    param("module"),
+                    param("action")
+                )
+                .into()
+            })
+        })
+    }
+}
+
+pub fn request_params(request: &HttpRequest) -> Vec<(String, String)> {
+    let mut params = match request.url.split_once('?') {
+        Some((_, query)) => parse_query(query),
+        None => Vec::new(),
+    };
+
+    if let (HttpMethod::Post, Some(body)) = (request.method, &request.body) {
+        params.extend(parse_query(&String::from_utf8_lossy(body)));
+    }
+
+    params
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                match std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            b => decoded.push(b),
+        }
+
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
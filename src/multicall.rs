@@ -0,0 +1,170 @@
+use {
+    crate::EtherscanClient,
+    ethabi::{
+        ethereum_types::{H160, U256},
+        ParamType, Token,
+    },
+    serde::{Deserialize, Serialize},
+    std::error::Error,
+};
+
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+pub const DEFAULT_MULTICALL_BATCH_SIZE: usize = 50;
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Call {
+    pub target: U256,
+    pub data: Vec<u8>,
+    pub allow_failure: bool,
+}
+
+impl Call {
+    #[inline]
+    pub fn new(target: U256, data: Vec<u8>) -> Self {
+        Self {
+            target,
+            data,
+            allow_failure: true,
+        }
+    }
+
+    #[inline]
+    pub fn with_allow_failure(mut self, allow_failure: bool) -> Self {
+        self.allow_failure = allow_failure;
+        self
+    }
+
+    fn to_token(&self) -> Token {
+        let mut bytes = [0u8; 32];
+        self.target.to_big_endian(&mut bytes);
+
+        Token::Tuple(vec![
+            Token::Address(H160::from_slice(&bytes[12..])),
+            Token::Bool(self.allow_failure),
+            Token::Bytes(self.data.clone()),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CallResult {
+    pub success: bool,
+    pub data: Vec<u8>,
+}
+
+impl CallResult {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Tuple(mut fields) if fields.len() == 2 => {
+                let data = fields.pop()?.into_bytes()?;
+                let success = fields.pop()?.into_bool()?;
+                Some(Self { success, data })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Multicall {
+    client: EtherscanClient,
+    address: U256,
+    batch_size: usize,
+    calls: Vec<Call>,
+}
+
+impl Multicall {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            address: U256::from_str_radix(MULTICALL3_ADDRESS.trim_start_matches("0x"), 16)
+                .unwrap_or_default(),
+            batch_size: DEFAULT_MULTICALL_BATCH_SIZE,
+            calls: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_address(mut self, address: U256) -> Self {
+        self.address = address;
+        self
+    }
+
+    #[inline]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn with_call(mut self, call: Call) -> Self {
+        self.calls.push(call);
+        self
+    }
+
+    #[inline]
+    pub fn with_calls<I: IntoIterator<Item = Call>>(mut self, calls: I) -> Self {
+        self.calls.extend(calls);
+        self
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    pub async fn call(&self) -> Result<Vec<CallResult>, Box<dyn Error + Send + Sync>> {
+        let call = ParamType::Tuple(vec![ParamType::Address, ParamType::Bool, ParamType::Bytes]);
+        let output = ParamType::Array(Box::new(ParamType::Tuple(vec![
+            ParamType::Bool,
+            ParamType::Bytes,
+        ])));
+
+        let selector = ethabi::short_signature("aggregate3", &[ParamType::Array(Box::new(call))]);
+        let mut results = Vec::with_capacity(self.calls.len());
+
+        for chunk in self.calls.chunks(self.batch_size) {
+            let mut data = selector.to_vec();
+            data.extend(ethabi::encode(&[Token::Array(
+                chunk.iter().map(Call::to_token).collect(),
+            )]));
+
+            let response = self.client.call(self.address, &data).await?;
+
+            let decoded = ethabi::decode(std::slice::from_ref(&output), &response)?
+                .pop()
+                .and_then(Token::into_array)
+                .ok_or("unexpected aggregate3 return data")?;
+
+            if decoded.len() != chunk.len() {
+                return Err(format!(
+                    "aggregate3 returned {} results for {} calls",
+                    decoded.len(),
+                    chunk.len()
+                )
+                .into());
+            }
+
+            for token in decoded {
+                results.push(
+                    CallResult::from_token(token).ok_or("unexpected aggregate3 result entry")?,
+                );
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn multicall(&self) -> Multicall {
+        Multicall::new(self.clone())
+    }
+}
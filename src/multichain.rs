@@ -0,0 +1,136 @@
+use {
+    crate::{retry::RetryBudget, EtherscanClient, EtherscanRequest, QuotaTracker, RateLimiter},
+    futures::{stream, Future, StreamExt},
+    serde::de::DeserializeOwned,
+    std::{collections::BTreeMap, error::Error},
+};
+
+#[derive(Clone, Debug)]
+pub struct MultiChainClient {
+    clients: BTreeMap<u64, EtherscanClient>,
+    concurrency: usize,
+}
+
+impl Default for MultiChainClient {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            clients: BTreeMap::new(),
+            concurrency: 8,
+        }
+    }
+}
+
+impl MultiChainClient {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_client<I: IntoIterator<Item = u64>>(client: &EtherscanClient, chainids: I) -> Self {
+        chainids.into_iter().fold(Self::new(), |multi, chainid| {
+            multi.with_client(chainid, client.clone().with_chainid(chainid))
+        })
+    }
+
+    #[inline]
+    pub fn with_client(mut self, chainid: u64, client: EtherscanClient) -> Self {
+        self.clients.insert(chainid, client);
+        self
+    }
+
+    fn map_client<F>(mut self, chainid: u64, f: F) -> Self
+    where
+        F: FnOnce(EtherscanClient) -> EtherscanClient,
+    {
+        if let Some(client) = self.clients.remove(&chainid) {
+            self.clients.insert(chainid, f(client));
+        }
+
+        self
+    }
+
+    #[inline]
+    pub fn with_chain_apikey(self, chainid: u64, apikey: String) -> Self {
+        self.map_client(chainid, |client| client.with_apikey(apikey))
+    }
+
+    #[inline]
+    pub fn with_chain_rate_limit(self, chainid: u64, requests_per_second: u32) -> Self {
+        self.map_client(chainid, |client| {
+            client.with_rate_limit(requests_per_second)
+        })
+    }
+
+    #[inline]
+    pub fn with_chain_rate_limiter(self, chainid: u64, rate_limiter: RateLimiter) -> Self {
+        self.map_client(chainid, |client| client.with_rate_limiter(rate_limiter))
+    }
+
+    #[inline]
+    pub fn with_chain_quota(self, chainid: u64, quota: QuotaTracker) -> Self {
+        self.map_client(chainid, |client| client.with_quota(quota))
+    }
+
+    #[inline]
+    pub fn with_chain_retry_budget(self, chainid: u64, retry_budget: RetryBudget) -> Self {
+        self.map_client(chainid, |client| client.with_retry_budget(retry_budget))
+    }
+
+    #[inline]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    #[inline]
+    pub fn chains(&self) -> impl Iterator<Item = u64> + '_ {
+        self.clients.keys().copied()
+    }
+
+    #[inline]
+    pub fn client(&self, chainid: u64) -> Option<&EtherscanClient> {
+        self.clients.get(&chainid)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    pub async fn run<F, Fut, T>(&self, f: F) -> BTreeMap<u64, T>
+    where
+        F: Fn(u64, EtherscanClient) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        stream::iter(&self.clients)
+            .map(|(chainid, client)| {
+                let result = f(*chainid, client.clone());
+                async move { (*chainid, result.await) }
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    pub async fn fetch<T: DeserializeOwned>(
+        &self,
+        request: EtherscanRequest,
+    ) -> BTreeMap<u64, Result<T, Box<dyn Error + Send + Sync>>> {
+        self.run(|_, client| {
+            let request = request.clone();
+            async move { client.fetch(request).await }
+        })
+        .await
+    }
+}
@@ -0,0 +1,194 @@
+use {
+    crate::{
+        portfolio::HOLDINGS_PAGE_SIZE, EtherscanClient, EtherscanRequest, Page, TokenTransfer,
+    },
+    ethabi::ethereum_types::U256,
+    futures::TryStreamExt,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+const TRANSFERS_PAGE_SIZE: u64 = 1_000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum NftInventorySource {
+    #[default]
+    Pro,
+    Transfers,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NftToken {
+    #[serde(rename = "TokenAddress", with = "crate::serde_u256::hex")]
+    pub contract_address: U256,
+    #[serde(rename = "TokenId", with = "crate::serde_u256::decimal")]
+    pub token_id: U256,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NftInventory {
+    pub address: U256,
+    pub collections: BTreeMap<U256, BTreeMap<U256, U256>>,
+}
+
+impl NftInventory {
+    #[inline]
+    pub fn new(address: U256) -> Self {
+        Self {
+            address,
+            collections: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_transfers(
+        address: U256,
+        erc721: &[TokenTransfer],
+        erc1155: &[TokenTransfer],
+    ) -> Self {
+        let mut inventory = Self::new(address);
+
+        for transfer in erc721.iter().chain(erc1155) {
+            let Some(token_id) = transfer
+                .token_id
+                .as_deref()
+                .and_then(|id| U256::from_dec_str(id).ok())
+            else {
+                continue;
+            };
+
+            let quantity = match &transfer.token_value {
+                Some(value) => U256::from_dec_str(value).unwrap_or_default(),
+                None => U256::one(),
+            };
+
+            if transfer.from == address {
+                inventory.remove(transfer.contract_address, token_id, quantity);
+            }
+
+            if transfer.to == Some(address) {
+                inventory.add(transfer.contract_address, token_id, quantity);
+            }
+        }
+
+        inventory
+    }
+
+    fn add(&mut self, contract_address: U256, token_id: U256, quantity: U256) {
+        let balance = self
+            .collections
+            .entry(contract_address)
+            .or_default()
+            .entry(token_id)
+            .or_default();
+
+        *balance = balance.saturating_add(quantity);
+    }
+
+    fn remove(&mut self, contract_address: U256, token_id: U256, quantity: U256) {
+        let Some(collection) = self.collections.get_mut(&contract_address) else {
+            return;
+        };
+
+        if let Some(balance) = collection.get_mut(&token_id) {
+            *balance = balance.saturating_sub(quantity);
+
+            if balance.is_zero() {
+                collection.remove(&token_id);
+            }
+        }
+
+        if collection.is_empty() {
+            self.collections.remove(&contract_address);
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, contract_address: U256, token_id: U256) -> bool {
+        self.collections
+            .get(&contract_address)
+            .is_some_and(|collection| collection.contains_key(&token_id))
+    }
+
+    #[inline]
+    pub fn token_count(&self) -> usize {
+        self.collections.values().map(BTreeMap::len).sum()
+    }
+}
+
+impl EtherscanClient {
+    pub async fn address_nft_inventory(
+        &self,
+        address: U256,
+        contract_address: U256,
+    ) -> Result<Vec<NftToken>, Box<dyn Error + Send + Sync>> {
+        self.all_pages(|page| {
+            EtherscanRequest::account_address_token_nft_inventory(
+                address,
+                contract_address,
+                Some(page),
+                Some(U256::from(HOLDINGS_PAGE_SIZE)),
+            )
+        })
+        .await
+    }
+
+    pub async fn nft_inventory(
+        &self,
+        address: U256,
+        source: NftInventorySource,
+    ) -> Result<NftInventory, Box<dyn Error + Send + Sync>> {
+        match source {
+            NftInventorySource::Pro => {
+                let mut inventory = NftInventory::new(address);
+
+                for holding in self.address_nft_balances(address).await? {
+                    for token in self
+                        .address_nft_inventory(address, holding.contract_address)
+                        .await?
+                    {
+                        inventory.add(token.contract_address, token.token_id, U256::one());
+                    }
+                }
+
+                Ok(inventory)
+            }
+            NftInventorySource::Transfers => {
+                let erc721 = self
+                    .nft_transfers(EtherscanRequest::account_token_nft_tx_filtered(
+                        None,
+                        Some(address),
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .await?;
+
+                let erc1155 = self
+                    .nft_transfers(EtherscanRequest::account_token_1155_tx(
+                        None,
+                        Some(address),
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .await?;
+
+                Ok(NftInventory::from_transfers(address, &erc721, &erc1155))
+            }
+        }
+    }
+
+    async fn nft_transfers(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<Vec<TokenTransfer>, Box<dyn Error + Send + Sync>> {
+        self.paginate::<TokenTransfer>(request, TRANSFERS_PAGE_SIZE)
+            .map_ok(|page: Page<TokenTransfer>| page.items)
+            .try_concat()
+            .await
+    }
+}
@@ -0,0 +1,120 @@
+use {
+    crate::{EtherscanClient, EtherscanRequest, EtherscanTag, Page, Transaction},
+    ethabi::ethereum_types::U256,
+    futures::TryStreamExt,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+const PAGE_SIZE: u64 = 1_000;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct NonceReplacement {
+    pub nonce: U256,
+    pub hashes: Vec<U256>,
+    pub succeeded: Option<U256>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct NonceReport {
+    pub address: U256,
+    pub latest_nonce: U256,
+    pub pending_nonce: U256,
+    pub gaps: Vec<U256>,
+    pub replacements: Vec<NonceReplacement>,
+    pub stuck: Vec<U256>,
+}
+
+impl NonceReport {
+    pub fn analyze(
+        address: U256,
+        transactions: &[Transaction],
+        latest_nonce: U256,
+        pending_nonce: U256,
+    ) -> Self {
+        let mut by_nonce = BTreeMap::<U256, Vec<&Transaction>>::new();
+
+        for transaction in transactions.iter().filter(|tx| tx.from == address) {
+            by_nonce
+                .entry(transaction.nonce)
+                .or_default()
+                .push(transaction);
+        }
+
+        let mut gaps = Vec::new();
+        let mut nonce = U256::zero();
+
+        while nonce < latest_nonce {
+            if !by_nonce.contains_key(&nonce) {
+                gaps.push(nonce);
+            }
+
+            nonce += U256::one();
+        }
+
+        let replacements = by_nonce
+            .iter()
+            .filter(|(_, transactions)| transactions.len() > 1)
+            .map(|(nonce, transactions)| NonceReplacement {
+                nonce: *nonce,
+                hashes: transactions.iter().map(|tx| tx.hash).collect(),
+                succeeded: transactions
+                    .iter()
+                    .find(|tx| tx.is_error != "1")
+                    .map(|tx| tx.hash),
+            })
+            .collect();
+
+        let mut stuck = Vec::new();
+        let mut nonce = latest_nonce;
+
+        while nonce < pending_nonce {
+            stuck.push(nonce);
+            nonce += U256::one();
+        }
+
+        Self {
+            address,
+            latest_nonce,
+            pending_nonce,
+            gaps,
+            replacements,
+            stuck,
+        }
+    }
+
+    #[inline]
+    pub fn has_issues(&self) -> bool {
+        !self.gaps.is_empty() || !self.replacements.is_empty() || !self.stuck.is_empty()
+    }
+}
+
+impl EtherscanClient {
+    pub async fn nonce_report(
+        &self,
+        address: U256,
+    ) -> Result<NonceReport, Box<dyn Error + Send + Sync>> {
+        let latest_nonce = self
+            .transaction_count(address, EtherscanTag::Latest)
+            .await?;
+        let pending_nonce = self
+            .transaction_count(address, EtherscanTag::Pending)
+            .await?;
+
+        let transactions = self
+            .paginate::<Transaction>(
+                EtherscanRequest::account_tx_list(address, None, None, None, None, None),
+                PAGE_SIZE,
+            )
+            .map_ok(|page: Page<Transaction>| page.items)
+            .try_concat()
+            .await?;
+
+        Ok(NonceReport::analyze(
+            address,
+            &transactions,
+            latest_nonce,
+            pending_nonce,
+        ))
+    }
+}
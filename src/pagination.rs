@@ -0,0 +1,399 @@
+use {
+    crate::{
+        BeaconWithdrawal, CancellationToken, EtherscanClient, EtherscanRequest, EtherscanSort,
+        InternalTransaction, StoredRecord, TokenTransfer, Transaction,
+    },
+    ethabi::ethereum_types::U256,
+    futures::{
+        channel::oneshot,
+        future::BoxFuture,
+        stream::{self, BoxStream, FuturesOrdered},
+        FutureExt, Stream, StreamExt,
+    },
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    std::{collections::VecDeque, error::Error},
+};
+
+pub const MAX_RESULT_WINDOW: u64 = 10_000;
+
+pub const DEFAULT_PREFETCH_DEPTH: usize = 2;
+
+pub trait Paginated {
+    fn block_number(&self) -> U256;
+    fn hash(&self) -> U256;
+    fn key(&self) -> String;
+}
+
+impl Paginated for Transaction {
+    #[inline]
+    fn block_number(&self) -> U256 {
+        self.block_number
+    }
+
+    #[inline]
+    fn hash(&self) -> U256 {
+        self.hash
+    }
+
+    #[inline]
+    fn key(&self) -> String {
+        StoredRecord::key(self)
+    }
+}
+
+impl Paginated for InternalTransaction {
+    #[inline]
+    fn block_number(&self) -> U256 {
+        self.block_number
+    }
+
+    #[inline]
+    fn hash(&self) -> U256 {
+        self.hash
+    }
+
+    #[inline]
+    fn key(&self) -> String {
+        StoredRecord::key(self)
+    }
+}
+
+impl Paginated for TokenTransfer {
+    #[inline]
+    fn block_number(&self) -> U256 {
+        self.block_number
+    }
+
+    #[inline]
+    fn hash(&self) -> U256 {
+        self.hash
+    }
+
+    #[inline]
+    fn key(&self) -> String {
+        StoredRecord::key(self)
+    }
+}
+
+impl Paginated for BeaconWithdrawal {
+    #[inline]
+    fn block_number(&self) -> U256 {
+        self.block_number
+    }
+
+    #[inline]
+    fn hash(&self) -> U256 {
+        self.withdrawal_index
+    }
+
+    #[inline]
+    fn key(&self) -> String {
+        self.withdrawal_index.to_string()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Cursor {
+    pub block: U256,
+    pub page: u64,
+    pub last_hash: Option<U256>,
+    #[serde(default)]
+    pub last_key: Option<String>,
+}
+
+impl Cursor {
+    #[inline]
+    pub fn new(block: U256) -> Self {
+        Self {
+            block,
+            page: 1,
+            last_hash: None,
+            last_key: None,
+        }
+    }
+
+    fn advance<T: Paginated>(
+        &self,
+        items: &[T],
+        offset: u64,
+        exhausted: bool,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let Some(last) = items.last() else {
+            return Ok(self.clone());
+        };
+
+        if !exhausted && (self.page + 1) * offset <= MAX_RESULT_WINDOW {
+            return Ok(Self {
+                block: self.block,
+                page: self.page + 1,
+                last_hash: Some(last.hash()),
+                last_key: Some(last.key()),
+            });
+        }
+
+        if !exhausted && last.block_number() == self.block {
+            return Err(format!(
+                "block {} has more than {MAX_RESULT_WINDOW} results",
+                self.block
+            )
+            .into());
+        }
+
+        Ok(Self {
+            block: last.block_number(),
+            page: 1,
+            last_hash: Some(last.hash()),
+            last_key: Some(last.key()),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub cursor: Cursor,
+}
+
+/// The last item already yielded before a block rollover. Rows of one
+/// transaction share a hash, so the composite key is preferred when known.
+enum Resume {
+    Key(String),
+    Hash(U256),
+}
+
+impl Resume {
+    fn of(cursor: &Cursor) -> Option<Self> {
+        match (&cursor.last_key, cursor.last_hash) {
+            (Some(key), _) => Some(Self::Key(key.clone())),
+            (None, Some(hash)) => Some(Self::Hash(hash)),
+            (None, None) => None,
+        }
+    }
+
+    fn matches<T: Paginated>(&self, item: &T) -> bool {
+        match self {
+            Self::Key(key) => item.key() == *key,
+            Self::Hash(hash) => item.hash() == *hash,
+        }
+    }
+}
+
+type PageResult<T> = (Cursor, Result<Vec<T>, Box<dyn Error + Send + Sync>>);
+
+type PageFetch<T> = BoxFuture<'static, PageResult<T>>;
+
+struct Pages<T> {
+    client: EtherscanClient,
+    request: EtherscanRequest,
+    offset: u64,
+    depth: usize,
+    shutdown: Option<CancellationToken>,
+    next: Option<Cursor>,
+    skip: Option<(U256, Resume)>,
+    in_flight: FuturesOrdered<PageFetch<T>>,
+    ready: VecDeque<PageResult<T>>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> Pages<T> {
+    fn schedule(&mut self) {
+        while self.in_flight.len() + self.ready.len() < self.depth {
+            let Some(cursor) = self.next.take() else {
+                return;
+            };
+
+            if (cursor.page + 1) * self.offset <= MAX_RESULT_WINDOW {
+                self.next = Some(Cursor {
+                    block: cursor.block,
+                    page: cursor.page + 1,
+                    last_hash: None,
+                    last_key: None,
+                });
+            }
+
+            let client = self.client.clone();
+            let mut request = self.request.clone();
+
+            request.startblock = Some(cursor.block);
+            request.page = Some(U256::from(cursor.page));
+            request.offset = Some(U256::from(self.offset));
+            request.sort = Some(EtherscanSort::Ascending);
+
+            let fetch = async move { client.fetch::<Vec<T>>(request).await };
+
+            match self.client.spawner() {
+                Some(spawner) => {
+                    let (sender, receiver) = oneshot::channel();
+
+                    spawner.spawn(Box::pin(async move {
+                        let _ = sender.send(fetch.await);
+                    }));
+
+                    self.in_flight.push_back(Box::pin(async move {
+                        let items = receiver
+                            .await
+                            .unwrap_or_else(|_| Err("prefetch task was dropped".into()));
+                        (cursor, items)
+                    }));
+                }
+                None => self
+                    .in_flight
+                    .push_back(Box::pin(async move { (cursor, fetch.await) })),
+            }
+        }
+    }
+}
+
+impl EtherscanClient {
+    /// Keeps up to `depth` page requests in flight ahead of the consumer.
+    ///
+    /// With a [`Spawner`](crate::Spawner) configured on the client the
+    /// look-ahead runs in the background while a page is being processed;
+    /// without one the next requests are sent before each page is yielded
+    /// but only make further progress while the stream is polled.
+    #[inline]
+    pub fn paginate_prefetched<T>(
+        &self,
+        request: EtherscanRequest,
+        offset: u64,
+        depth: usize,
+    ) -> BoxStream<'static, Result<Page<T>, Box<dyn Error + Send + Sync>>>
+    where
+        T: DeserializeOwned + Paginated + Send + 'static,
+    {
+        let cursor = Cursor::new(request.startblock.unwrap_or_default());
+        self.pages(request, offset, cursor, depth, None).boxed()
+    }
+
+    #[inline]
+    pub fn paginate<T>(
+        &self,
+        request: EtherscanRequest,
+        offset: u64,
+    ) -> impl Stream<Item = Result<Page<T>, Box<dyn Error + Send + Sync>>> + Send + 'static
+    where
+        T: DeserializeOwned + Paginated + Send + 'static,
+    {
+        let cursor = Cursor::new(request.startblock.unwrap_or_default());
+        self.paginate_from(request, offset, cursor)
+    }
+
+    #[inline]
+    pub fn paginate_from<T>(
+        &self,
+        request: EtherscanRequest,
+        offset: u64,
+        cursor: Cursor,
+    ) -> impl Stream<Item = Result<Page<T>, Box<dyn Error + Send + Sync>>> + Send + 'static
+    where
+        T: DeserializeOwned + Paginated + Send + 'static,
+    {
+        self.paginate_until(request, offset, cursor, None)
+    }
+
+    #[inline]
+    pub fn paginate_until<T>(
+        &self,
+        request: EtherscanRequest,
+        offset: u64,
+        cursor: Cursor,
+        shutdown: Option<CancellationToken>,
+    ) -> impl Stream<Item = Result<Page<T>, Box<dyn Error + Send + Sync>>> + Send + 'static
+    where
+        T: DeserializeOwned + Paginated + Send + 'static,
+    {
+        self.pages(request, offset, cursor, DEFAULT_PREFETCH_DEPTH, shutdown)
+    }
+
+    pub(crate) fn pages<T>(
+        &self,
+        request: EtherscanRequest,
+        offset: u64,
+        cursor: Cursor,
+        depth: usize,
+        shutdown: Option<CancellationToken>,
+    ) -> impl Stream<Item = Result<Page<T>, Box<dyn Error + Send + Sync>>> + Send + 'static
+    where
+        T: DeserializeOwned + Paginated + Send + 'static,
+    {
+        let state = Pages::<T> {
+            client: self.clone(),
+            request,
+            offset: offset.clamp(1, MAX_RESULT_WINDOW),
+            depth: depth.max(1),
+            shutdown,
+            skip: Resume::of(&cursor)
+                .filter(|_| cursor.page == 1)
+                .map(|resume| (cursor.block, resume)),
+            next: Some(cursor),
+            in_flight: FuturesOrdered::new(),
+            ready: VecDeque::new(),
+        };
+
+        stream::unfold(Some(state), |state| async move {
+            let mut state = state?;
+
+            if state
+                .shutdown
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+            {
+                return None;
+            }
+
+            state.schedule();
+
+            let (cursor, items) = match state.ready.pop_front() {
+                Some(page) => page,
+                None => state.in_flight.next().await?,
+            };
+
+            // Poll the look-ahead once so its requests are sent before this
+            // page is handed to the consumer.
+            state.schedule();
+
+            while let Some(Some(page)) = state.in_flight.next().now_or_never() {
+                state.ready.push_back(page);
+            }
+
+            let mut items = match items {
+                Ok(items) => items,
+                Err(e) => return Some((Err(e), None)),
+            };
+
+            let exhausted = (items.len() as u64) < state.offset;
+
+            let next = match cursor.advance(&items, state.offset, exhausted) {
+                Ok(next) => next,
+                Err(e) => return Some((Err(e), None)),
+            };
+
+            if let Some((block, resume)) = state.skip.take() {
+                match items.iter().rposition(|item| resume.matches(item)) {
+                    Some(seen) => {
+                        items.drain(..=seen);
+                    }
+                    None if items.iter().all(|item| item.block_number() == block) => {
+                        items.clear();
+                        state.skip = Some((block, resume));
+                    }
+                    None => {}
+                }
+            }
+
+            let done = exhausted || next == cursor;
+
+            if !done && next.page == 1 {
+                state.skip = Resume::of(&next).map(|resume| (next.block, resume));
+                state.next = Some(next.clone());
+            }
+
+            Some((
+                Ok(Page {
+                    items,
+                    cursor: next,
+                }),
+                (!done).then_some(state),
+            ))
+        })
+    }
+}
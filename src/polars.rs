@@ -0,0 +1,34 @@
+use {
+    crate::arrow::{checked_u64, ArrowRecord, ColumnType, ColumnValue},
+    ::polars::prelude::{Column, DataFrame},
+    std::error::Error,
+};
+
+pub fn to_dataframe<T: ArrowRecord>(
+    records: &[T],
+) -> Result<DataFrame, Box<dyn Error + Send + Sync>> {
+    let mut numbers = vec![Vec::with_capacity(records.len()); T::COLUMNS.len()];
+    let mut strings = vec![Vec::with_capacity(records.len()); T::COLUMNS.len()];
+
+    for record in records {
+        for (index, value) in record.values().into_iter().enumerate() {
+            match value {
+                ColumnValue::UInt64(value) => {
+                    numbers[index].push(checked_u64(T::COLUMNS[index].0, value)?)
+                }
+                ColumnValue::Utf8(value) => strings[index].push(value),
+            }
+        }
+    }
+
+    let columns = T::COLUMNS
+        .iter()
+        .enumerate()
+        .map(|(index, (name, kind))| match kind {
+            ColumnType::UInt64 => Column::new((*name).into(), std::mem::take(&mut numbers[index])),
+            ColumnType::Utf8 => Column::new((*name).into(), std::mem::take(&mut strings[index])),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(DataFrame::new(columns)?)
+}
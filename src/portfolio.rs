@@ -0,0 +1,158 @@
+use {
+    crate::{EtherscanClient, EtherscanRequest, EtherscanTag},
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::error::Error,
+};
+
+pub(crate) const HOLDINGS_PAGE_SIZE: u64 = 100;
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TokenHolding {
+    #[serde(rename = "TokenAddress", with = "crate::serde_u256::hex")]
+    pub contract_address: U256,
+    #[serde(rename = "TokenName", default)]
+    pub name: String,
+    #[serde(rename = "TokenSymbol", default)]
+    pub symbol: String,
+    #[serde(rename = "TokenQuantity", with = "crate::serde_u256::decimal")]
+    pub quantity: U256,
+    #[serde(rename = "TokenDivisor", default)]
+    pub divisor: String,
+}
+
+impl TokenHolding {
+    #[inline]
+    pub fn decimals(&self) -> Option<u32> {
+        self.divisor.parse().ok()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NftHolding {
+    #[serde(rename = "TokenAddress", with = "crate::serde_u256::hex")]
+    pub contract_address: U256,
+    #[serde(rename = "TokenName", default)]
+    pub name: String,
+    #[serde(rename = "TokenSymbol", default)]
+    pub symbol: String,
+    #[serde(rename = "TokenQuantity", with = "crate::serde_u256::decimal")]
+    pub quantity: U256,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Portfolio {
+    pub address: U256,
+    pub balance: U256,
+    pub tokens: Vec<TokenHolding>,
+    pub nfts: Vec<NftHolding>,
+}
+
+impl Portfolio {
+    #[inline]
+    pub fn nft_count(&self) -> U256 {
+        self.nfts.iter().fold(U256::zero(), |total, nft| {
+            total.saturating_add(nft.quantity)
+        })
+    }
+}
+
+impl EtherscanClient {
+    pub async fn address_token_balances(
+        &self,
+        address: U256,
+    ) -> Result<Vec<TokenHolding>, Box<dyn Error + Send + Sync>> {
+        self.all_pages(|page| {
+            EtherscanRequest::account_address_token_balance(
+                address,
+                Some(page),
+                Some(U256::from(HOLDINGS_PAGE_SIZE)),
+            )
+        })
+        .await
+    }
+
+    pub async fn address_nft_balances(
+        &self,
+        address: U256,
+    ) -> Result<Vec<NftHolding>, Box<dyn Error + Send + Sync>> {
+        self.all_pages(|page| {
+            EtherscanRequest::account_address_token_nft_balance(
+                address,
+                Some(page),
+                Some(U256::from(HOLDINGS_PAGE_SIZE)),
+            )
+        })
+        .await
+    }
+
+    pub(crate) async fn all_pages<T, F>(
+        &self,
+        request: F,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>>
+    where
+        T: serde::de::DeserializeOwned,
+        F: Fn(U256) -> EtherscanRequest,
+    {
+        let mut items = Vec::new();
+        let mut page = U256::one();
+
+        loop {
+            let batch: Vec<T> = self.fetch(request(page)).await?;
+            let done = (batch.len() as u64) < HOLDINGS_PAGE_SIZE;
+
+            items.extend(batch);
+
+            if done {
+                return Ok(items);
+            }
+
+            page += U256::one();
+        }
+    }
+
+    pub async fn portfolio(
+        &self,
+        address: U256,
+        tokens: Option<Vec<U256>>,
+        include_nfts: bool,
+    ) -> Result<Portfolio, Box<dyn Error + Send + Sync>> {
+        let balance = self.balance(address, Some(EtherscanTag::Latest)).await?;
+
+        let tokens = match tokens {
+            Some(tokens) => {
+                let balances = self
+                    .batch(4)
+                    .run(tokens, |client, token| async move {
+                        let quantity = client
+                            .token_balance(address, token, Some(EtherscanTag::Latest))
+                            .await?;
+
+                        Ok::<_, Box<dyn Error + Send + Sync>>(TokenHolding {
+                            contract_address: token,
+                            name: String::new(),
+                            symbol: String::new(),
+                            quantity,
+                            divisor: String::new(),
+                        })
+                    })
+                    .await;
+
+                balances.into_iter().collect::<Result<Vec<_>, _>>()?
+            }
+            None => self.address_token_balances(address).await?,
+        };
+
+        let nfts = match include_nfts {
+            true => self.address_nft_balances(address).await?,
+            false => Vec::new(),
+        };
+
+        Ok(Portfolio {
+            address,
+            balance,
+            tokens,
+            nfts,
+        })
+    }
+}
@@ -0,0 +1,193 @@
+use {
+    crate::{
+        record::{address, hash},
+        StoredRecord,
+    },
+    ethabi::ethereum_types::U256,
+    sqlx::{PgPool, Postgres, QueryBuilder, Row},
+    std::{
+        collections::{hash_map::Entry, HashMap},
+        error::Error,
+    },
+};
+
+pub const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value NUMERIC(78, 0) NOT NULL,
+    data JSONB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transactions_block ON transactions (block_number);
+CREATE INDEX IF NOT EXISTS transactions_sender ON transactions (sender);
+CREATE INDEX IF NOT EXISTS transactions_recipient ON transactions (recipient);
+
+CREATE TABLE IF NOT EXISTS internal_transactions (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value NUMERIC(78, 0) NOT NULL,
+    data JSONB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS internal_transactions_block ON internal_transactions (block_number);
+CREATE INDEX IF NOT EXISTS internal_transactions_sender ON internal_transactions (sender);
+CREATE INDEX IF NOT EXISTS internal_transactions_recipient ON internal_transactions (recipient);
+
+CREATE TABLE IF NOT EXISTS token_transfers (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value NUMERIC(78, 0) NOT NULL,
+    data JSONB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS token_transfers_block ON token_transfers (block_number);
+CREATE INDEX IF NOT EXISTS token_transfers_sender ON token_transfers (sender);
+CREATE INDEX IF NOT EXISTS token_transfers_recipient ON token_transfers (recipient);
+CREATE INDEX IF NOT EXISTS token_transfers_contract ON token_transfers (contract_address);
+
+CREATE TABLE IF NOT EXISTS logs (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value NUMERIC(78, 0) NOT NULL,
+    data JSONB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS logs_block ON logs (block_number);
+CREATE INDEX IF NOT EXISTS logs_contract ON logs (contract_address);
+";
+
+const BIND_LIMIT: usize = 65_535;
+const COLUMNS: usize = 9;
+
+#[derive(Clone, Debug)]
+pub struct PostgresSink {
+    pool: PgPool,
+    batch_size: usize,
+}
+
+impl PostgresSink {
+    #[inline]
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            batch_size: 1_000,
+        }
+    }
+
+    #[inline]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.clamp(1, BIND_LIMIT / COLUMNS);
+        self
+    }
+
+    #[inline]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    pub async fn migrate(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::raw_sql(SCHEMA).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn insert<T: StoredRecord>(
+        &self,
+        records: &[T],
+    ) -> Result<u64, Box<dyn Error + Send + Sync>> {
+        let mut inserted = 0;
+
+        for chunk in records.chunks(self.batch_size) {
+            let mut builder = QueryBuilder::<Postgres>::new(format!(
+                "INSERT INTO {} (key, hash, block_number, timestamp, sender, recipient, contract_address, value, data) ",
+                T::TABLE
+            ));
+
+            let mut positions = HashMap::with_capacity(chunk.len());
+            let mut rows = Vec::with_capacity(chunk.len());
+
+            for record in chunk {
+                let row = (record, serde_json::to_string(record)?);
+
+                match positions.entry(record.key()) {
+                    Entry::Occupied(entry) => rows[*entry.get()] = row,
+                    Entry::Vacant(entry) => {
+                        entry.insert(rows.len());
+                        rows.push(row);
+                    }
+                }
+            }
+
+            builder.push_values(rows, |mut row, (record, data)| {
+                row.push_bind(record.key())
+                    .push_bind(hash(record.hash()))
+                    .push_bind(record.block_number().low_u64() as i64)
+                    .push_bind(record.timestamp().low_u64() as i64)
+                    .push_bind(address(record.from()))
+                    .push_bind(record.to().map(address))
+                    .push_bind(record.contract_address().map(address))
+                    .push_bind(record.value())
+                    .push_unseparated("::NUMERIC")
+                    .push_bind(data)
+                    .push_unseparated("::JSONB");
+            });
+
+            builder.push(
+                " ON CONFLICT (key) DO UPDATE SET
+                    block_number = EXCLUDED.block_number,
+                    timestamp = EXCLUDED.timestamp,
+                    data = EXCLUDED.data",
+            );
+
+            inserted += builder.build().execute(&self.pool).await?.rows_affected();
+        }
+
+        Ok(inserted)
+    }
+
+    pub async fn by_address<T: StoredRecord>(
+        &self,
+        address: U256,
+        from_block: Option<U256>,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query(&format!(
+            "SELECT data::TEXT AS data FROM {} WHERE (sender = $1 OR recipient = $1) AND block_number >= $2 ORDER BY block_number",
+            T::TABLE
+        ))
+        .bind(self::address(address))
+        .bind(from_block.unwrap_or_default().low_u64() as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Ok(serde_json::from_str(row.try_get("data")?)?))
+            .collect()
+    }
+
+    pub async fn latest_block<T: StoredRecord>(
+        &self,
+    ) -> Result<Option<U256>, Box<dyn Error + Send + Sync>> {
+        let block: Option<i64> =
+            sqlx::query_scalar(&format!("SELECT MAX(block_number) FROM {}", T::TABLE))
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(block.map(|block| U256::from(block as u64)))
+    }
+}
@@ -0,0 +1,11 @@
+pub use {
+    crate::{
+        AccountBalance, ApiKey, BeaconWithdrawal, ContractSource, EtherscanAction,
+        EtherscanBlockType, EtherscanClient, EtherscanClosest, EtherscanError, EtherscanFormat,
+        EtherscanModule, EtherscanRequest, EtherscanResponse, EtherscanSort, EtherscanTag,
+        GasOracle, HttpRequest, HttpResponse, InternalTransaction, Log, MinedBlock, Page,
+        Paginated, TokenTransfer, Transaction, TransactionReceipt, Transport,
+    },
+    ethabi::ethereum_types::U256,
+    futures::{StreamExt, TryStreamExt},
+};
@@ -0,0 +1,188 @@
+use {
+    crate::{tokens::is_unavailable, EtherscanClient, EtherscanRequest, Portfolio, TokenHolding},
+    ethabi::ethereum_types::U256,
+    serde::{de, Deserialize, Deserializer, Serialize},
+    std::error::Error,
+};
+
+const ETHER_DECIMALS: u32 = 18;
+
+fn number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    String::deserialize(deserializer)?
+        .trim()
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+fn optional_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.and_then(|value| value.trim().parse().ok()))
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EthPrice {
+    #[serde(rename = "ethbtc", deserialize_with = "number")]
+    pub eth_btc: f64,
+    #[serde(rename = "ethbtc_timestamp", with = "crate::serde_u256::decimal")]
+    pub eth_btc_timestamp: U256,
+    #[serde(rename = "ethusd", deserialize_with = "number")]
+    pub eth_usd: f64,
+    #[serde(rename = "ethusd_timestamp", with = "crate::serde_u256::decimal")]
+    pub eth_usd_timestamp: U256,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    #[serde(with = "crate::serde_u256::hex")]
+    pub contract_address: U256,
+    #[serde(default)]
+    pub token_name: String,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub divisor: String,
+    #[serde(default)]
+    pub token_type: String,
+    #[serde(default, with = "crate::serde_u256::decimal")]
+    pub total_supply: U256,
+    #[serde(default)]
+    pub website: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(
+        rename = "tokenPriceUSD",
+        default,
+        deserialize_with = "optional_number"
+    )]
+    pub token_price_usd: Option<f64>,
+}
+
+impl TokenInfo {
+    #[inline]
+    pub fn decimals(&self) -> Option<u32> {
+        self.divisor.parse().ok()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TokenValuation {
+    pub holding: TokenHolding,
+    pub price_usd: Option<f64>,
+    pub value_usd: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PortfolioValuation {
+    pub portfolio: Portfolio,
+    pub eth_usd: f64,
+    pub balance_usd: f64,
+    pub tokens: Vec<TokenValuation>,
+    pub total_usd: f64,
+}
+
+pub fn to_units(amount: U256, decimals: u32) -> f64 {
+    amount.to_string().parse::<f64>().unwrap_or(f64::MAX) / 10f64.powi(decimals as i32)
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub async fn eth_price(&self) -> Result<EthPrice, Box<dyn Error + Send + Sync>> {
+        self.fetch(EtherscanRequest::stats_eth_price()).await
+    }
+
+    #[inline]
+    pub async fn token_info(
+        &self,
+        contract_address: U256,
+    ) -> Result<TokenInfo, Box<dyn Error + Send + Sync>> {
+        let mut info: Vec<TokenInfo> = self
+            .fetch(EtherscanRequest::token_info(contract_address))
+            .await?;
+
+        info.pop()
+            .ok_or_else(|| format!("no token info for {contract_address:#x}").into())
+    }
+
+    async fn token_price_info(
+        &self,
+        contract_address: U256,
+    ) -> Result<Option<TokenInfo>, Box<dyn Error + Send + Sync>> {
+        match self
+            .fetch::<Vec<TokenInfo>>(EtherscanRequest::token_info(contract_address))
+            .await
+        {
+            Ok(mut info) => Ok(info.pop()),
+            Err(e) if is_unavailable(e.as_ref()) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn token_supply(
+        &self,
+        contract_address: U256,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let supply: String = self
+            .fetch(EtherscanRequest::stats_token_supply(contract_address))
+            .await?;
+
+        Ok(U256::from_dec_str(&supply)?)
+    }
+
+    pub async fn value_portfolio(
+        &self,
+        portfolio: Portfolio,
+        token_prices: bool,
+    ) -> Result<PortfolioValuation, Box<dyn Error + Send + Sync>> {
+        let eth_usd = self.eth_price().await?.eth_usd;
+        let balance_usd = to_units(portfolio.balance, ETHER_DECIMALS) * eth_usd;
+
+        let infos = match token_prices {
+            true => self
+                .batch(4)
+                .run(portfolio.tokens.clone(), |client, holding| async move {
+                    client.token_price_info(holding.contract_address).await
+                })
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?,
+            false => vec![None; portfolio.tokens.len()],
+        };
+
+        let tokens = portfolio
+            .tokens
+            .iter()
+            .cloned()
+            .zip(infos)
+            .map(|(holding, info)| {
+                let price_usd = info.as_ref().and_then(|info| info.token_price_usd);
+                let decimals = holding
+                    .decimals()
+                    .or_else(|| info.as_ref().and_then(TokenInfo::decimals));
+
+                let value_usd = price_usd
+                    .zip(decimals)
+                    .map(|(price, decimals)| to_units(holding.quantity, decimals) * price);
+
+                TokenValuation {
+                    holding,
+                    price_usd,
+                    value_usd,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let total_usd = balance_usd
+            + tokens
+                .iter()
+                .filter_map(|token| token.value_usd)
+                .sum::<f64>();
+
+        Ok(PortfolioValuation {
+            portfolio,
+            eth_usd,
+            balance_usd,
+            tokens,
+            total_usd,
+        })
+    }
+}
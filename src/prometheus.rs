@@ -0,0 +1,113 @@
+use {
+    crate::{Metrics, RequestMetrics, RequestOutcome},
+    prometheus::{HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry},
+};
+
+#[derive(Clone, Debug)]
+pub struct PrometheusMetrics {
+    requests: IntCounterVec,
+    duration: HistogramVec,
+    retries: IntCounterVec,
+    rate_limited: IntCounterVec,
+    cache_hits: IntCounter,
+    cache_misses: IntCounter,
+}
+
+impl PrometheusMetrics {
+    pub fn new(registry: &Registry) -> prometheus::Result<Self> {
+        let requests = IntCounterVec::new(
+            Opts::new(
+                "etherscan_requests_total",
+                "Etherscan requests by module, action, and outcome",
+            ),
+            &["module", "action", "outcome"],
+        )?;
+        let duration = HistogramVec::new(
+            HistogramOpts::new(
+                "etherscan_request_duration_seconds",
+                "Etherscan request latency including retries",
+            ),
+            &["module", "action"],
+        )?;
+        let retries = IntCounterVec::new(
+            Opts::new("etherscan_retries_total", "Etherscan request retries"),
+            &["module", "action"],
+        )?;
+        let rate_limited = IntCounterVec::new(
+            Opts::new(
+                "etherscan_rate_limited_total",
+                "Etherscan requests that were rate limited",
+            ),
+            &["module", "action"],
+        )?;
+        let cache_hits = IntCounter::new(
+            "etherscan_cache_hits_total",
+            "Etherscan response cache hits",
+        )?;
+        let cache_misses = IntCounter::new(
+            "etherscan_cache_misses_total",
+            "Etherscan response cache misses",
+        )?;
+
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(duration.clone()))?;
+        registry.register(Box::new(retries.clone()))?;
+        registry.register(Box::new(rate_limited.clone()))?;
+        registry.register(Box::new(cache_hits.clone()))?;
+        registry.register(Box::new(cache_misses.clone()))?;
+
+        Ok(Self {
+            requests,
+            duration,
+            retries,
+            rate_limited,
+            cache_hits,
+            cache_misses,
+        })
+    }
+}
+
+fn outcome_label(outcome: RequestOutcome) -> &'static str {
+    match outcome {
+        RequestOutcome::Success => "success",
+        RequestOutcome::CacheHit => "cache_hit",
+        RequestOutcome::ApiError => "api_error",
+        RequestOutcome::HttpError(_) => "http_error",
+        RequestOutcome::RateLimited => "rate_limited",
+        RequestOutcome::TransportError => "transport_error",
+        RequestOutcome::Cancelled => "cancelled",
+        RequestOutcome::CircuitOpen => "circuit_open",
+        RequestOutcome::QuotaExhausted => "quota_exhausted",
+    }
+}
+
+impl Metrics for PrometheusMetrics {
+    fn record(&self, metrics: &RequestMetrics) {
+        let module = metrics.module.map_or("unknown", |module| module.as_str());
+        let action = metrics.action.map_or("unknown", |action| action.as_str());
+
+        self.requests
+            .with_label_values(&[module, action, outcome_label(metrics.outcome)])
+            .inc();
+
+        if metrics.outcome == RequestOutcome::CacheHit {
+            self.cache_hits.inc();
+            return;
+        }
+
+        self.cache_misses.inc();
+        self.duration
+            .with_label_values(&[module, action])
+            .observe(metrics.duration.as_secs_f64());
+
+        if metrics.retries > 0 {
+            self.retries
+                .with_label_values(&[module, action])
+                .inc_by(metrics.retries as u64);
+        }
+
+        if metrics.outcome == RequestOutcome::RateLimited {
+            self.rate_limited.with_label_values(&[module, action]).inc();
+        }
+    }
+}
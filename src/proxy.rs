@@ -0,0 +1,285 @@
+use {
+    crate::{
+        decode_hex, CorrelatedError, EtherscanClient, EtherscanError, EtherscanRequest,
+        EtherscanResponse, EtherscanTag,
+    },
+    ethabi::ethereum_types::U256,
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProxyError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProxyResponse<T> {
+    pub jsonrpc: String,
+    pub id: serde_json::Value,
+    pub result: Option<T>,
+    pub error: Option<ProxyError>,
+}
+
+impl<T> ProxyResponse<T> {
+    pub fn into_result(self) -> Result<Option<T>, Box<dyn Error + Send + Sync>> {
+        match self.error {
+            Some(ProxyError { code, message }) => {
+                Err(Box::new(EtherscanError::Rpc { code, message }))
+            }
+            None => Ok(self.result),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    #[serde(with = "crate::serde_u256::hex")]
+    pub address: U256,
+    #[serde(with = "crate::serde_u256::hex_vec")]
+    pub topics: Vec<U256>,
+    pub data: String,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub block_number: Option<U256>,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub block_hash: Option<U256>,
+    #[serde(default, rename = "timeStamp", with = "crate::serde_u256::hex_opt")]
+    pub timestamp: Option<U256>,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub transaction_hash: Option<U256>,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub transaction_index: Option<U256>,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub log_index: Option<U256>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    #[serde(with = "crate::serde_u256::hex")]
+    pub transaction_hash: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub transaction_index: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub block_hash: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub block_number: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub from: U256,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub to: Option<U256>,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub cumulative_gas_used: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub gas_used: U256,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub effective_gas_price: Option<U256>,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub contract_address: Option<U256>,
+    #[serde(default)]
+    pub logs: Vec<Log>,
+    #[serde(default, with = "crate::serde_u256::hex_opt")]
+    pub status: Option<U256>,
+}
+
+impl TransactionReceipt {
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        self.status.map(|status| !status.is_zero()).unwrap_or(true)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    #[serde(with = "crate::serde_u256::hex")]
+    pub number: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub hash: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub parent_hash: U256,
+    #[serde(with = "crate::serde_u256::hex")]
+    pub timestamp: U256,
+}
+
+impl EtherscanClient {
+    pub async fn fetch_proxy<T: DeserializeOwned>(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<Option<T>, Box<dyn Error + Send + Sync>> {
+        let request = self.prepare(request);
+        let context = request.context();
+
+        let result = async {
+            let response = self.execute(request).await?.error_for_status()?;
+
+            match serde_json::from_slice::<ProxyResponse<T>>(&response.body) {
+                Ok(response) => response.into_result(),
+                Err(e) => {
+                    match serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(
+                        &response.body,
+                    ) {
+                        Ok(envelope) => envelope.into_result(),
+                        Err(_) => Err(Box::new(e) as Box<dyn Error + Send + Sync>),
+                    }
+                }
+            }
+        };
+
+        result.await.map_err(|e| CorrelatedError::wrap(&context, e))
+    }
+
+    pub async fn logs(
+        &self,
+        address: Option<U256>,
+        fromblock: Option<U256>,
+        toblock: Option<U256>,
+        topics: [Option<U256>; 4],
+    ) -> Result<Vec<Log>, Box<dyn Error + Send + Sync>> {
+        self.fetch(EtherscanRequest::logs_get_logs(
+            address, fromblock, toblock, topics, None, None,
+        ))
+        .await
+    }
+
+    #[inline]
+    pub async fn storage_at(
+        &self,
+        address: U256,
+        position: U256,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        self.storage_at_tag(address, position, EtherscanTag::Latest)
+            .await
+    }
+
+    pub async fn storage_at_tag(
+        &self,
+        address: U256,
+        position: U256,
+        tag: EtherscanTag,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let value = self
+            .fetch_proxy::<String>(EtherscanRequest::proxy_get_storage_at(
+                address,
+                position,
+                Some(tag),
+            ))
+            .await?
+            .unwrap_or_default();
+
+        let value = value.trim_start_matches("0x");
+
+        match value.is_empty() {
+            true => Ok(U256::zero()),
+            false => Ok(U256::from_str_radix(value, 16)?),
+        }
+    }
+
+    pub async fn storage_snapshot(
+        &self,
+        address: U256,
+        slots: Vec<U256>,
+        tag: EtherscanTag,
+        concurrency: usize,
+    ) -> Result<BTreeMap<U256, U256>, Box<dyn Error + Send + Sync>> {
+        self.batch(concurrency)
+            .run(slots, |client, slot| async move {
+                Ok((slot, client.storage_at_tag(address, slot, tag).await?))
+            })
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    pub async fn block_number(&self) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let block = self
+            .fetch_proxy::<String>(EtherscanRequest::proxy_block_number())
+            .await?
+            .unwrap_or_default();
+
+        Ok(U256::from_str_radix(block.trim_start_matches("0x"), 16)?)
+    }
+
+    #[inline]
+    pub async fn block_by_number(
+        &self,
+        number: U256,
+    ) -> Result<Option<Block>, Box<dyn Error + Send + Sync>> {
+        self.fetch_proxy(EtherscanRequest::proxy_get_block_by_number(number, false))
+            .await
+    }
+
+    pub async fn transaction_count(
+        &self,
+        address: U256,
+        tag: EtherscanTag,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let count = self
+            .fetch_proxy::<String>(EtherscanRequest::proxy_get_transaction_count(
+                address,
+                Some(tag),
+            ))
+            .await?
+            .unwrap_or_default();
+
+        let count = count.trim_start_matches("0x");
+
+        match count.is_empty() {
+            true => Ok(U256::zero()),
+            false => Ok(U256::from_str_radix(count, 16)?),
+        }
+    }
+
+    pub async fn call(
+        &self,
+        to: U256,
+        data: &[u8],
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        let data = format!(
+            "0x{}",
+            data.iter().map(|b| format!("{b:02x}")).collect::<String>()
+        );
+
+        let result = self
+            .fetch_proxy::<String>(EtherscanRequest::proxy_call(
+                to,
+                data,
+                Some(EtherscanTag::Latest),
+            ))
+            .await?
+            .unwrap_or_default();
+
+        decode_hex(&result)
+    }
+
+    pub async fn send_raw_transaction(
+        &self,
+        transaction: &[u8],
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let hex = format!(
+            "0x{}",
+            transaction
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<String>()
+        );
+
+        let hash = self
+            .fetch_proxy::<String>(EtherscanRequest::proxy_send_raw_transaction(hex))
+            .await?
+            .ok_or("eth_sendRawTransaction returned no transaction hash")?;
+
+        Ok(U256::from_str_radix(hash.trim_start_matches("0x"), 16)?)
+    }
+
+    #[inline]
+    pub async fn transaction_receipt(
+        &self,
+        txhash: U256,
+    ) -> Result<Option<TransactionReceipt>, Box<dyn Error + Send + Sync>> {
+        self.fetch_proxy(EtherscanRequest::proxy_get_transaction_receipt(txhash))
+            .await
+    }
+}
@@ -0,0 +1,173 @@
+use {
+    crate::{retry, ApiKey, CancellationToken, EtherscanError},
+    std::{
+        collections::HashMap,
+        error::Error,
+        fmt,
+        sync::Mutex,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+};
+
+pub const FREE_TIER_DAILY_LIMIT: u64 = 100_000;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QuotaExhausted {
+    #[default]
+    FailFast,
+    WaitForReset,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QuotaStatus {
+    pub tokens_remaining: Option<u32>,
+    pub rate_limit_delay: Duration,
+    pub daily_limit: Option<u64>,
+    pub calls_today: Vec<(Option<ApiKey>, u64)>,
+    pub resets_in: Duration,
+    pub backoff: Option<Duration>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct DailyUsage {
+    day: u64,
+    calls: u64,
+}
+
+pub struct QuotaTracker {
+    daily_limit: u64,
+    behavior: QuotaExhausted,
+    usage: Mutex<HashMap<Option<ApiKey>, DailyUsage>>,
+}
+
+impl Default for QuotaTracker {
+    fn default() -> Self {
+        Self::new(FREE_TIER_DAILY_LIMIT)
+    }
+}
+
+impl fmt::Debug for QuotaTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuotaTracker")
+            .field("daily_limit", &self.daily_limit)
+            .field("behavior", &self.behavior)
+            .field("keys", &self.usage.lock().unwrap().len())
+            .finish()
+    }
+}
+
+#[inline]
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / SECONDS_PER_DAY
+}
+
+pub fn until_reset() -> Duration {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    Duration::from_secs((today() + 1) * SECONDS_PER_DAY).saturating_sub(now)
+}
+
+impl QuotaTracker {
+    #[inline]
+    pub fn new(daily_limit: u64) -> Self {
+        Self {
+            daily_limit,
+            behavior: QuotaExhausted::FailFast,
+            usage: Mutex::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_behavior(mut self, behavior: QuotaExhausted) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    #[inline]
+    pub fn daily_limit(&self) -> u64 {
+        self.daily_limit
+    }
+
+    pub fn usage(&self, apikey: Option<&ApiKey>) -> u64 {
+        match self.usage.lock().unwrap().get(&apikey.cloned()) {
+            Some(usage) if usage.day == today() => usage.calls,
+            _ => 0,
+        }
+    }
+
+    #[inline]
+    pub fn remaining(&self, apikey: Option<&ApiKey>) -> u64 {
+        self.daily_limit.saturating_sub(self.usage(apikey))
+    }
+
+    pub fn usage_by_key(&self) -> Vec<(Option<ApiKey>, u64)> {
+        let today = today();
+
+        self.usage
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, usage)| usage.day == today)
+            .map(|(apikey, usage)| (apikey.clone(), usage.calls))
+            .collect()
+    }
+
+    pub fn total_usage(&self) -> u64 {
+        let today = today();
+
+        self.usage
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|usage| usage.day == today)
+            .map(|usage| usage.calls)
+            .sum()
+    }
+
+    pub fn try_acquire(&self, apikey: Option<&ApiKey>) -> Result<(), EtherscanError> {
+        let today = today();
+        let mut usage = self.usage.lock().unwrap();
+        let usage = usage.entry(apikey.cloned()).or_default();
+
+        if usage.day != today {
+            *usage = DailyUsage {
+                day: today,
+                calls: 0,
+            };
+        }
+
+        if usage.calls >= self.daily_limit {
+            return Err(EtherscanError::QuotaExhausted {
+                limit: self.daily_limit,
+                resets_in: until_reset(),
+            });
+        }
+
+        usage.calls += 1;
+        Ok(())
+    }
+
+    pub async fn acquire(
+        &self,
+        apikey: Option<&ApiKey>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        loop {
+            match self.try_acquire(apikey) {
+                Ok(()) => return Ok(()),
+                Err(e) if self.behavior == QuotaExhausted::FailFast => return Err(Box::new(e)),
+                Err(_) => {
+                    retry::sleep(until_reset() + Duration::from_secs(1), cancellation).await?
+                }
+            }
+        }
+    }
+}
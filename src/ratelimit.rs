@@ -0,0 +1,158 @@
+use {
+    crate::{retry, CancellationToken},
+    std::{
+        error::Error,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        },
+        time::{Duration, Instant},
+    },
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestPriority {
+    Background,
+    #[default]
+    Interactive,
+}
+
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests: u32,
+    interval: Duration,
+    tolerance: Duration,
+    next: Mutex<Instant>,
+    interactive: AtomicUsize,
+}
+
+impl RateLimiter {
+    #[inline]
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn new(requests: u32, period: Duration) -> Self {
+        let requests = requests.max(1);
+        let interval = period / requests;
+
+        Self {
+            requests,
+            interval,
+            tolerance: interval * (requests - 1),
+            next: Mutex::new(Instant::now()),
+            interactive: AtomicUsize::new(0),
+        }
+    }
+
+    #[inline]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    #[inline]
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    #[inline]
+    pub fn period(&self) -> Duration {
+        self.interval * self.requests
+    }
+
+    pub fn available(&self) -> u32 {
+        let backlog = self
+            .next
+            .lock()
+            .unwrap()
+            .saturating_duration_since(Instant::now());
+
+        let used = backlog.as_nanos().div_ceil(self.interval.as_nanos().max(1));
+        self.requests
+            .saturating_sub(used.min(u32::MAX as u128) as u32)
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.next
+            .lock()
+            .unwrap()
+            .saturating_duration_since(Instant::now())
+            .saturating_sub(self.tolerance)
+    }
+
+    pub fn reserve(&self) -> Duration {
+        let now = Instant::now();
+        let mut next = self.next.lock().unwrap();
+        let slot = (*next).max(now);
+
+        *next = slot + self.interval;
+
+        slot.saturating_duration_since(now)
+            .saturating_sub(self.tolerance)
+    }
+
+    #[inline]
+    pub fn waiting_interactive(&self) -> usize {
+        self.interactive.load(Ordering::Acquire)
+    }
+
+    fn try_reserve(&self) -> bool {
+        let now = Instant::now();
+        let mut next = self.next.lock().unwrap();
+        let slot = (*next).max(now);
+
+        if slot.saturating_duration_since(now) > self.tolerance {
+            return false;
+        }
+
+        *next = slot + self.interval;
+        true
+    }
+
+    #[inline]
+    pub async fn acquire(
+        &self,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.acquire_with_priority(RequestPriority::Interactive, cancellation)
+            .await
+    }
+
+    pub async fn acquire_with_priority(
+        &self,
+        priority: RequestPriority,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if priority == RequestPriority::Background {
+            while self.waiting_interactive() > 0 || !self.try_reserve() {
+                retry::sleep(self.interval, cancellation).await?;
+            }
+
+            return Ok(());
+        }
+
+        self.interactive.fetch_add(1, Ordering::AcqRel);
+        let _waiting = InteractiveGuard(&self.interactive);
+        let delay = self.reserve();
+
+        if !delay.is_zero() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                delay_ms = delay.as_millis() as u64,
+                "waiting for etherscan rate limiter"
+            );
+
+            retry::sleep(delay, cancellation).await?;
+        }
+
+        Ok(())
+    }
+}
+
+struct InteractiveGuard<'a>(&'a AtomicUsize);
+
+impl Drop for InteractiveGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
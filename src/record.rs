@@ -0,0 +1,184 @@
+use {
+    crate::{InternalTransaction, Log, TokenTransfer, Transaction},
+    ethabi::ethereum_types::U256,
+    serde::{de::DeserializeOwned, Serialize},
+};
+
+pub trait StoredRecord: Serialize + DeserializeOwned {
+    const TABLE: &'static str;
+
+    fn key(&self) -> String;
+    fn hash(&self) -> U256;
+    fn block_number(&self) -> U256;
+    fn timestamp(&self) -> U256;
+    fn from(&self) -> U256;
+    fn to(&self) -> Option<U256>;
+    fn contract_address(&self) -> Option<U256>;
+    fn value(&self) -> String;
+}
+
+pub(crate) fn address(value: U256) -> String {
+    format!("{value:#042x}")
+}
+
+pub(crate) fn hash(value: U256) -> String {
+    format!("{value:#066x}")
+}
+
+impl StoredRecord for Transaction {
+    const TABLE: &'static str = "transactions";
+
+    fn key(&self) -> String {
+        hash(self.hash)
+    }
+
+    fn hash(&self) -> U256 {
+        self.hash
+    }
+
+    fn block_number(&self) -> U256 {
+        self.block_number
+    }
+
+    fn timestamp(&self) -> U256 {
+        self.timestamp
+    }
+
+    fn from(&self) -> U256 {
+        self.from
+    }
+
+    fn to(&self) -> Option<U256> {
+        self.to
+    }
+
+    fn contract_address(&self) -> Option<U256> {
+        self.contract_address
+    }
+
+    fn value(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl StoredRecord for InternalTransaction {
+    const TABLE: &'static str = "internal_transactions";
+
+    fn key(&self) -> String {
+        format!("{}:{}", hash(self.hash), self.trace_id)
+    }
+
+    fn hash(&self) -> U256 {
+        self.hash
+    }
+
+    fn block_number(&self) -> U256 {
+        self.block_number
+    }
+
+    fn timestamp(&self) -> U256 {
+        self.timestamp
+    }
+
+    fn from(&self) -> U256 {
+        self.from
+    }
+
+    fn to(&self) -> Option<U256> {
+        self.to
+    }
+
+    fn contract_address(&self) -> Option<U256> {
+        self.contract_address
+    }
+
+    fn value(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl StoredRecord for TokenTransfer {
+    const TABLE: &'static str = "token_transfers";
+
+    fn key(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            hash(self.hash),
+            address(self.contract_address),
+            address(self.from),
+            self.to.map(address).unwrap_or_default(),
+            self.token_id.as_deref().unwrap_or_default(),
+            self.value(),
+        )
+    }
+
+    fn hash(&self) -> U256 {
+        self.hash
+    }
+
+    fn block_number(&self) -> U256 {
+        self.block_number
+    }
+
+    fn timestamp(&self) -> U256 {
+        self.timestamp
+    }
+
+    fn from(&self) -> U256 {
+        self.from
+    }
+
+    fn to(&self) -> Option<U256> {
+        self.to
+    }
+
+    fn contract_address(&self) -> Option<U256> {
+        Some(self.contract_address)
+    }
+
+    fn value(&self) -> String {
+        self.token_value
+            .clone()
+            .unwrap_or_else(|| self.value.to_string())
+    }
+}
+
+impl StoredRecord for Log {
+    const TABLE: &'static str = "logs";
+
+    fn key(&self) -> String {
+        format!(
+            "{}:{}",
+            hash(self.transaction_hash.unwrap_or_default()),
+            self.log_index.unwrap_or_default()
+        )
+    }
+
+    fn hash(&self) -> U256 {
+        self.transaction_hash.unwrap_or_default()
+    }
+
+    fn block_number(&self) -> U256 {
+        self.block_number.unwrap_or_default()
+    }
+
+    fn timestamp(&self) -> U256 {
+        self.timestamp.unwrap_or_default()
+    }
+
+    fn from(&self) -> U256 {
+        self.address
+    }
+
+    fn to(&self) -> Option<U256> {
+        None
+    }
+
+    fn contract_address(&self) -> Option<U256> {
+        Some(self.address)
+    }
+
+    fn value(&self) -> String {
+        "0".to_string()
+    }
+}
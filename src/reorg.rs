@@ -0,0 +1,107 @@
+use {
+    crate::EtherscanClient,
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Reorg {
+    pub block_number: U256,
+    pub recorded_hash: U256,
+    pub canonical_hash: Option<U256>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReorgDetector {
+    client: EtherscanClient,
+    depth: u64,
+    blocks: BTreeMap<U256, U256>,
+}
+
+impl ReorgDetector {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            depth: 12,
+            blocks: BTreeMap::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_depth(mut self, depth: u64) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    #[inline]
+    pub fn depth(&self) -> u64 {
+        self.depth
+    }
+
+    #[inline]
+    pub fn pending(&self) -> &BTreeMap<U256, U256> {
+        &self.blocks
+    }
+
+    #[inline]
+    pub fn record(&mut self, block_number: U256, hash: U256) {
+        self.blocks.insert(block_number, hash);
+    }
+
+    pub async fn track(
+        &mut self,
+        block_number: U256,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        let block = self
+            .client
+            .block_by_number(block_number)
+            .await?
+            .ok_or_else(|| format!("block {block_number} not found"))?;
+
+        self.record(block.number, block.hash);
+        Ok(block.hash)
+    }
+
+    pub async fn check(&mut self) -> Result<Vec<Reorg>, Box<dyn Error + Send + Sync>> {
+        let head = self.client.block_number().await?;
+        let depth = U256::from(self.depth);
+
+        let due = self
+            .blocks
+            .iter()
+            .filter(|(number, _)| number.saturating_add(depth) <= head)
+            .map(|(number, hash)| (*number, *hash))
+            .collect::<Vec<_>>();
+
+        let mut reorgs = vec![];
+
+        for (block_number, recorded_hash) in due {
+            let canonical_hash = self
+                .client
+                .block_by_number(block_number)
+                .await?
+                .map(|block| block.hash);
+
+            if canonical_hash != Some(recorded_hash) {
+                reorgs.push(Reorg {
+                    block_number,
+                    recorded_hash,
+                    canonical_hash,
+                });
+            }
+
+            self.blocks.remove(&block_number);
+        }
+
+        Ok(reorgs)
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn reorg_detector(&self) -> ReorgDetector {
+        ReorgDetector::new(self.clone())
+    }
+}
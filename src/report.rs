@@ -0,0 +1,209 @@
+use {
+    crate::{EtherscanClient, HistoryEvent, TokenTransfer},
+    chrono::DateTime,
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportPeriod {
+    Day,
+    #[default]
+    Month,
+    Year,
+}
+
+impl ReportPeriod {
+    pub fn key(&self, timestamp: U256) -> String {
+        let format = match self {
+            Self::Day => "%Y-%m-%d",
+            Self::Month => "%Y-%m",
+            Self::Year => "%Y",
+        };
+
+        DateTime::from_timestamp(timestamp.low_u64() as i64, 0)
+            .map(|time| time.format(format).to_string())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+#[serde(tag = "kind", content = "contract_address")]
+pub enum Asset {
+    #[default]
+    Ether,
+    Erc20(U256),
+    Erc721(U256),
+    Erc1155(U256),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AssetFlow {
+    pub period: String,
+    pub asset: Asset,
+    pub symbol: String,
+    pub decimals: Option<u8>,
+    pub inflow: U256,
+    pub outflow: U256,
+    pub transfers_in: u64,
+    pub transfers_out: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TransferReport {
+    pub address: U256,
+    pub period: ReportPeriod,
+    pub flows: Vec<AssetFlow>,
+    pub fees: BTreeMap<String, U256>,
+    pub total_fees: U256,
+}
+
+impl TransferReport {
+    pub fn from_events(address: U256, events: &[HistoryEvent], period: ReportPeriod) -> Self {
+        let mut flows = BTreeMap::<(String, Asset), AssetFlow>::new();
+        let mut fees = BTreeMap::<String, U256>::new();
+        let mut total_fees = U256::zero();
+
+        let mut record = |key: String,
+                          asset: Asset,
+                          symbol: &str,
+                          decimals: Option<u8>,
+                          from: U256,
+                          to: Option<U256>,
+                          amount: U256| {
+            if from != address && to != Some(address) {
+                return;
+            }
+
+            let flow = flows
+                .entry((key.clone(), asset))
+                .or_insert_with(|| AssetFlow {
+                    period: key,
+                    asset,
+                    symbol: symbol.to_string(),
+                    decimals,
+                    ..Default::default()
+                });
+
+            if from == address {
+                flow.outflow = flow.outflow.saturating_add(amount);
+                flow.transfers_out += 1;
+            }
+
+            if to == Some(address) {
+                flow.inflow = flow.inflow.saturating_add(amount);
+                flow.transfers_in += 1;
+            }
+        };
+
+        for event in events {
+            let key = period.key(event.timestamp());
+
+            match event {
+                HistoryEvent::Transaction(transaction) => {
+                    if transaction.from == address {
+                        let fee = transaction.gas_used.saturating_mul(transaction.gas_price);
+                        let entry = fees.entry(key.clone()).or_default();
+                        *entry = entry.saturating_add(fee);
+                        total_fees = total_fees.saturating_add(fee);
+                    }
+
+                    if transaction.is_error != "1" && !transaction.value.is_zero() {
+                        record(
+                            key,
+                            Asset::Ether,
+                            "ETH",
+                            Some(18),
+                            transaction.from,
+                            transaction.to,
+                            transaction.value,
+                        );
+                    }
+                }
+                HistoryEvent::Internal(transaction) => {
+                    if transaction.is_error != "1" && !transaction.value.is_zero() {
+                        record(
+                            key,
+                            Asset::Ether,
+                            "ETH",
+                            Some(18),
+                            transaction.from,
+                            transaction.to,
+                            transaction.value,
+                        );
+                    }
+                }
+                HistoryEvent::Erc20(transfer) => record(
+                    key,
+                    Asset::Erc20(transfer.contract_address),
+                    &transfer.token_symbol,
+                    transfer.token_decimal.parse().ok(),
+                    transfer.from,
+                    transfer.to,
+                    transfer.value,
+                ),
+                HistoryEvent::Erc721(transfer) => record(
+                    key,
+                    Asset::Erc721(transfer.contract_address),
+                    &transfer.token_symbol,
+                    None,
+                    transfer.from,
+                    transfer.to,
+                    U256::one(),
+                ),
+                HistoryEvent::Erc1155(transfer) => record(
+                    key,
+                    Asset::Erc1155(transfer.contract_address),
+                    &transfer.token_symbol,
+                    None,
+                    transfer.from,
+                    transfer.to,
+                    token_quantity(transfer),
+                ),
+            }
+        }
+
+        Self {
+            address,
+            period,
+            flows: flows.into_values().collect(),
+            fees,
+            total_fees,
+        }
+    }
+
+    pub fn totals(&self) -> BTreeMap<Asset, (U256, U256)> {
+        let mut totals = BTreeMap::<Asset, (U256, U256)>::new();
+
+        for flow in &self.flows {
+            let (inflow, outflow) = totals.entry(flow.asset).or_default();
+            *inflow = inflow.saturating_add(flow.inflow);
+            *outflow = outflow.saturating_add(flow.outflow);
+        }
+
+        totals
+    }
+}
+
+fn token_quantity(transfer: &TokenTransfer) -> U256 {
+    transfer
+        .token_value
+        .as_deref()
+        .and_then(|value| U256::from_dec_str(value).ok())
+        .unwrap_or_else(U256::one)
+}
+
+impl EtherscanClient {
+    pub async fn transfer_report(
+        &self,
+        address: U256,
+        period: ReportPeriod,
+    ) -> Result<TransferReport, Box<dyn Error + Send + Sync>> {
+        let events = self.history(address).fetch().await?;
+        Ok(TransferReport::from_events(address, &events, period))
+    }
+}
@@ -0,0 +1,34 @@
+use {
+    crate::EtherscanError,
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    std::error::Error,
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EtherscanResponse<T> {
+    pub status: String,
+    pub message: String,
+    pub result: T,
+}
+
+impl EtherscanResponse<serde_json::Value> {
+    pub fn into_result<T: DeserializeOwned>(self) -> Result<T, Box<dyn Error + Send + Sync>> {
+        let Self {
+            status,
+            message,
+            result,
+        } = self;
+
+        if status != "1" && !result.is_array() {
+            return Err(Box::new(EtherscanError::Api {
+                message,
+                result: match result {
+                    serde_json::Value::String(result) => result,
+                    result => result.to_string(),
+                },
+            }));
+        }
+
+        Ok(serde_json::from_value(result)?)
+    }
+}
@@ -0,0 +1,334 @@
+use {
+    crate::{CancellationToken, EtherscanError, EtherscanResponse, HttpResponse},
+    futures::future::{self, Either},
+    futures_timer::Delay,
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::{hash_map::RandomState, VecDeque},
+        error::Error,
+        future::Future,
+        hash::{BuildHasher, Hasher},
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Mutex,
+        },
+        time::{Duration, Instant, SystemTime},
+    },
+};
+
+pub const RATE_LIMIT_DELAY: Duration = Duration::from_secs(1);
+
+pub fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    let value = response.header("retry-after")?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    httpdate::parse_http_date(value)
+        .ok()?
+        .duration_since(SystemTime::now())
+        .ok()
+}
+
+pub fn rate_limit_message(response: &HttpResponse) -> Option<String> {
+    let response =
+        serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body).ok()?;
+
+    if response.status == "1" {
+        return None;
+    }
+
+    match response.result {
+        serde_json::Value::String(result) if result.to_lowercase().contains("rate limit") => {
+            Some(result)
+        }
+        _ => None,
+    }
+}
+
+pub fn is_rate_limited(response: &HttpResponse) -> bool {
+    response.status == 429 || rate_limit_message(response).is_some()
+}
+
+pub fn throttle_delay(response: &HttpResponse) -> Option<Duration> {
+    let message = rate_limit_message(response);
+
+    if !matches!(response.status, 429 | 503) && message.is_none() {
+        return None;
+    }
+
+    if let Some(delay) = retry_after(response) {
+        return Some(delay);
+    }
+
+    if response.status == 429 {
+        return Some(RATE_LIMIT_DELAY);
+    }
+
+    match message {
+        Some(message) if message.to_lowercase().contains("daily") => None,
+        Some(_) => Some(RATE_LIMIT_DELAY),
+        None => None,
+    }
+}
+
+pub(crate) async fn sleep(
+    duration: Duration,
+    cancellation: Option<&CancellationToken>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    match cancellation {
+        Some(token) => match future::select(Delay::new(duration), token.cancelled()).await {
+            Either::Left(_) => Ok(()),
+            Either::Right(_) => Err(Box::new(EtherscanError::Cancelled)),
+        },
+        None => {
+            Delay::new(duration).await;
+            Ok(())
+        }
+    }
+}
+
+pub(crate) async fn until_shutdown<F, T>(
+    future: F,
+    shutdown: Option<&CancellationToken>,
+) -> Result<T, Box<dyn Error + Send + Sync>>
+where
+    F: Future<Output = Result<T, Box<dyn Error + Send + Sync>>>,
+{
+    match shutdown {
+        Some(token) if token.is_cancelled() => Err(Box::new(EtherscanError::Cancelled)),
+        Some(token) => match future::select(Box::pin(future), token.cancelled()).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(Box::new(EtherscanError::Cancelled)),
+        },
+        None => future.await,
+    }
+}
+
+#[inline]
+pub(crate) async fn idle(
+    duration: Duration,
+    cancellation: Option<&CancellationToken>,
+    shutdown: Option<&CancellationToken>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    until_shutdown(sleep(duration, cancellation), shutdown).await
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryCause {
+    Transport,
+    Throttled(Duration),
+    Server(u16),
+}
+
+impl RetryCause {
+    pub fn classify(response: &HttpResponse) -> Option<Self> {
+        match throttle_delay(response) {
+            Some(delay) => Some(Self::Throttled(delay)),
+            None if response.status >= 500 => Some(Self::Server(response.status)),
+            None => None,
+        }
+    }
+}
+
+pub trait RetryPolicy: Send + Sync {
+    fn retry_delay(&self, attempt: u32, cause: RetryCause) -> Option<Duration>;
+
+    #[inline]
+    fn max_elapsed(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Jitter {
+    #[default]
+    None,
+    Full,
+    Equal,
+}
+
+impl Jitter {
+    pub fn apply(self, delay: Duration) -> Duration {
+        match self {
+            Self::None => delay,
+            Self::Full => random_duration(delay),
+            Self::Equal => delay / 2 + random_duration(delay - delay / 2),
+        }
+    }
+
+    /// Returns only the random part of [`Jitter::apply`], for adding on top of
+    /// a delay that must not be shortened.
+    pub fn spread(self, delay: Duration) -> Duration {
+        match self {
+            Self::None => Duration::ZERO,
+            Self::Full => random_duration(delay),
+            Self::Equal => random_duration(delay - delay / 2),
+        }
+    }
+}
+
+fn random_duration(max: Duration) -> Duration {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+
+    let nanos = max.as_nanos().min(u64::MAX as u128) as u64;
+
+    match nanos {
+        0 => Duration::ZERO,
+        nanos => Duration::from_nanos(hasher.finish() % (nanos + 1)),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialBackoff {
+    pub max_retries: u32,
+    pub initial: Duration,
+    pub max: Duration,
+    pub jitter: Jitter,
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(30),
+            jitter: Jitter::None,
+            max_elapsed: None,
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    #[inline]
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    #[inline]
+    pub fn with_max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn retry_delay(&self, attempt: u32, cause: RetryCause) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+
+        let backoff = self
+            .initial
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max);
+
+        match cause {
+            RetryCause::Throttled(delay) if delay > self.max => None,
+            RetryCause::Throttled(delay) => Some(delay + self.jitter.spread(backoff)),
+            RetryCause::Transport | RetryCause::Server(_) => Some(self.jitter.apply(backoff)),
+        }
+    }
+
+    #[inline]
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FixedInterval {
+    pub max_retries: u32,
+    pub interval: Duration,
+}
+
+impl RetryPolicy for FixedInterval {
+    fn retry_delay(&self, attempt: u32, cause: RetryCause) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+
+        match cause {
+            RetryCause::Throttled(delay) => Some(delay.max(self.interval)),
+            RetryCause::Transport | RetryCause::Server(_) => Some(self.interval),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RetryBudget {
+    max_retries: usize,
+    window: Duration,
+    spent: Mutex<VecDeque<Instant>>,
+}
+
+impl RetryBudget {
+    #[inline]
+    pub fn new(max_retries: usize, window: Duration) -> Self {
+        Self {
+            max_retries,
+            window,
+            spent: Mutex::default(),
+        }
+    }
+
+    #[inline]
+    pub fn per_minute(max_retries: usize) -> Self {
+        Self::new(max_retries, Duration::from_secs(60))
+    }
+
+    #[inline]
+    pub fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+
+    #[inline]
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    fn expire(&self, spent: &mut VecDeque<Instant>, now: Instant) {
+        while spent
+            .front()
+            .is_some_and(|at| now.saturating_duration_since(*at) >= self.window)
+        {
+            spent.pop_front();
+        }
+    }
+
+    pub fn remaining(&self) -> usize {
+        let mut spent = self.spent.lock().unwrap();
+        self.expire(&mut spent, Instant::now());
+        self.max_retries.saturating_sub(spent.len())
+    }
+
+    pub fn try_spend(&self) -> bool {
+        let now = Instant::now();
+        let mut spent = self.spent.lock().unwrap();
+        self.expire(&mut spent, now);
+
+        if spent.len() >= self.max_retries {
+            return false;
+        }
+
+        spent.push_back(now);
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoRetry;
+
+impl RetryPolicy for NoRetry {
+    #[inline]
+    fn retry_delay(&self, _: u32, _: RetryCause) -> Option<Duration> {
+        None
+    }
+}
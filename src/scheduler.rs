@@ -0,0 +1,122 @@
+use {
+    crate::{
+        quota, retry, CancellationToken, EtherscanClient, EtherscanRequest, HttpResponse,
+        RateLimiter, RequestPriority,
+    },
+    serde::de::DeserializeOwned,
+    std::{error::Error, sync::Arc, time::Duration},
+};
+
+pub const DEFAULT_BACKFILL_SHARE: f64 = 0.4;
+
+#[derive(Clone, Debug)]
+pub struct BackfillScheduler {
+    client: EtherscanClient,
+    share: f64,
+    quota_reserve: u64,
+    pacing: Option<Arc<RateLimiter>>,
+    shutdown: Option<CancellationToken>,
+}
+
+impl BackfillScheduler {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client: client.with_priority(RequestPriority::Background),
+            share: 0.0,
+            quota_reserve: 0,
+            pacing: None,
+            shutdown: None,
+        }
+        .with_share(DEFAULT_BACKFILL_SHARE)
+    }
+
+    pub fn with_share(mut self, share: f64) -> Self {
+        self.share = share.clamp(0.0, 1.0);
+        self.pacing = self.client.rate_limiter().map(|limiter| {
+            let requests = (limiter.requests() as f64 * self.share).floor().max(1.0) as u32;
+            Arc::new(RateLimiter::new(requests, limiter.period()))
+        });
+        self
+    }
+
+    #[inline]
+    pub fn with_quota_reserve(mut self, calls: u64) -> Self {
+        self.quota_reserve = calls;
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn share(&self) -> f64 {
+        self.share
+    }
+
+    #[inline]
+    pub fn quota_reserve(&self) -> u64 {
+        self.quota_reserve
+    }
+
+    #[inline]
+    pub fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    pub async fn acquire(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if let Some(tracker) = self.client.quota_tracker() {
+            while tracker.total_usage() + self.quota_reserve >= tracker.daily_limit() {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    reserve = self.quota_reserve,
+                    "backfill paused until the daily quota resets"
+                );
+
+                retry::idle(
+                    quota::until_reset().max(Duration::from_secs(1)),
+                    self.client.cancellation(),
+                    self.shutdown.as_ref(),
+                )
+                .await?;
+            }
+        }
+
+        if let Some(pacing) = &self.pacing {
+            retry::until_shutdown(
+                pacing
+                    .acquire_with_priority(RequestPriority::Background, self.client.cancellation()),
+                self.shutdown.as_ref(),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn execute(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        self.acquire().await?;
+        self.client.execute(request).await
+    }
+
+    pub async fn fetch<T: DeserializeOwned>(
+        &self,
+        request: EtherscanRequest,
+    ) -> Result<T, Box<dyn Error + Send + Sync>> {
+        self.acquire().await?;
+        self.client.fetch(request).await
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn backfill_scheduler(&self) -> BackfillScheduler {
+        BackfillScheduler::new(self.clone())
+    }
+}
@@ -0,0 +1,88 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+pub const DEFAULT_SEEN_CAPACITY: usize = 10_000;
+
+#[derive(Clone, Debug)]
+pub struct SeenFilter<T> {
+    capacity: usize,
+    order: VecDeque<T>,
+    items: HashSet<T>,
+}
+
+impl<T: Clone + Eq + Hash> Default for SeenFilter<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEEN_CAPACITY)
+    }
+}
+
+impl<T: Clone + Eq + Hash> SeenFilter<T> {
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::new(),
+            items: HashSet::new(),
+        }
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.items.contains(item)
+    }
+
+    pub fn insert(&mut self, item: T) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+
+        if !self.items.insert(item.clone()) {
+            return false;
+        }
+
+        self.order.push_back(item);
+
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.items.remove(&evicted);
+            }
+        }
+
+        true
+    }
+
+    pub fn remove(&mut self, item: &T) -> bool {
+        if !self.items.remove(item) {
+            return false;
+        }
+
+        if let Some(index) = self.order.iter().position(|entry| entry == item) {
+            self.order.remove(index);
+        }
+
+        true
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.order.clear();
+        self.items.clear();
+    }
+}
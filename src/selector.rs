@@ -0,0 +1,239 @@
+use {
+    crate::{decode_hex, DecodedCall, EtherscanClient, HttpRequest, Transport},
+    ethabi::{param_type::Reader, ParamType},
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        error::Error,
+        fmt,
+        sync::{Arc, Mutex},
+    },
+};
+
+pub const DEFAULT_OPENCHAIN_URL: &str = "https://api.openchain.xyz/signature-database/v1/lookup";
+pub const DEFAULT_FOUR_BYTE_URL: &str = "https://www.4byte.directory/api/v1/signatures/";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureDatabase {
+    #[default]
+    OpenChain,
+    FourByte,
+}
+
+impl SignatureDatabase {
+    #[inline]
+    pub fn default_url(self) -> &'static str {
+        match self {
+            Self::OpenChain => DEFAULT_OPENCHAIN_URL,
+            Self::FourByte => DEFAULT_FOUR_BYTE_URL,
+        }
+    }
+
+    fn url(self, base: &str, selector: [u8; 4]) -> String {
+        let selector = format!("0x{}", hex(&selector));
+
+        match self {
+            Self::OpenChain => format!("{base}?function={selector}&filter=true"),
+            Self::FourByte => format!("{base}?hex_signature={selector}&ordering=created_at"),
+        }
+    }
+
+    fn parse(
+        self,
+        selector: [u8; 4],
+        body: &[u8],
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+        #[derive(Deserialize)]
+        struct OpenChainResponse {
+            result: OpenChainResult,
+        }
+
+        #[derive(Deserialize)]
+        struct OpenChainResult {
+            function: HashMap<String, Option<Vec<OpenChainSignature>>>,
+        }
+
+        #[derive(Deserialize)]
+        struct OpenChainSignature {
+            name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct FourByteResponse {
+            results: Vec<FourByteSignature>,
+        }
+
+        #[derive(Deserialize)]
+        struct FourByteSignature {
+            text_signature: String,
+        }
+
+        Ok(match self {
+            Self::OpenChain => serde_json::from_slice::<OpenChainResponse>(body)?
+                .result
+                .function
+                .remove(&format!("0x{}", hex(&selector)))
+                .flatten()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|signature| signature.name)
+                .collect(),
+            Self::FourByte => serde_json::from_slice::<FourByteResponse>(body)?
+                .results
+                .into_iter()
+                .map(|signature| signature.text_signature)
+                .collect(),
+        })
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn param_types(signature: &str) -> Option<Vec<ParamType>> {
+    let (_, params) = signature.split_once('(')?;
+    let params = params.strip_suffix(')')?;
+
+    if params.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut types = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in params.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                types.push(Reader::read(&params[start..i]).ok()?);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    types.push(Reader::read(&params[start..]).ok()?);
+    Some(types)
+}
+
+fn decode_signature(signature: &str, selector: [u8; 4], data: &[u8]) -> Option<DecodedCall> {
+    let types = param_types(signature)?;
+    let tokens = ethabi::decode(&types, &data[4..]).ok()?;
+
+    Some(DecodedCall {
+        name: signature.split('(').next().unwrap_or(signature).to_string(),
+        signature: signature.to_string(),
+        selector,
+        params: tokens
+            .into_iter()
+            .map(|token| (String::new(), token))
+            .collect(),
+        guessed: true,
+    })
+}
+
+#[derive(Clone)]
+pub struct SelectorLookup {
+    transport: Arc<dyn Transport>,
+    database: SignatureDatabase,
+    url: String,
+    cache: Arc<Mutex<HashMap<[u8; 4], Vec<String>>>>,
+}
+
+impl fmt::Debug for SelectorLookup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectorLookup")
+            .field("database", &self.database)
+            .field("url", &self.url)
+            .field("cached", &self.cache.lock().unwrap().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SelectorLookup {
+    #[inline]
+    pub fn new<T: Transport + 'static>(transport: T, database: SignatureDatabase) -> Self {
+        Self::from_arc(Arc::new(transport), database)
+    }
+
+    fn from_arc(transport: Arc<dyn Transport>, database: SignatureDatabase) -> Self {
+        Self {
+            transport,
+            database,
+            url: database.default_url().to_string(),
+            cache: Arc::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = url;
+        self
+    }
+
+    #[inline]
+    pub fn database(&self) -> SignatureDatabase {
+        self.database
+    }
+
+    pub async fn lookup(
+        &self,
+        selector: [u8; 4],
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+        if let Some(signatures) = self.cache.lock().unwrap().get(&selector) {
+            return Ok(signatures.clone());
+        }
+
+        let response = self
+            .transport
+            .send(HttpRequest::get(self.database.url(&self.url, selector)))
+            .await?
+            .error_for_status()?;
+
+        let signatures = self.database.parse(selector, &response.body)?;
+
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(selector, signatures.clone());
+
+        Ok(signatures)
+    }
+
+    pub async fn decode_input(
+        &self,
+        input: &str,
+    ) -> Result<Option<DecodedCall>, Box<dyn Error + Send + Sync>> {
+        let data = decode_hex(input)?;
+
+        let Some(selector) = data.get(..4).and_then(|s| <[u8; 4]>::try_from(s).ok()) else {
+            return Ok(None);
+        };
+
+        let signatures = self.lookup(selector).await?;
+
+        Ok(signatures
+            .iter()
+            .find_map(|signature| decode_signature(signature, selector, &data))
+            .or_else(|| {
+                signatures.first().map(|signature| DecodedCall {
+                    name: signature.split('(').next().unwrap_or(signature).to_string(),
+                    signature: signature.clone(),
+                    selector,
+                    params: Vec::new(),
+                    guessed: true,
+                })
+            }))
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn selector_lookup(&self, database: SignatureDatabase) -> SelectorLookup {
+        SelectorLookup::from_arc(self.transport_arc(), database)
+    }
+}
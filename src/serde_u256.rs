@@ -0,0 +1,121 @@
+pub mod decimal {
+    use {
+        ethabi::ethereum_types::U256,
+        serde::{de, Deserialize, Deserializer, Serializer},
+    };
+
+    pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let value = value.trim();
+
+        if value.is_empty() {
+            return Ok(U256::zero());
+        }
+
+        U256::from_dec_str(value).map_err(de::Error::custom)
+    }
+}
+
+pub mod gwei {
+    use {
+        ethabi::ethereum_types::U256,
+        serde::{de, Deserializer, Serializer},
+    };
+
+    const WEI_PER_GWEI: u64 = 1_000_000_000;
+
+    pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&(*value / WEI_PER_GWEI))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        super::decimal::deserialize(deserializer)?
+            .checked_mul(U256::from(WEI_PER_GWEI))
+            .ok_or_else(|| de::Error::custom("gwei amount overflows U256"))
+    }
+}
+
+pub mod hex {
+    use {
+        ethabi::ethereum_types::U256,
+        serde::{de, Deserialize, Deserializer, Serializer},
+    };
+
+    pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{value:#x}"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let value = value.trim();
+        let digits = value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+            .unwrap_or(value);
+
+        if digits.is_empty() {
+            return Ok(U256::zero());
+        }
+
+        U256::from_str_radix(digits, 16).map_err(de::Error::custom)
+    }
+}
+
+pub mod hex_opt {
+    use {
+        ethabi::ethereum_types::U256,
+        serde::{Deserialize, Deserializer, Serializer},
+    };
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<U256>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::hex::serialize(value, serializer),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<U256>, D::Error> {
+        let value = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+
+        if value.trim().is_empty() {
+            return Ok(None);
+        }
+
+        super::hex::deserialize(serde::de::value::StrDeserializer::new(value.as_str())).map(Some)
+    }
+}
+
+pub mod hex_vec {
+    use {
+        ethabi::ethereum_types::U256,
+        serde::{ser::SerializeSeq, Deserialize, Deserializer, Serializer},
+    };
+
+    pub fn serialize<S: Serializer>(values: &[U256], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+
+        for value in values {
+            seq.serialize_element(&format!("{value:#066x}"))?;
+        }
+
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<U256>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|value| {
+                super::hex::deserialize(serde::de::value::StrDeserializer::new(value.as_str()))
+            })
+            .collect()
+    }
+}
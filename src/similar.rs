@@ -0,0 +1,75 @@
+use {
+    crate::{is_unverified_error, keccak256, strip_metadata, ContractSource, EtherscanClient},
+    ethabi::ethereum_types::U256,
+    std::error::Error,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimilarContract {
+    pub address: U256,
+    pub source: ContractSource,
+}
+
+impl EtherscanClient {
+    async fn verified_source(
+        &self,
+        address: U256,
+    ) -> Result<Option<ContractSource>, Box<dyn Error + Send + Sync>> {
+        match self.contract_source(address).await {
+            Ok(source) if source.is_verified() => Ok(Some(source)),
+            Ok(_) => Ok(None),
+            Err(e) if is_unverified_error(&*e) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn similar_contracts(
+        &self,
+        address: U256,
+        candidates: Vec<U256>,
+    ) -> Result<Vec<SimilarContract>, Box<dyn Error + Send + Sync>> {
+        let mut similar = Vec::new();
+
+        let source = self.contract_source(address).await?;
+
+        if let Ok(similar_match) =
+            U256::from_str_radix(source.similar_match.trim().trim_start_matches("0x"), 16)
+        {
+            if !similar_match.is_zero() && similar_match != address {
+                if let Some(source) = self.verified_source(similar_match).await? {
+                    similar.push(SimilarContract {
+                        address: similar_match,
+                        source,
+                    });
+                }
+            }
+        }
+
+        let code = self.code(address).await?;
+
+        if code.is_empty() {
+            return Ok(similar);
+        }
+
+        let hash = keccak256(strip_metadata(&code));
+
+        for candidate in candidates {
+            if candidate == address || similar.iter().any(|x| x.address == candidate) {
+                continue;
+            }
+
+            if keccak256(strip_metadata(&self.code(candidate).await?)) != hash {
+                continue;
+            }
+
+            if let Some(source) = self.verified_source(candidate).await? {
+                similar.push(SimilarContract {
+                    address: candidate,
+                    source,
+                });
+            }
+        }
+
+        Ok(similar)
+    }
+}
@@ -0,0 +1,189 @@
+use {
+    crate::{transport::default_transport, HttpMethod, HttpRequest, Transport},
+    futures::{future::BoxFuture, Stream, StreamExt},
+    serde::Serialize,
+    std::{error::Error, fmt, sync::Arc},
+};
+
+pub trait NotificationSink<T>: Send + Sync {
+    fn send<'a>(&'a self, event: &'a T) -> BoxFuture<'a, Result<(), Box<dyn Error + Send + Sync>>>;
+}
+
+impl<T, F> NotificationSink<T> for F
+where
+    T: Sync,
+    F: Fn(&T) -> Result<(), Box<dyn Error + Send + Sync>> + Send + Sync,
+{
+    #[inline]
+    fn send<'a>(&'a self, event: &'a T) -> BoxFuture<'a, Result<(), Box<dyn Error + Send + Sync>>> {
+        Box::pin(futures::future::ready(self(event)))
+    }
+}
+
+impl<T: Clone + Send + Sync> NotificationSink<T> for futures::channel::mpsc::UnboundedSender<T> {
+    #[inline]
+    fn send<'a>(&'a self, event: &'a T) -> BoxFuture<'a, Result<(), Box<dyn Error + Send + Sync>>> {
+        Box::pin(async move {
+            self.unbounded_send(event.clone())
+                .map_err(|_| "notification channel closed".into())
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: Clone + Send + Sync> NotificationSink<T> for tokio::sync::mpsc::Sender<T> {
+    #[inline]
+    fn send<'a>(&'a self, event: &'a T) -> BoxFuture<'a, Result<(), Box<dyn Error + Send + Sync>>> {
+        Box::pin(async move {
+            tokio::sync::mpsc::Sender::send(self, event.clone())
+                .await
+                .map_err(|_| "notification channel closed".into())
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: Clone + Send + Sync> NotificationSink<T> for tokio::sync::mpsc::UnboundedSender<T> {
+    #[inline]
+    fn send<'a>(&'a self, event: &'a T) -> BoxFuture<'a, Result<(), Box<dyn Error + Send + Sync>>> {
+        Box::pin(async move {
+            tokio::sync::mpsc::UnboundedSender::send(self, event.clone())
+                .map_err(|_| "notification channel closed".into())
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: Clone + Send + Sync> NotificationSink<T> for tokio::sync::broadcast::Sender<T> {
+    #[inline]
+    fn send<'a>(&'a self, event: &'a T) -> BoxFuture<'a, Result<(), Box<dyn Error + Send + Sync>>> {
+        Box::pin(async move {
+            tokio::sync::broadcast::Sender::send(self, event.clone())
+                .map(|_| ())
+                .map_err(|_| "notification channel has no receivers".into())
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct WebhookSink {
+    url: String,
+    headers: Vec<(String, String)>,
+    transport: Arc<dyn Transport>,
+}
+
+impl fmt::Debug for WebhookSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookSink")
+            .field("url", &self.url)
+            .field("headers", &self.headers.len())
+            .finish()
+    }
+}
+
+impl WebhookSink {
+    #[inline]
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            headers: Vec::new(),
+            transport: default_transport(),
+        }
+    }
+
+    #[inline]
+    pub fn with_header(mut self, name: String, value: String) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    #[inline]
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl<T: Serialize + Sync> NotificationSink<T> for WebhookSink {
+    fn send<'a>(&'a self, event: &'a T) -> BoxFuture<'a, Result<(), Box<dyn Error + Send + Sync>>> {
+        Box::pin(async move {
+            let mut headers = vec![("Content-Type".to_string(), "application/json".to_string())];
+            headers.extend(self.headers.iter().cloned());
+
+            let response = self
+                .transport
+                .send(HttpRequest {
+                    method: HttpMethod::Post,
+                    url: self.url.clone(),
+                    headers,
+                    body: Some(serde_json::to_vec(event)?),
+                    correlation_id: None,
+                })
+                .await?;
+
+            match response.is_success() {
+                true => Ok(()),
+                false => Err(format!("webhook returned status {}", response.status).into()),
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct PublishReport {
+    pub published: u64,
+    pub errors: u64,
+}
+
+#[inline]
+pub async fn publish<T, S, K>(
+    events: S,
+    sink: &K,
+) -> Result<PublishReport, Box<dyn Error + Send + Sync>>
+where
+    S: Stream<Item = Result<T, Box<dyn Error + Send + Sync>>>,
+    K: NotificationSink<T> + ?Sized,
+{
+    publish_with(events, sink, |_e| {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(error = %_e, "skipping failed event");
+
+        Ok(())
+    })
+    .await
+}
+
+pub async fn publish_with<T, S, K, F>(
+    events: S,
+    sink: &K,
+    mut on_error: F,
+) -> Result<PublishReport, Box<dyn Error + Send + Sync>>
+where
+    S: Stream<Item = Result<T, Box<dyn Error + Send + Sync>>>,
+    K: NotificationSink<T> + ?Sized,
+    F: FnMut(Box<dyn Error + Send + Sync>) -> Result<(), Box<dyn Error + Send + Sync>>,
+{
+    futures::pin_mut!(events);
+
+    let mut report = PublishReport::default();
+
+    while let Some(event) = events.next().await {
+        match event {
+            Ok(event) => {
+                sink.send(&event).await?;
+                report.published += 1;
+            }
+            Err(e) => {
+                report.errors += 1;
+                on_error(e)?;
+            }
+        }
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,307 @@
+use {
+    crate::{is_unverified_error, EtherscanClient, EtherscanRequest},
+    ethabi::ethereum_types::U256,
+    semver::Version,
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::BTreeMap,
+        error::Error,
+        path::{Component, Path, PathBuf},
+    },
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ContractSource {
+    pub source_code: String,
+    #[serde(rename = "ABI")]
+    pub abi: String,
+    pub contract_name: String,
+    pub compiler_version: String,
+    pub optimization_used: String,
+    pub runs: String,
+    pub constructor_arguments: String,
+    #[serde(rename = "EVMVersion")]
+    pub evm_version: String,
+    pub library: String,
+    pub license_type: String,
+    pub proxy: String,
+    pub implementation: String,
+    pub swarm_source: String,
+    pub similar_match: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compiler {
+    Solidity,
+    Vyper,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CompilerSettings {
+    pub compiler: Compiler,
+    pub version: Version,
+    pub optimization_used: bool,
+    pub runs: Option<u64>,
+    pub evm_version: Option<String>,
+    pub license_type: Option<String>,
+    pub libraries: BTreeMap<String, U256>,
+}
+
+impl CompilerSettings {
+    pub fn parse_version(
+        version: &str,
+    ) -> Result<(Compiler, Version), Box<dyn Error + Send + Sync>> {
+        let trimmed = version.trim();
+
+        let (compiler, raw) = match trimmed.strip_prefix("vyper:") {
+            Some(raw) => (Compiler::Vyper, raw),
+            None => (Compiler::Solidity, trimmed),
+        };
+
+        let raw = raw.strip_prefix('v').unwrap_or(raw);
+
+        match Version::parse(raw) {
+            Ok(parsed) => Ok((compiler, parsed)),
+            Err(e) => Err(format!("invalid compiler version {version:?}: {e}").into()),
+        }
+    }
+
+    pub fn parse_libraries(
+        library: &str,
+    ) -> Result<BTreeMap<String, U256>, Box<dyn Error + Send + Sync>> {
+        let mut libraries = BTreeMap::new();
+
+        for entry in library.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            let (name, address) = entry
+                .rsplit_once(':')
+                .ok_or_else(|| format!("invalid library mapping {entry:?}"))?;
+
+            let address = address.trim();
+            let address = U256::from_str_radix(address.trim_start_matches("0x"), 16)
+                .map_err(|_| format!("invalid library address {address:?}"))?;
+
+            libraries.insert(name.trim().to_string(), address);
+        }
+
+        Ok(libraries)
+    }
+}
+
+#[derive(Deserialize)]
+struct SourceFile {
+    content: String,
+}
+
+#[derive(Deserialize)]
+struct StandardJsonInput {
+    #[serde(default)]
+    language: Option<String>,
+    sources: BTreeMap<String, SourceFile>,
+    #[serde(default)]
+    settings: Option<serde_json::Value>,
+}
+
+impl ContractSource {
+    #[inline]
+    pub fn is_verified(&self) -> bool {
+        !self.source_code.is_empty()
+    }
+
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        self.proxy == "1"
+    }
+
+    fn standard_json(&self) -> Option<StandardJsonInput> {
+        let source = self.source_code.trim();
+
+        if let Some(inner) = source.strip_prefix("{{").and_then(|s| s.strip_suffix("}}")) {
+            return serde_json::from_str(&format!("{{{inner}}}")).ok();
+        }
+
+        if source.starts_with('{') {
+            if let Ok(input) = serde_json::from_str::<StandardJsonInput>(source) {
+                return Some(input);
+            }
+
+            let sources = serde_json::from_str::<BTreeMap<String, SourceFile>>(source).ok()?;
+
+            return Some(StandardJsonInput {
+                language: None,
+                sources,
+                settings: None,
+            });
+        }
+
+        None
+    }
+
+    pub fn sources(&self) -> BTreeMap<String, String> {
+        match self.standard_json() {
+            Some(input) => input
+                .sources
+                .into_iter()
+                .map(|(path, file)| (path, file.content))
+                .collect(),
+            None => {
+                let extension = match self.compiler_version.starts_with("vyper") {
+                    true => "vy",
+                    false => "sol",
+                };
+
+                BTreeMap::from([(
+                    format!("{}.{extension}", self.contract_name),
+                    self.source_code.clone(),
+                )])
+            }
+        }
+    }
+
+    pub fn settings(&self) -> Option<serde_json::Value> {
+        self.standard_json().and_then(|input| input.settings)
+    }
+
+    pub fn language(&self) -> Option<String> {
+        self.standard_json().and_then(|input| input.language)
+    }
+
+    pub fn compiler_settings(&self) -> Result<CompilerSettings, Box<dyn Error + Send + Sync>> {
+        let (compiler, version) = CompilerSettings::parse_version(&self.compiler_version)?;
+
+        let non_empty = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty() && !value.eq_ignore_ascii_case("default")).then(|| value.to_string())
+        };
+
+        Ok(CompilerSettings {
+            compiler,
+            version,
+            optimization_used: self.optimization_used == "1",
+            runs: self.runs.trim().parse().ok(),
+            evm_version: non_empty(&self.evm_version),
+            license_type: non_empty(&self.license_type),
+            libraries: CompilerSettings::parse_libraries(&self.library)?,
+        })
+    }
+
+    pub fn metadata(&self) -> serde_json::Value {
+        serde_json::json!({
+            "contractName": self.contract_name,
+            "compilerVersion": self.compiler_version,
+            "language": self.language(),
+            "optimizationUsed": self.optimization_used == "1",
+            "runs": self.runs.parse::<u64>().ok(),
+            "evmVersion": self.evm_version,
+            "library": self.library,
+            "licenseType": self.license_type,
+            "constructorArguments": self.constructor_arguments,
+            "proxy": self.is_proxy(),
+            "implementation": self.implementation,
+            "settings": self.settings(),
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+pub(crate) async fn write_file(path: &Path, contents: Vec<u8>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    tokio::fs::write(path, contents).await
+}
+
+#[cfg(not(feature = "tokio"))]
+pub(crate) async fn write_file(path: &Path, contents: Vec<u8>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(path, contents)
+}
+
+fn safe_path(root: &Path, path: &str) -> Result<PathBuf, Box<dyn Error + Send + Sync>> {
+    let relative = Path::new(path);
+
+    if relative
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(format!("refusing to write source outside of output directory: {path}").into());
+    }
+
+    Ok(root.join(relative))
+}
+
+impl EtherscanClient {
+    pub async fn contract_source(
+        &self,
+        contract_address: U256,
+    ) -> Result<ContractSource, Box<dyn Error + Send + Sync>> {
+        let source = match self
+            .fetch::<Vec<ContractSource>>(EtherscanRequest::contract_get_source_code(
+                contract_address,
+            ))
+            .await
+        {
+            Ok(mut sources) => sources.pop(),
+            Err(e) if self.sourcify_url().is_some() && is_unverified_error(&*e) => None,
+            Err(e) => return Err(e),
+        };
+
+        if self.sourcify_url().is_some()
+            && !source.as_ref().is_some_and(ContractSource::is_verified)
+        {
+            if let Some(source) = self.sourcify_source(contract_address).await? {
+                return Ok(source);
+            }
+        }
+
+        source.ok_or_else(|| format!("no source code for {contract_address:#x}").into())
+    }
+
+    #[inline]
+    pub async fn compiler_settings(
+        &self,
+        contract_address: U256,
+    ) -> Result<CompilerSettings, Box<dyn Error + Send + Sync>> {
+        self.contract_source(contract_address)
+            .await?
+            .compiler_settings()
+    }
+
+    pub async fn download_sources<P: AsRef<Path>>(
+        &self,
+        contract_address: U256,
+        dir: P,
+    ) -> Result<Vec<PathBuf>, Box<dyn Error + Send + Sync>> {
+        let source = self.contract_source(contract_address).await?;
+
+        if !source.is_verified() {
+            return Err(format!("contract {contract_address:#x} is not verified").into());
+        }
+
+        let dir = dir.as_ref();
+        let mut written = Vec::new();
+
+        for (path, content) in source.sources() {
+            let path = safe_path(dir, &path)?;
+            write_file(&path, content.into_bytes()).await?;
+            written.push(path);
+        }
+
+        let metadata = dir.join("metadata.json");
+        write_file(&metadata, serde_json::to_vec_pretty(&source.metadata())?).await?;
+        written.push(metadata);
+
+        if !source.abi.is_empty() && source.abi.starts_with('[') {
+            let abi = dir.join("abi.json");
+            write_file(&abi, source.abi.clone().into_bytes()).await?;
+            written.push(abi);
+        }
+
+        Ok(written)
+    }
+}
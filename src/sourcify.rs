@@ -0,0 +1,175 @@
+use {
+    crate::{legacy_chainid, record::address, ContractSource, EtherscanClient, HttpRequest},
+    ethabi::ethereum_types::U256,
+    serde::Deserialize,
+    std::{collections::BTreeMap, error::Error},
+};
+
+pub const DEFAULT_SOURCIFY_URL: &str = "https://sourcify.dev/server";
+
+#[derive(Deserialize)]
+struct SourcifyFiles {
+    files: Vec<SourcifyFile>,
+}
+
+#[derive(Deserialize)]
+struct SourcifyFile {
+    name: String,
+    path: String,
+    content: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Metadata {
+    compiler: MetadataCompiler,
+    language: Option<String>,
+    output: MetadataOutput,
+    settings: serde_json::Map<String, serde_json::Value>,
+    sources: BTreeMap<String, MetadataSource>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct MetadataCompiler {
+    version: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct MetadataOutput {
+    abi: serde_json::Value,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct MetadataSource {
+    license: Option<String>,
+}
+
+fn source_path(path: &str) -> &str {
+    match path.split_once("/sources/") {
+        Some((_, relative)) => relative,
+        None => path.trim_start_matches('/'),
+    }
+}
+
+fn contract_source(
+    files: Vec<SourcifyFile>,
+) -> Result<ContractSource, Box<dyn Error + Send + Sync>> {
+    let (metadata, sources): (Vec<_>, Vec<_>) = files
+        .into_iter()
+        .partition(|file| file.name == "metadata.json");
+
+    let metadata = metadata
+        .into_iter()
+        .next()
+        .ok_or("sourcify response is missing metadata.json")?;
+    let mut metadata = serde_json::from_str::<Metadata>(&metadata.content)?;
+
+    let (target, contract_name) = metadata
+        .settings
+        .remove("compilationTarget")
+        .and_then(|target| serde_json::from_value::<BTreeMap<String, String>>(target).ok())
+        .and_then(|target| target.into_iter().next())
+        .unwrap_or_default();
+
+    let optimizer = metadata.settings.get("optimizer");
+    let optimization_used = optimizer
+        .and_then(|optimizer| optimizer["enabled"].as_bool())
+        .unwrap_or_default();
+    let runs = optimizer
+        .and_then(|optimizer| optimizer["runs"].as_u64())
+        .map(|runs| runs.to_string())
+        .unwrap_or_default();
+
+    let evm_version = metadata
+        .settings
+        .get("evmVersion")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    let library = metadata
+        .settings
+        .get("libraries")
+        .and_then(serde_json::Value::as_object)
+        .map(|libraries| {
+            libraries
+                .iter()
+                .filter_map(|(name, library)| {
+                    let name = name.rsplit(':').next().unwrap_or(name);
+                    Some(format!("{name}:{}", library.as_str()?))
+                })
+                .collect::<Vec<_>>()
+                .join(";")
+        })
+        .unwrap_or_default();
+
+    let license_type = metadata
+        .sources
+        .get(&target)
+        .and_then(|source| source.license.clone())
+        .unwrap_or_default();
+
+    let sources = sources
+        .into_iter()
+        .map(|file| {
+            (
+                source_path(&file.path).to_string(),
+                serde_json::json!({ "content": file.content }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    let source_code = serde_json::json!({
+        "language": metadata.language.as_deref().unwrap_or("Solidity"),
+        "sources": sources,
+        "settings": metadata.settings,
+    });
+
+    Ok(ContractSource {
+        source_code: source_code.to_string(),
+        abi: metadata.output.abi.to_string(),
+        contract_name,
+        compiler_version: format!("v{}", metadata.compiler.version.trim_start_matches('v')),
+        optimization_used: if optimization_used { "1" } else { "0" }.to_string(),
+        runs,
+        evm_version,
+        library,
+        license_type,
+        proxy: "0".to_string(),
+        ..Default::default()
+    })
+}
+
+impl EtherscanClient {
+    pub async fn sourcify_source(
+        &self,
+        contract_address: U256,
+    ) -> Result<Option<ContractSource>, Box<dyn Error + Send + Sync>> {
+        let url = self.sourcify_url().unwrap_or(DEFAULT_SOURCIFY_URL);
+        let chainid = self
+            .chainid()
+            .or_else(|| legacy_chainid(self.url()))
+            .unwrap_or(1);
+
+        let response = self
+            .transport()
+            .send(HttpRequest::get(format!(
+                "{}/files/any/{chainid}/{}",
+                url.trim_end_matches('/'),
+                address(contract_address),
+            )))
+            .await?;
+
+        if response.status == 404 {
+            return Ok(None);
+        }
+
+        let response = response.error_for_status()?;
+        let files = serde_json::from_slice::<SourcifyFiles>(&response.body)?;
+
+        contract_source(files.files).map(Some)
+    }
+}
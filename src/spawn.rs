@@ -0,0 +1,15 @@
+use futures::future::BoxFuture;
+
+pub trait Spawner: Send + Sync {
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+}
+
+impl<F> Spawner for F
+where
+    F: Fn(BoxFuture<'static, ()>) + Send + Sync,
+{
+    #[inline]
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        self(future)
+    }
+}
@@ -0,0 +1,218 @@
+use {
+    crate::{
+        record::{address, hash},
+        StoredRecord,
+    },
+    ethabi::ethereum_types::U256,
+    rusqlite::{params, Connection, OptionalExtension},
+    std::{error::Error, fmt, path::Path, sync::Mutex},
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transactions_block ON transactions (block_number);
+CREATE INDEX IF NOT EXISTS transactions_sender ON transactions (sender);
+CREATE INDEX IF NOT EXISTS transactions_recipient ON transactions (recipient);
+
+CREATE TABLE IF NOT EXISTS internal_transactions (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS internal_transactions_block ON internal_transactions (block_number);
+CREATE INDEX IF NOT EXISTS internal_transactions_sender ON internal_transactions (sender);
+CREATE INDEX IF NOT EXISTS internal_transactions_recipient ON internal_transactions (recipient);
+
+CREATE TABLE IF NOT EXISTS token_transfers (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS token_transfers_block ON token_transfers (block_number);
+CREATE INDEX IF NOT EXISTS token_transfers_sender ON token_transfers (sender);
+CREATE INDEX IF NOT EXISTS token_transfers_recipient ON token_transfers (recipient);
+CREATE INDEX IF NOT EXISTS token_transfers_contract ON token_transfers (contract_address);
+
+CREATE TABLE IF NOT EXISTS logs (
+    key TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    block_number INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    sender TEXT NOT NULL,
+    recipient TEXT,
+    contract_address TEXT,
+    value TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS logs_block ON logs (block_number);
+CREATE INDEX IF NOT EXISTS logs_contract ON logs (contract_address);
+";
+
+pub struct SqliteSink {
+    connection: Mutex<Connection>,
+}
+
+impl fmt::Debug for SqliteSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SqliteSink").finish_non_exhaustive()
+    }
+}
+
+impl SqliteSink {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    pub fn in_memory() -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    pub fn from_connection(connection: Connection) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        connection.execute_batch(SCHEMA)?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    pub fn insert<T: StoredRecord>(
+        &self,
+        records: &[T],
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let mut connection = self.connection.lock().unwrap();
+        let transaction = connection.transaction()?;
+        let mut inserted = 0;
+
+        {
+            let mut statement = transaction.prepare(&format!(
+                "INSERT INTO {} (key, hash, block_number, timestamp, sender, recipient, contract_address, value, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                 ON CONFLICT (key) DO UPDATE SET
+                    block_number = excluded.block_number,
+                    timestamp = excluded.timestamp,
+                    data = excluded.data",
+                T::TABLE
+            ))?;
+
+            for record in records {
+                inserted += statement.execute(params![
+                    record.key(),
+                    hash(record.hash()),
+                    record.block_number().low_u64() as i64,
+                    record.timestamp().low_u64() as i64,
+                    address(record.from()),
+                    record.to().map(address),
+                    record.contract_address().map(address),
+                    record.value(),
+                    serde_json::to_string(record)?,
+                ])?;
+            }
+        }
+
+        transaction.commit()?;
+        Ok(inserted)
+    }
+
+    pub fn by_hash<T: StoredRecord>(
+        &self,
+        tx_hash: U256,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        self.query(
+            &format!(
+                "SELECT data FROM {} WHERE hash = ?1 ORDER BY block_number",
+                T::TABLE
+            ),
+            params![hash(tx_hash)],
+        )
+    }
+
+    pub fn by_address<T: StoredRecord>(
+        &self,
+        address: U256,
+        from_block: Option<U256>,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        self.query(
+            &format!(
+                "SELECT data FROM {} WHERE (sender = ?1 OR recipient = ?1) AND block_number >= ?2 ORDER BY block_number",
+                T::TABLE
+            ),
+            params![
+                self::address(address),
+                from_block.unwrap_or_default().low_u64() as i64
+            ],
+        )
+    }
+
+    pub fn by_contract<T: StoredRecord>(
+        &self,
+        contract_address: U256,
+        from_block: Option<U256>,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        self.query(
+            &format!(
+                "SELECT data FROM {} WHERE contract_address = ?1 AND block_number >= ?2 ORDER BY block_number",
+                T::TABLE
+            ),
+            params![
+                address(contract_address),
+                from_block.unwrap_or_default().low_u64() as i64
+            ],
+        )
+    }
+
+    pub fn latest_block<T: StoredRecord>(
+        &self,
+    ) -> Result<Option<U256>, Box<dyn Error + Send + Sync>> {
+        let connection = self.connection.lock().unwrap();
+
+        let block = connection
+            .query_row(
+                &format!("SELECT MAX(block_number) FROM {}", T::TABLE),
+                [],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .optional()?
+            .flatten();
+
+        Ok(block.map(|block| U256::from(block as u64)))
+    }
+
+    fn query<T: StoredRecord>(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare(sql)?;
+
+        let rows = statement
+            .query_map(params, |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.iter()
+            .map(|data| Ok(serde_json::from_str(data)?))
+            .collect()
+    }
+}
@@ -0,0 +1,126 @@
+use {
+    crate::{EtherscanAction, EtherscanClient, EtherscanRequest, EtherscanSort},
+    chrono::{Days, NaiveDate},
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, error::Error},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DailySeries {
+    Price,
+    TxCount,
+    AvgGasPrice,
+    GasUsed,
+    TxnFee,
+}
+
+impl DailySeries {
+    #[inline]
+    pub fn action(self) -> EtherscanAction {
+        match self {
+            Self::Price => EtherscanAction::EthDailyPrice,
+            Self::TxCount => EtherscanAction::DailyTx,
+            Self::AvgGasPrice => EtherscanAction::DailyAvgGasPrice,
+            Self::GasUsed => EtherscanAction::DailyGasUsed,
+            Self::TxnFee => EtherscanAction::DailyTxnFee,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DailyStat {
+    #[serde(rename = "UTCDate")]
+    pub date: NaiveDate,
+    #[serde(rename = "unixTimeStamp", with = "crate::serde_u256::decimal")]
+    pub timestamp: U256,
+    #[serde(flatten)]
+    pub values: BTreeMap<String, serde_json::Value>,
+}
+
+impl DailyStat {
+    pub fn value(&self, name: &str) -> Option<f64> {
+        match self.values.get(name)? {
+            serde_json::Value::Number(value) => value.as_f64(),
+            serde_json::Value::String(value) => value.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StatsBackfill {
+    client: EtherscanClient,
+    window_days: u64,
+}
+
+impl StatsBackfill {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            window_days: 365,
+        }
+    }
+
+    #[inline]
+    pub fn with_window_days(mut self, window_days: u64) -> Self {
+        self.window_days = window_days.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn client(&self) -> &EtherscanClient {
+        &self.client
+    }
+
+    pub fn windows(&self, startdate: NaiveDate, enddate: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut windows = Vec::new();
+        let mut start = startdate;
+
+        while start <= enddate {
+            let end = start
+                .checked_add_days(Days::new(self.window_days - 1))
+                .unwrap_or(enddate)
+                .min(enddate);
+
+            windows.push((start, end));
+
+            match end.checked_add_days(Days::new(1)) {
+                Some(next) => start = next,
+                None => break,
+            }
+        }
+
+        windows
+    }
+
+    pub async fn fetch(
+        &self,
+        series: DailySeries,
+        startdate: NaiveDate,
+        enddate: NaiveDate,
+    ) -> Result<Vec<DailyStat>, Box<dyn Error + Send + Sync>> {
+        let requests = self
+            .windows(startdate, enddate)
+            .into_iter()
+            .map(|(start, end)| {
+                EtherscanRequest::stats_daily(series, start, end, Some(EtherscanSort::Ascending))
+            });
+
+        let mut series = BTreeMap::new();
+
+        for rows in self
+            .client
+            .batch(1)
+            .fetch::<Vec<DailyStat>, _>(requests)
+            .await
+        {
+            for row in rows? {
+                series.insert(row.date, row);
+            }
+        }
+
+        Ok(series.into_values().collect())
+    }
+}
@@ -0,0 +1,132 @@
+use {
+    crate::{EtherscanAction, EtherscanModule, EtherscanRequest, EtherscanSort, EtherscanTag},
+    ethabi::ethereum_types::U256,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RequestTemplate {
+    request: EtherscanRequest,
+}
+
+impl From<EtherscanRequest> for RequestTemplate {
+    #[inline]
+    fn from(request: EtherscanRequest) -> Self {
+        Self { request }
+    }
+}
+
+impl RequestTemplate {
+    #[inline]
+    pub fn new(module: EtherscanModule, action: EtherscanAction) -> Self {
+        Self {
+            request: EtherscanRequest {
+                module_action: Some((module, action)),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[inline]
+    pub fn with_chainid(mut self, chainid: u64) -> Self {
+        self.request.chainid = Some(chainid);
+        self
+    }
+
+    #[inline]
+    pub fn with_tag(mut self, tag: EtherscanTag) -> Self {
+        self.request.tag = Some(tag);
+        self
+    }
+
+    #[inline]
+    pub fn with_sort(mut self, sort: EtherscanSort) -> Self {
+        self.request.sort = Some(sort);
+        self
+    }
+
+    #[inline]
+    pub fn with_page(mut self, page: U256) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: U256) -> Self {
+        self.request.offset = Some(offset);
+        self
+    }
+
+    #[inline]
+    pub fn with_block_range(mut self, startblock: Option<U256>, endblock: Option<U256>) -> Self {
+        self.request.startblock = startblock;
+        self.request.endblock = endblock;
+        self
+    }
+
+    #[inline]
+    pub fn request(&self) -> &EtherscanRequest {
+        &self.request
+    }
+
+    #[inline]
+    pub fn build(&self) -> EtherscanRequest {
+        self.request.clone()
+    }
+
+    #[inline]
+    pub fn for_address(&self, address: U256) -> EtherscanRequest {
+        EtherscanRequest {
+            address: Some(vec![address]),
+            ..self.build()
+        }
+    }
+
+    #[inline]
+    pub fn for_addresses(&self, addresses: Vec<U256>) -> EtherscanRequest {
+        EtherscanRequest {
+            address: Some(addresses),
+            ..self.build()
+        }
+    }
+
+    #[inline]
+    pub fn for_contract(&self, contract_address: U256) -> EtherscanRequest {
+        EtherscanRequest {
+            contractaddress: Some(contract_address),
+            ..self.build()
+        }
+    }
+
+    #[inline]
+    pub fn for_transaction(&self, txhash: U256) -> EtherscanRequest {
+        EtherscanRequest {
+            txhash: Some(txhash),
+            ..self.build()
+        }
+    }
+
+    #[inline]
+    pub fn for_block(&self, blockno: U256) -> EtherscanRequest {
+        EtherscanRequest {
+            blockno: Some(blockno),
+            ..self.build()
+        }
+    }
+
+    #[inline]
+    pub fn for_block_range(&self, startblock: U256, endblock: U256) -> EtherscanRequest {
+        EtherscanRequest {
+            startblock: Some(startblock),
+            endblock: Some(endblock),
+            ..self.build()
+        }
+    }
+
+    #[inline]
+    pub fn for_page(&self, page: U256) -> EtherscanRequest {
+        EtherscanRequest {
+            page: Some(page),
+            ..self.build()
+        }
+    }
+}
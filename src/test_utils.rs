@@ -0,0 +1,507 @@
+use {
+    crate::{keccak256, parse_topic, InternalTransaction, Log, TokenTransfer, Transaction},
+    ethabi::ethereum_types::U256,
+};
+
+pub const DEFAULT_BLOCK_NUMBER: u64 = 18_000_000;
+pub const DEFAULT_TIMESTAMP: u64 = 1_693_526_400;
+pub const DEFAULT_GAS_PRICE: u64 = 20_000_000_000;
+pub use crate::TRANSFER_TOPIC;
+
+pub fn address(seed: u64) -> U256 {
+    U256::from_big_endian(&keccak256(&seed.to_be_bytes()).as_bytes()[12..])
+}
+
+pub fn hash(seed: &[u8]) -> U256 {
+    U256::from_big_endian(keccak256(seed).as_bytes())
+}
+
+fn transaction_hash(from: U256, nonce: U256, block_number: U256) -> U256 {
+    let mut seed = [0u8; 96];
+    from.to_big_endian(&mut seed[..32]);
+    nonce.to_big_endian(&mut seed[32..64]);
+    block_number.to_big_endian(&mut seed[64..]);
+    hash(&seed)
+}
+
+fn block_hash(block_number: U256) -> U256 {
+    let mut seed = [0u8; 32];
+    block_number.to_big_endian(&mut seed);
+    hash(&seed)
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionBuilder {
+    transaction: Transaction,
+}
+
+impl Default for TransactionBuilder {
+    fn default() -> Self {
+        Self {
+            transaction: Transaction {
+                block_number: U256::from(DEFAULT_BLOCK_NUMBER),
+                timestamp: U256::from(DEFAULT_TIMESTAMP),
+                hash: U256::zero(),
+                nonce: U256::zero(),
+                block_hash: U256::zero(),
+                transaction_index: U256::zero(),
+                from: address(1),
+                to: Some(address(2)),
+                value: U256::exp10(18),
+                gas: U256::from(21_000),
+                gas_price: U256::from(DEFAULT_GAS_PRICE),
+                is_error: "0".into(),
+                txreceipt_status: "1".into(),
+                input: "0x".into(),
+                contract_address: None,
+                cumulative_gas_used: U256::from(21_000),
+                gas_used: U256::from(21_000),
+                confirmations: U256::one(),
+                method_id: "0x".into(),
+                function_name: String::new(),
+            },
+        }
+    }
+}
+
+impl TransactionBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_block_number(mut self, block_number: u64) -> Self {
+        self.transaction.block_number = U256::from(block_number);
+        self
+    }
+
+    #[inline]
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.transaction.timestamp = U256::from(timestamp);
+        self
+    }
+
+    #[inline]
+    pub fn with_hash(mut self, hash: U256) -> Self {
+        self.transaction.hash = hash;
+        self
+    }
+
+    #[inline]
+    pub fn with_nonce(mut self, nonce: u64) -> Self {
+        self.transaction.nonce = U256::from(nonce);
+        self
+    }
+
+    #[inline]
+    pub fn with_from(mut self, from: U256) -> Self {
+        self.transaction.from = from;
+        self
+    }
+
+    #[inline]
+    pub fn with_to(mut self, to: Option<U256>) -> Self {
+        self.transaction.to = to;
+        self
+    }
+
+    #[inline]
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.transaction.value = value;
+        self
+    }
+
+    #[inline]
+    pub fn with_gas(mut self, gas: u64, gas_used: u64) -> Self {
+        self.transaction.gas = U256::from(gas);
+        self.transaction.gas_used = U256::from(gas_used);
+        self.transaction.cumulative_gas_used = U256::from(gas_used);
+        self
+    }
+
+    #[inline]
+    pub fn with_gas_price(mut self, gas_price: U256) -> Self {
+        self.transaction.gas_price = gas_price;
+        self
+    }
+
+    #[inline]
+    pub fn with_input(mut self, input: &str) -> Self {
+        self.transaction.method_id = input.get(..10).unwrap_or("0x").to_string();
+        self.transaction.input = input.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn with_function_name(mut self, function_name: &str) -> Self {
+        self.transaction.function_name = function_name.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn with_error(mut self, failed: bool) -> Self {
+        self.transaction.is_error = if failed { "1" } else { "0" }.into();
+        self.transaction.txreceipt_status = if failed { "0" } else { "1" }.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_contract_creation(mut self, contract_address: U256) -> Self {
+        self.transaction.to = None;
+        self.transaction.contract_address = Some(contract_address);
+        self
+    }
+
+    #[inline]
+    pub fn with_confirmations(mut self, confirmations: u64) -> Self {
+        self.transaction.confirmations = U256::from(confirmations);
+        self
+    }
+
+    pub fn build(self) -> Transaction {
+        let mut transaction = self.transaction;
+
+        if transaction.hash.is_zero() {
+            transaction.hash = transaction_hash(
+                transaction.from,
+                transaction.nonce,
+                transaction.block_number,
+            );
+        }
+
+        if transaction.block_hash.is_zero() {
+            transaction.block_hash = block_hash(transaction.block_number);
+        }
+
+        transaction
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InternalTransactionBuilder {
+    transaction: InternalTransaction,
+}
+
+impl Default for InternalTransactionBuilder {
+    fn default() -> Self {
+        Self {
+            transaction: InternalTransaction {
+                block_number: U256::from(DEFAULT_BLOCK_NUMBER),
+                timestamp: U256::from(DEFAULT_TIMESTAMP),
+                hash: U256::zero(),
+                from: address(2),
+                to: Some(address(1)),
+                value: U256::exp10(17),
+                contract_address: None,
+                input: String::new(),
+                kind: "call".into(),
+                gas: U256::from(2_300),
+                gas_used: U256::zero(),
+                trace_id: "0".into(),
+                is_error: "0".into(),
+                err_code: String::new(),
+            },
+        }
+    }
+}
+
+impl InternalTransactionBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_block_number(mut self, block_number: u64) -> Self {
+        self.transaction.block_number = U256::from(block_number);
+        self
+    }
+
+    #[inline]
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.transaction.timestamp = U256::from(timestamp);
+        self
+    }
+
+    #[inline]
+    pub fn with_hash(mut self, hash: U256) -> Self {
+        self.transaction.hash = hash;
+        self
+    }
+
+    #[inline]
+    pub fn with_from(mut self, from: U256) -> Self {
+        self.transaction.from = from;
+        self
+    }
+
+    #[inline]
+    pub fn with_to(mut self, to: Option<U256>) -> Self {
+        self.transaction.to = to;
+        self
+    }
+
+    #[inline]
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.transaction.value = value;
+        self
+    }
+
+    #[inline]
+    pub fn with_kind(mut self, kind: &str) -> Self {
+        self.transaction.kind = kind.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn with_trace_id(mut self, trace_id: &str) -> Self {
+        self.transaction.trace_id = trace_id.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn with_error(mut self, err_code: Option<&str>) -> Self {
+        self.transaction.is_error = if err_code.is_some() { "1" } else { "0" }.into();
+        self.transaction.err_code = err_code.unwrap_or_default().to_string();
+        self
+    }
+
+    pub fn build(self) -> InternalTransaction {
+        let mut transaction = self.transaction;
+
+        if transaction.hash.is_zero() {
+            transaction.hash =
+                transaction_hash(transaction.from, U256::zero(), transaction.block_number);
+        }
+
+        transaction
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenTransferBuilder {
+    transfer: TokenTransfer,
+}
+
+impl Default for TokenTransferBuilder {
+    fn default() -> Self {
+        Self {
+            transfer: TokenTransfer {
+                block_number: U256::from(DEFAULT_BLOCK_NUMBER),
+                timestamp: U256::from(DEFAULT_TIMESTAMP),
+                hash: U256::zero(),
+                nonce: U256::zero(),
+                block_hash: U256::zero(),
+                from: address(1),
+                contract_address: address(100),
+                to: Some(address(2)),
+                value: U256::from(1_000_000),
+                token_id: None,
+                token_value: None,
+                token_name: "USD Coin".into(),
+                token_symbol: "USDC".into(),
+                token_decimal: "6".into(),
+                transaction_index: U256::zero(),
+                gas: U256::from(65_000),
+                gas_price: U256::from(DEFAULT_GAS_PRICE),
+                gas_used: U256::from(51_000),
+                cumulative_gas_used: U256::from(51_000),
+                input: "deprecated".into(),
+                confirmations: U256::one(),
+            },
+        }
+    }
+}
+
+impl TokenTransferBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn erc721(token_id: u64) -> Self {
+        let mut builder = Self::default().with_token("Test Collection", "TEST", 0);
+        builder.transfer.value = U256::zero();
+        builder.transfer.token_id = Some(token_id.to_string());
+        builder
+    }
+
+    #[inline]
+    pub fn erc1155(token_id: u64, token_value: u64) -> Self {
+        let mut builder = Self::erc721(token_id);
+        builder.transfer.token_value = Some(token_value.to_string());
+        builder
+    }
+
+    #[inline]
+    pub fn with_block_number(mut self, block_number: u64) -> Self {
+        self.transfer.block_number = U256::from(block_number);
+        self
+    }
+
+    #[inline]
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.transfer.timestamp = U256::from(timestamp);
+        self
+    }
+
+    #[inline]
+    pub fn with_hash(mut self, hash: U256) -> Self {
+        self.transfer.hash = hash;
+        self
+    }
+
+    #[inline]
+    pub fn with_nonce(mut self, nonce: u64) -> Self {
+        self.transfer.nonce = U256::from(nonce);
+        self
+    }
+
+    #[inline]
+    pub fn with_from(mut self, from: U256) -> Self {
+        self.transfer.from = from;
+        self
+    }
+
+    #[inline]
+    pub fn with_to(mut self, to: Option<U256>) -> Self {
+        self.transfer.to = to;
+        self
+    }
+
+    #[inline]
+    pub fn with_contract_address(mut self, contract_address: U256) -> Self {
+        self.transfer.contract_address = contract_address;
+        self
+    }
+
+    #[inline]
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.transfer.value = value;
+        self
+    }
+
+    #[inline]
+    pub fn with_token(mut self, name: &str, symbol: &str, decimals: u8) -> Self {
+        self.transfer.token_name = name.to_string();
+        self.transfer.token_symbol = symbol.to_string();
+        self.transfer.token_decimal = decimals.to_string();
+        self
+    }
+
+    pub fn build(self) -> TokenTransfer {
+        let mut transfer = self.transfer;
+
+        if transfer.hash.is_zero() {
+            transfer.hash = transaction_hash(transfer.from, transfer.nonce, transfer.block_number);
+        }
+
+        if transfer.block_hash.is_zero() {
+            transfer.block_hash = block_hash(transfer.block_number);
+        }
+
+        transfer
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LogBuilder {
+    log: Log,
+}
+
+impl Default for LogBuilder {
+    fn default() -> Self {
+        Self {
+            log: Log {
+                address: address(100),
+                topics: Vec::new(),
+                data: "0x".into(),
+                block_number: Some(U256::from(DEFAULT_BLOCK_NUMBER)),
+                block_hash: None,
+                timestamp: Some(U256::from(DEFAULT_TIMESTAMP)),
+                transaction_hash: None,
+                transaction_index: Some(U256::zero()),
+                log_index: Some(U256::zero()),
+            },
+        }
+    }
+}
+
+impl LogBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn transfer(from: U256, to: U256, amount: U256) -> Self {
+        let mut data = [0u8; 32];
+        amount.to_big_endian(&mut data);
+
+        Self::default()
+            .with_topic(parse_topic(TRANSFER_TOPIC))
+            .with_topic(from)
+            .with_topic(to)
+            .with_data(&data)
+    }
+
+    #[inline]
+    pub fn with_address(mut self, address: U256) -> Self {
+        self.log.address = address;
+        self
+    }
+
+    #[inline]
+    pub fn with_topic(mut self, topic: U256) -> Self {
+        self.log.topics.push(topic);
+        self
+    }
+
+    #[inline]
+    pub fn with_data(mut self, data: &[u8]) -> Self {
+        self.log.data = format!(
+            "0x{}",
+            data.iter().map(|b| format!("{b:02x}")).collect::<String>()
+        );
+        self
+    }
+
+    #[inline]
+    pub fn with_block_number(mut self, block_number: u64) -> Self {
+        self.log.block_number = Some(U256::from(block_number));
+        self
+    }
+
+    #[inline]
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.log.timestamp = Some(U256::from(timestamp));
+        self
+    }
+
+    #[inline]
+    pub fn with_transaction_hash(mut self, transaction_hash: U256) -> Self {
+        self.log.transaction_hash = Some(transaction_hash);
+        self
+    }
+
+    #[inline]
+    pub fn with_log_index(mut self, log_index: u64) -> Self {
+        self.log.log_index = Some(U256::from(log_index));
+        self
+    }
+
+    pub fn build(self) -> Log {
+        let mut log = self.log;
+        let block_number = log.block_number.unwrap_or_default();
+
+        log.block_hash = log.block_hash.or(Some(block_hash(block_number)));
+        log.transaction_hash = log.transaction_hash.or(Some(transaction_hash(
+            log.address,
+            U256::zero(),
+            block_number,
+        )));
+
+        log
+    }
+}
@@ -0,0 +1,238 @@
+use {
+    crate::{EtherscanClient, EtherscanError},
+    ethabi::{ethereum_types::U256, ParamType, Token},
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        error::Error,
+        sync::{Arc, Mutex},
+    },
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct TokenMetadata {
+    pub contract_address: U256,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: Option<u32>,
+    pub total_supply: U256,
+}
+
+impl TokenMetadata {
+    pub fn format_amount(&self, amount: U256) -> String {
+        let decimals = match self.decimals {
+            Some(decimals) if decimals > 0 => decimals as usize,
+            _ => return amount.to_string(),
+        };
+
+        let digits = format!("{amount:0>width$}", width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        match fraction.is_empty() {
+            true => whole.to_string(),
+            false => format!("{whole}.{fraction}"),
+        }
+    }
+}
+
+pub(crate) fn is_unavailable(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    if error.downcast_ref::<ethabi::Error>().is_some() {
+        return true;
+    }
+
+    match EtherscanError::find(error) {
+        Some(EtherscanError::Api { message, result }) => {
+            let text = format!("{message} {result}").to_lowercase();
+            !text.contains("rate limit") && !text.contains("api key")
+        }
+        Some(EtherscanError::Rpc { .. }) => true,
+        _ => false,
+    }
+}
+
+fn optional(
+    result: Result<Token, Box<dyn Error + Send + Sync>>,
+) -> Result<Option<Token>, Box<dyn Error + Send + Sync>> {
+    match result {
+        Ok(token) => Ok(Some(token)),
+        Err(e) if is_unavailable(e.as_ref()) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenRegistry {
+    client: EtherscanClient,
+    token_info: bool,
+    concurrency: usize,
+    tokens: Arc<Mutex<HashMap<U256, TokenMetadata>>>,
+}
+
+impl TokenRegistry {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            token_info: true,
+            concurrency: 4,
+            tokens: Arc::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_token_info(mut self, token_info: bool) -> Self {
+        self.token_info = token_info;
+        self
+    }
+
+    #[inline]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn insert(&self, metadata: TokenMetadata) {
+        self.tokens
+            .lock()
+            .unwrap()
+            .insert(metadata.contract_address, metadata);
+    }
+
+    #[inline]
+    pub fn cached(&self, contract_address: U256) -> Option<TokenMetadata> {
+        self.tokens.lock().unwrap().get(&contract_address).cloned()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tokens.lock().unwrap().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub async fn get(
+        &self,
+        contract_address: U256,
+    ) -> Result<TokenMetadata, Box<dyn Error + Send + Sync>> {
+        if let Some(metadata) = self.cached(contract_address) {
+            return Ok(metadata);
+        }
+
+        let info = match self.token_info {
+            true => match self.client.token_info(contract_address).await {
+                Ok(info) => Some(info),
+                Err(e) if is_unavailable(e.as_ref()) => None,
+                Err(e) => return Err(e),
+            },
+            false => None,
+        };
+
+        let metadata = match info {
+            Some(info) if info.decimals().is_some() => TokenMetadata {
+                contract_address,
+                decimals: info.decimals(),
+                name: info.token_name,
+                symbol: info.symbol,
+                total_supply: info.total_supply,
+            },
+            _ => self.fetch_onchain(contract_address).await?,
+        };
+
+        self.insert(metadata.clone());
+        Ok(metadata)
+    }
+
+    pub async fn resolve(
+        &self,
+        contract_addresses: Vec<U256>,
+    ) -> Result<Vec<TokenMetadata>, Box<dyn Error + Send + Sync>> {
+        self.client
+            .batch(self.concurrency)
+            .run(contract_addresses, |_, contract_address| {
+                self.get(contract_address)
+            })
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    pub async fn format_amount(
+        &self,
+        contract_address: U256,
+        amount: U256,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(self.get(contract_address).await?.format_amount(amount))
+    }
+
+    async fn fetch_onchain(
+        &self,
+        contract_address: U256,
+    ) -> Result<TokenMetadata, Box<dyn Error + Send + Sync>> {
+        let name = optional(self.read(contract_address, "name", ParamType::String).await)?;
+        let symbol = optional(
+            self.read(contract_address, "symbol", ParamType::String)
+                .await,
+        )?;
+        let decimals = optional(
+            self.read(contract_address, "decimals", ParamType::Uint(8))
+                .await,
+        )?;
+
+        let total_supply = match optional(
+            self.read(contract_address, "totalSupply", ParamType::Uint(256))
+                .await,
+        )? {
+            Some(Token::Uint(total_supply)) => total_supply,
+            _ => match self.client.token_supply(contract_address).await {
+                Ok(total_supply) => total_supply,
+                Err(e) if is_unavailable(e.as_ref()) => U256::zero(),
+                Err(e) => return Err(e),
+            },
+        };
+
+        Ok(TokenMetadata {
+            contract_address,
+            name: match name {
+                Some(Token::String(name)) => name,
+                _ => String::new(),
+            },
+            symbol: match symbol {
+                Some(Token::String(symbol)) => symbol,
+                _ => String::new(),
+            },
+            decimals: match decimals {
+                Some(Token::Uint(decimals)) if decimals <= U256::from(u8::MAX) => {
+                    Some(decimals.low_u32())
+                }
+                _ => None,
+            },
+            total_supply,
+        })
+    }
+
+    async fn read(
+        &self,
+        contract_address: U256,
+        function: &str,
+        output: ParamType,
+    ) -> Result<Token, Box<dyn Error + Send + Sync>> {
+        let data = ethabi::short_signature(function, &[]);
+        let result = self.client.call(contract_address, &data).await?;
+
+        ethabi::decode(&[output], &result)?
+            .pop()
+            .ok_or_else(|| format!("unexpected {function} return data").into())
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn token_registry(&self) -> TokenRegistry {
+        TokenRegistry::new(self.clone())
+    }
+}
@@ -0,0 +1,68 @@
+use {crate::keccak256, ethabi::ethereum_types::U256};
+
+pub const TRANSFER_TOPIC: &str =
+    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+pub const APPROVAL_TOPIC: &str =
+    "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+
+pub const APPROVAL_FOR_ALL_TOPIC: &str =
+    "0x17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31";
+
+pub const TRANSFER_SINGLE_TOPIC: &str =
+    "0xc3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62";
+
+pub const TRANSFER_BATCH_TOPIC: &str =
+    "0x4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb";
+
+pub fn event_topic(signature: &str) -> U256 {
+    let signature = signature
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+
+    U256::from_big_endian(keccak256(signature.as_bytes()).as_bytes())
+}
+
+#[inline]
+pub fn parse_topic(topic: &str) -> U256 {
+    U256::from_str_radix(topic.trim_start_matches("0x"), 16).unwrap_or_default()
+}
+
+#[inline]
+pub fn transfer_topics(from: Option<U256>, to: Option<U256>) -> [Option<U256>; 4] {
+    [Some(parse_topic(TRANSFER_TOPIC)), from, to, None]
+}
+
+#[inline]
+pub fn approval_topics(owner: Option<U256>, spender: Option<U256>) -> [Option<U256>; 4] {
+    [Some(parse_topic(APPROVAL_TOPIC)), owner, spender, None]
+}
+
+#[inline]
+pub fn approval_for_all_topics(owner: Option<U256>, operator: Option<U256>) -> [Option<U256>; 4] {
+    [
+        Some(parse_topic(APPROVAL_FOR_ALL_TOPIC)),
+        owner,
+        operator,
+        None,
+    ]
+}
+
+#[inline]
+pub fn transfer_single_topics(
+    operator: Option<U256>,
+    from: Option<U256>,
+    to: Option<U256>,
+) -> [Option<U256>; 4] {
+    [Some(parse_topic(TRANSFER_SINGLE_TOPIC)), operator, from, to]
+}
+
+#[inline]
+pub fn transfer_batch_topics(
+    operator: Option<U256>,
+    from: Option<U256>,
+    to: Option<U256>,
+) -> [Option<U256>; 4] {
+    [Some(parse_topic(TRANSFER_BATCH_TOPIC)), operator, from, to]
+}
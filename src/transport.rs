@@ -0,0 +1,460 @@
+use {
+    crate::{redact_url, EtherscanError},
+    futures::future::BoxFuture,
+    std::{error::Error, fmt, sync::Arc, time::Duration},
+};
+
+const ERROR_BODY_SNIPPET: usize = 512;
+
+const ERROR_HEADERS: &[&str] = &[
+    "cf-mitigated",
+    "cf-ray",
+    "content-type",
+    "retry-after",
+    "server",
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+    "x-ratelimit-reset",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HttpMethod {
+    #[default]
+    Get,
+    Post,
+}
+
+#[derive(Clone, Default)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+    pub correlation_id: Option<String>,
+}
+
+impl fmt::Debug for HttpRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpRequest")
+            .field("method", &self.method)
+            .field("url", &redact_url(&self.url))
+            .field("headers", &self.headers)
+            .field(
+                "body",
+                &self
+                    .body
+                    .as_ref()
+                    .map(|body| format!("<{} bytes>", body.len())),
+            )
+            .field("correlation_id", &self.correlation_id)
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    #[inline]
+    pub fn get(url: String) -> Self {
+        Self {
+            url,
+            ..Default::default()
+        }
+    }
+}
+
+impl HttpResponse {
+    #[inline]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn error_for_status(self) -> Result<Self, EtherscanError> {
+        if self.is_success() {
+            return Ok(self);
+        }
+
+        let body = redact_url(&String::from_utf8_lossy(&self.body));
+
+        Err(EtherscanError::Http {
+            status: self.status,
+            body: match body.char_indices().nth(ERROR_BODY_SNIPPET) {
+                Some((index, _)) => body[..index].to_string(),
+                None => body,
+            },
+            headers: self
+                .headers
+                .into_iter()
+                .filter(|(name, _)| {
+                    ERROR_HEADERS
+                        .iter()
+                        .any(|header| name.eq_ignore_ascii_case(header))
+                })
+                .collect(),
+        })
+    }
+
+    #[inline]
+    pub fn text(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(&self.body)
+    }
+}
+
+pub trait Transport: Send + Sync {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>>;
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    #[inline]
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>> {
+        (**self).send(request)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Compression {
+    pub gzip: bool,
+    pub brotli: bool,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self {
+            gzip: true,
+            brotli: true,
+        }
+    }
+}
+
+impl Compression {
+    #[inline]
+    pub fn none() -> Self {
+        Self {
+            gzip: false,
+            brotli: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HttpVersion {
+    #[default]
+    Auto,
+    Http1,
+    /// Prefers HTTP/2 but lets TLS ALPN fall back to HTTP/1.1. With reqwest
+    /// this needs the `http2` feature for ALPN support.
+    Http2,
+    /// Speaks HTTP/2 without negotiation; only for servers known to accept it.
+    Http2PriorKnowledge,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionOptions {
+    pub compression: Compression,
+    pub http_version: HttpVersion,
+    pub pool_idle_timeout: Option<Duration>,
+    pub pool_max_idle_per_host: Option<usize>,
+    pub tcp_keepalive: Option<Duration>,
+    pub http2_keep_alive_interval: Option<Duration>,
+}
+
+impl ConnectionOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    #[inline]
+    pub fn with_http_version(mut self, http_version: HttpVersion) -> Self {
+        self.http_version = http_version;
+        self
+    }
+
+    #[inline]
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    #[inline]
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    #[inline]
+    pub fn with_tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    #[inline]
+    pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+}
+
+#[cfg(feature = "reqwest")]
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+    #[inline]
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    #[inline]
+    pub fn with_compression(
+        compression: Compression,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::with_options(&ConnectionOptions::new().with_compression(compression))
+    }
+
+    pub fn with_options(options: &ConnectionOptions) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder();
+
+        #[cfg(feature = "gzip")]
+        {
+            builder = builder.gzip(options.compression.gzip);
+        }
+
+        #[cfg(feature = "brotli")]
+        {
+            builder = builder.brotli(options.compression.brotli);
+        }
+
+        builder = match options.http_version {
+            HttpVersion::Auto => builder,
+            #[cfg(feature = "http2")]
+            HttpVersion::Http2 => builder,
+            #[cfg(not(feature = "http2"))]
+            HttpVersion::Http2 => {
+                return Err("HttpVersion::Http2 requires the http2 feature for ALPN".into())
+            }
+            HttpVersion::Http1 => builder.http1_only(),
+            HttpVersion::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+        };
+
+        if let Some(timeout) = options.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        if let Some(max) = options.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+
+        if let Some(interval) = options.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+
+        if let Some(interval) = options.http2_keep_alive_interval {
+            builder = builder.http2_keep_alive_interval(interval);
+        }
+
+        Ok(Self::new(builder.build()?))
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Transport for ReqwestTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>> {
+        Box::pin(async move {
+            let mut builder = match request.method {
+                HttpMethod::Get => self.client.get(&request.url),
+                HttpMethod::Post => self.client.post(&request.url),
+            };
+
+            for (name, value) in request.headers {
+                builder = builder.header(name, value);
+            }
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(feature = "isahc")]
+#[derive(Clone, Debug)]
+pub struct IsahcTransport {
+    client: isahc::HttpClient,
+}
+
+#[cfg(feature = "isahc")]
+impl IsahcTransport {
+    #[inline]
+    pub fn new(client: isahc::HttpClient) -> Self {
+        Self { client }
+    }
+
+    #[inline]
+    pub fn with_compression(
+        compression: Compression,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::with_options(&ConnectionOptions::new().with_compression(compression))
+    }
+
+    pub fn with_options(options: &ConnectionOptions) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        use isahc::config::{Configurable, VersionNegotiation};
+
+        let mut builder = isahc::HttpClient::builder()
+            .automatic_decompression(options.compression.gzip || options.compression.brotli)
+            .version_negotiation(match options.http_version {
+                HttpVersion::Auto | HttpVersion::Http2 => VersionNegotiation::latest_compatible(),
+                HttpVersion::Http1 => VersionNegotiation::http11(),
+                HttpVersion::Http2PriorKnowledge => VersionNegotiation::http2(),
+            });
+
+        if options.pool_max_idle_per_host.is_some() {
+            return Err("isahc does not support a per-host idle connection limit".into());
+        }
+
+        if options.http2_keep_alive_interval.is_some() {
+            return Err("isahc does not support an HTTP/2 keep-alive interval".into());
+        }
+
+        if let Some(timeout) = options.pool_idle_timeout {
+            builder = builder.connection_cache_ttl(timeout);
+        }
+
+        if let Some(interval) = options.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+
+        Ok(Self::new(builder.build()?))
+    }
+}
+
+#[cfg(feature = "isahc")]
+impl Transport for IsahcTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>> {
+        use isahc::AsyncReadResponseExt;
+
+        Box::pin(async move {
+            let mut builder =
+                isahc::Request::builder()
+                    .uri(&request.url)
+                    .method(match request.method {
+                        HttpMethod::Get => "GET",
+                        HttpMethod::Post => "POST",
+                    });
+
+            for (name, value) in request.headers {
+                builder = builder.header(name, value);
+            }
+
+            let mut response = self
+                .client
+                .send_async(builder.body(request.body.unwrap_or_default())?)
+                .await?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            let body = response.bytes().await?;
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+struct UnconfiguredTransport;
+
+impl Transport for UnconfiguredTransport {
+    fn send(
+        &self,
+        _: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>> {
+        Box::pin(async {
+            Err("no HTTP transport configured; enable the `reqwest` or `isahc` feature or supply one with `with_transport`".into())
+        })
+    }
+}
+
+#[allow(unreachable_code, unused_variables)]
+pub fn connection_transport(
+    options: &ConnectionOptions,
+) -> Result<Arc<dyn Transport>, Box<dyn Error + Send + Sync>> {
+    #[cfg(feature = "reqwest")]
+    return Ok(Arc::new(ReqwestTransport::with_options(options)?));
+
+    #[cfg(feature = "isahc")]
+    return Ok(Arc::new(IsahcTransport::with_options(options)?));
+
+    Err("connection options require the `reqwest` or `isahc` feature".into())
+}
+
+#[allow(unreachable_code)]
+pub fn default_transport() -> Arc<dyn Transport> {
+    #[cfg(feature = "reqwest")]
+    return Arc::new(ReqwestTransport::default());
+
+    #[cfg(feature = "isahc")]
+    if let Ok(client) = isahc::HttpClient::new() {
+        return Arc::new(IsahcTransport::new(client));
+    }
+
+    Arc::new(UnconfiguredTransport)
+}
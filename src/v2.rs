@@ -0,0 +1,67 @@
+use crate::{EtherscanResponse, HttpResponse};
+
+const LEGACY_HOSTS: &[(&str, u64)] = &[
+    ("api.etherscan.io", 1),
+    ("api-sepolia.etherscan.io", 11155111),
+    ("api-holesky.etherscan.io", 17000),
+    ("api.bscscan.com", 56),
+    ("api-testnet.bscscan.com", 97),
+    ("api.polygonscan.com", 137),
+    ("api-amoy.polygonscan.com", 80002),
+    ("api.arbiscan.io", 42161),
+    ("api-sepolia.arbiscan.io", 421614),
+    ("api-nova.arbiscan.io", 42170),
+    ("api-optimistic.etherscan.io", 10),
+    ("api-sepolia-optimistic.etherscan.io", 11155420),
+    ("api.basescan.org", 8453),
+    ("api-sepolia.basescan.org", 84532),
+    ("api.lineascan.build", 59144),
+    ("api.scrollscan.com", 534352),
+    ("api.snowscan.xyz", 43114),
+    ("api.ftmscan.com", 250),
+    ("api.gnosisscan.io", 100),
+    ("api.celoscan.io", 42220),
+    ("api.moonscan.io", 1284),
+    ("api-moonriver.moonscan.io", 1285),
+    ("api.blastscan.io", 81457),
+    ("api-zkevm.polygonscan.com", 1101),
+    ("api-era.zksync.network", 324),
+];
+
+pub fn legacy_chainid(url: &str) -> Option<u64> {
+    let host = url.split("://").nth(1).unwrap_or(url);
+    let host = host.split(['/', '?', ':']).next()?;
+
+    LEGACY_HOSTS
+        .iter()
+        .find(|(legacy, _)| legacy.eq_ignore_ascii_case(host))
+        .map(|(_, chainid)| *chainid)
+}
+
+pub fn is_v2_url(url: &str) -> bool {
+    url.split('?').next().unwrap_or(url).contains("/v2/")
+}
+
+pub fn is_v1_deprecated(response: &HttpResponse) -> bool {
+    if response.status == 404 || response.status == 410 {
+        return true;
+    }
+
+    let Ok(response) =
+        serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body)
+    else {
+        return false;
+    };
+
+    if response.status == "1" {
+        return false;
+    }
+
+    let deprecated = |text: &str| {
+        let text = text.to_lowercase();
+        text.contains("deprecated") || text.contains("v2-migration")
+    };
+
+    deprecated(&response.message)
+        || matches!(&response.result, serde_json::Value::String(result) if deprecated(result))
+}
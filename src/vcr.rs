@@ -0,0 +1,186 @@
+use {
+    crate::{
+        keccak256, redact_url, request_params, HttpMethod, HttpRequest, HttpResponse, Transport,
+    },
+    futures::future::BoxFuture,
+    serde::{Deserialize, Serialize},
+    std::{
+        error::Error,
+        fs,
+        path::{Path, PathBuf},
+        sync::Arc,
+    },
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Fixture {
+    method: String,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_body: Option<String>,
+    status: u16,
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    raw_body: Option<String>,
+}
+
+impl Fixture {
+    fn new(request: &HttpRequest, response: &HttpResponse) -> Self {
+        let body = serde_json::from_slice::<serde_json::Value>(&response.body).ok();
+
+        Self {
+            method: match request.method {
+                HttpMethod::Get => "GET".into(),
+                HttpMethod::Post => "POST".into(),
+            },
+            url: redact_url(&request.url),
+            request_body: request
+                .body
+                .as_deref()
+                .map(|body| redact_url(&String::from_utf8_lossy(body))),
+            status: response.status,
+            headers: response.headers.clone(),
+            raw_body: match body {
+                Some(_) => None,
+                None => Some(String::from_utf8_lossy(&response.body).into_owned()),
+            },
+            body,
+        }
+    }
+
+    fn response(self) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            headers: self.headers,
+            body: match (self.body, self.raw_body) {
+                (Some(body), _) => body.to_string().into_bytes(),
+                (None, raw_body) => raw_body.unwrap_or_default().into_bytes(),
+            },
+        }
+    }
+}
+
+pub fn fixture_key(request: &HttpRequest) -> String {
+    let mut params = request_params(request)
+        .into_iter()
+        .filter(|(key, _)| key != "apikey")
+        .collect::<Vec<_>>();
+
+    params.sort();
+
+    let param = |name: &str| {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .unwrap_or("unknown")
+    };
+
+    let canonical = format!(
+        "{}?{}",
+        request.url.split('?').next().unwrap_or_default(),
+        params
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("&")
+    );
+
+    let hash = keccak256(canonical.as_bytes());
+
+    format!(
+        "{}-{}-{}",
+        param("module"),
+        param("action"),
+        hash.as_bytes()[..8]
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>()
+    )
+}
+
+fn fixture_path(dir: &Path, request: &HttpRequest) -> PathBuf {
+    dir.join(format!("{}.json", fixture_key(request)))
+}
+
+#[derive(Clone)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    dir: PathBuf,
+}
+
+impl RecordingTransport {
+    #[inline]
+    pub fn new<T: Transport + 'static, P: Into<PathBuf>>(inner: T, dir: P) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            dir: dir.into(),
+        }
+    }
+
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>> {
+        Box::pin(async move {
+            let path = fixture_path(&self.dir, &request);
+            let response = self.inner.send(request.clone()).await?;
+
+            fs::create_dir_all(&self.dir)?;
+            fs::write(
+                path,
+                serde_json::to_string_pretty(&Fixture::new(&request, &response))?,
+            )?;
+
+            Ok(response)
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ReplayTransport {
+    dir: PathBuf,
+}
+
+impl ReplayTransport {
+    #[inline]
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<HttpResponse, Box<dyn Error + Send + Sync>>> {
+        let path = fixture_path(&self.dir, &request);
+
+        Box::pin(async move {
+            let source = fs::read_to_string(&path).map_err(|e| {
+                format!(
+                    "no recorded fixture {} for {}: {e}",
+                    path.display(),
+                    redact_url(&request.url)
+                )
+            })?;
+
+            Ok(serde_json::from_str::<Fixture>(&source)?.response())
+        })
+    }
+}
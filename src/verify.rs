@@ -0,0 +1,469 @@
+use {
+    crate::{
+        record::address, retry, EtherscanClient, EtherscanError, EtherscanRequest,
+        EtherscanResponse,
+    },
+    ethabi::ethereum_types::U256,
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::BTreeMap,
+        error::Error,
+        fs,
+        path::Path,
+        time::{Duration, Instant},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StandardJson {
+    pub language: String,
+    pub sources: BTreeMap<String, String>,
+    pub optimization_runs: Option<u32>,
+    pub evm_version: Option<String>,
+    pub via_ir: bool,
+    pub remappings: Vec<String>,
+    pub libraries: BTreeMap<String, BTreeMap<String, U256>>,
+}
+
+impl Default for StandardJson {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            language: "Solidity".to_string(),
+            sources: BTreeMap::new(),
+            optimization_runs: None,
+            evm_version: None,
+            via_ir: false,
+            remappings: Vec::new(),
+            libraries: BTreeMap::new(),
+        }
+    }
+}
+
+impl StandardJson {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_language(mut self, language: String) -> Self {
+        self.language = language;
+        self
+    }
+
+    #[inline]
+    pub fn with_source(mut self, path: String, content: String) -> Self {
+        self.sources.insert(path, content);
+        self
+    }
+
+    pub fn with_file<P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        root: P,
+        path: Q,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(root.as_ref().join(path))
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+
+        let name = path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        Ok(self.with_source(name, content))
+    }
+
+    #[inline]
+    pub fn with_optimization_runs(mut self, runs: u32) -> Self {
+        self.optimization_runs = Some(runs);
+        self
+    }
+
+    #[inline]
+    pub fn with_evm_version(mut self, evm_version: String) -> Self {
+        self.evm_version = Some(evm_version);
+        self
+    }
+
+    #[inline]
+    pub fn with_via_ir(mut self, via_ir: bool) -> Self {
+        self.via_ir = via_ir;
+        self
+    }
+
+    #[inline]
+    pub fn with_remapping(mut self, remapping: String) -> Self {
+        self.remappings.push(remapping);
+        self
+    }
+
+    #[inline]
+    pub fn with_library(mut self, file: String, name: String, address: U256) -> Self {
+        self.libraries
+            .entry(file)
+            .or_default()
+            .insert(name, address);
+        self
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let sources = self
+            .sources
+            .iter()
+            .map(|(path, content)| (path.clone(), serde_json::json!({ "content": content })))
+            .collect::<serde_json::Map<_, _>>();
+
+        let libraries = self
+            .libraries
+            .iter()
+            .map(|(file, libraries)| {
+                let libraries = libraries
+                    .iter()
+                    .map(|(name, library)| (name.clone(), address(*library).into()))
+                    .collect::<serde_json::Map<_, _>>();
+
+                (file.clone(), serde_json::Value::Object(libraries))
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        let mut settings = serde_json::json!({
+            "optimizer": {
+                "enabled": self.optimization_runs.is_some(),
+                "runs": self.optimization_runs.unwrap_or(200),
+            },
+            "outputSelection": {
+                "*": {
+                    "*": ["abi", "evm.bytecode", "evm.deployedBytecode", "metadata"],
+                },
+            },
+        });
+
+        if let Some(evm_version) = &self.evm_version {
+            settings["evmVersion"] = evm_version.clone().into();
+        }
+
+        if self.via_ir {
+            settings["viaIR"] = true.into();
+        }
+
+        if !self.remappings.is_empty() {
+            settings["remappings"] = self.remappings.clone().into();
+        }
+
+        if !libraries.is_empty() {
+            settings["libraries"] = serde_json::Value::Object(libraries);
+        }
+
+        serde_json::json!({
+            "language": self.language,
+            "sources": sources,
+            "settings": settings,
+        })
+    }
+
+    #[inline]
+    pub fn to_json_string(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(serde_json::to_string(&self.to_json())?)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum CodeFormat {
+    #[default]
+    #[serde(rename = "solidity-single-file")]
+    SoliditySingleFile,
+    #[serde(rename = "solidity-standard-json-input")]
+    SolidityStandardJsonInput,
+    #[serde(rename = "vyper-json")]
+    VyperJson,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VerificationRequest {
+    pub contract_address: U256,
+    pub source_code: String,
+    pub code_format: CodeFormat,
+    pub contract_name: String,
+    pub compiler_version: String,
+    pub optimization_runs: Option<u32>,
+    pub constructor_arguments: Option<String>,
+    pub evm_version: Option<String>,
+    pub license_type: Option<u32>,
+}
+
+impl VerificationRequest {
+    #[inline]
+    pub fn new(
+        contract_address: U256,
+        source_code: String,
+        contract_name: String,
+        compiler_version: String,
+    ) -> Self {
+        Self {
+            contract_address,
+            source_code,
+            contract_name,
+            compiler_version,
+            ..Default::default()
+        }
+    }
+
+    pub fn standard_json(
+        contract_address: U256,
+        input: &StandardJson,
+        contract_name: String,
+        compiler_version: String,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self {
+            contract_address,
+            source_code: input.to_json_string()?,
+            code_format: CodeFormat::SolidityStandardJsonInput,
+            contract_name,
+            compiler_version,
+            optimization_runs: input.optimization_runs,
+            evm_version: input.evm_version.clone(),
+            ..Default::default()
+        })
+    }
+
+    #[inline]
+    pub fn with_code_format(mut self, code_format: CodeFormat) -> Self {
+        self.code_format = code_format;
+        self
+    }
+
+    #[inline]
+    pub fn with_optimization_runs(mut self, runs: u32) -> Self {
+        self.optimization_runs = Some(runs);
+        self
+    }
+
+    #[inline]
+    pub fn with_constructor_arguments(mut self, constructor_arguments: String) -> Self {
+        self.constructor_arguments = Some(constructor_arguments);
+        self
+    }
+
+    #[inline]
+    pub fn with_evm_version(mut self, evm_version: String) -> Self {
+        self.evm_version = Some(evm_version);
+        self
+    }
+
+    #[inline]
+    pub fn with_license_type(mut self, license_type: u32) -> Self {
+        self.license_type = Some(license_type);
+        self
+    }
+
+    fn form(&self) -> Result<Vec<(String, String)>, Box<dyn Error + Send + Sync>> {
+        let mut form = vec![
+            ("contractaddress", address(self.contract_address)),
+            ("sourceCode", self.source_code.clone()),
+            ("codeformat", serde_plain::to_string(&self.code_format)?),
+            ("contractname", self.contract_name.clone()),
+            ("compilerversion", self.compiler_version.clone()),
+        ];
+
+        if self.code_format == CodeFormat::SoliditySingleFile {
+            form.push((
+                "optimizationUsed",
+                if self.optimization_runs.is_some() {
+                    "1"
+                } else {
+                    "0"
+                }
+                .to_string(),
+            ));
+            form.push(("runs", self.optimization_runs.unwrap_or(200).to_string()));
+
+            if let Some(evm_version) = &self.evm_version {
+                form.push(("evmversion", evm_version.clone()));
+            }
+        }
+
+        if let Some(constructor_arguments) = &self.constructor_arguments {
+            form.push((
+                "constructorArguements",
+                constructor_arguments.trim_start_matches("0x").to_string(),
+            ));
+        }
+
+        if let Some(license_type) = self.license_type {
+            form.push(("licenseType", license_type.to_string()));
+        }
+
+        Ok(form
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerificationStatus {
+    Pending,
+    Verified,
+    AlreadyVerified,
+    Failed(String),
+}
+
+impl VerificationStatus {
+    fn parse(result: &str) -> Self {
+        let lower = result.to_lowercase();
+
+        if lower.contains("already verified") {
+            Self::AlreadyVerified
+        } else if lower.starts_with("pass") {
+            Self::Verified
+        } else if lower.contains("pending") || lower.contains("in queue") {
+            Self::Pending
+        } else {
+            Self::Failed(result.trim_start_matches("Fail - ").to_string())
+        }
+    }
+
+    fn is_rejection(result: &str) -> bool {
+        const REJECTIONS: &[&str] = &[
+            "fail - ",
+            "unable to locate contractcode",
+            "unable to verify",
+            "unable to find matching",
+            "invalid constructor arguments",
+            "invalid compiler",
+            "compiler version",
+            "bytecode",
+        ];
+
+        let lower = result.to_lowercase();
+        REJECTIONS.iter().any(|rejection| lower.contains(rejection))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerificationOutcome {
+    Verified {
+        guid: String,
+    },
+    AlreadyVerified,
+    Failed {
+        guid: Option<String>,
+        reason: String,
+    },
+}
+
+impl VerificationOutcome {
+    #[inline]
+    pub fn is_verified(&self) -> bool {
+        !matches!(self, Self::Failed { .. })
+    }
+}
+
+impl EtherscanClient {
+    pub async fn submit_verification(
+        &self,
+        request: &VerificationRequest,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let response = self
+            .execute(EtherscanRequest::contract_verify_source_code().with_form(request.form()?))
+            .await?
+            .error_for_status()?;
+
+        serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body)?
+            .into_result()
+    }
+
+    pub async fn verification_status(
+        &self,
+        guid: &str,
+    ) -> Result<VerificationStatus, Box<dyn Error + Send + Sync>> {
+        let response = self
+            .execute(EtherscanRequest::contract_check_verify_status(
+                guid.to_string(),
+            ))
+            .await?
+            .error_for_status()?;
+
+        let envelope =
+            serde_json::from_slice::<EtherscanResponse<serde_json::Value>>(&response.body)?;
+
+        match envelope.result {
+            serde_json::Value::String(result) => Ok(VerificationStatus::parse(&result)),
+            result => Err(Box::new(EtherscanError::Api {
+                message: envelope.message,
+                result: result.to_string(),
+            })),
+        }
+    }
+
+    pub async fn verify_and_wait(
+        &self,
+        request: &VerificationRequest,
+        timeout: Option<Duration>,
+    ) -> Result<VerificationOutcome, Box<dyn Error + Send + Sync>> {
+        const INITIAL_DELAY: Duration = Duration::from_secs(2);
+        const MAX_DELAY: Duration = Duration::from_secs(30);
+
+        let guid = match self.submit_verification(request).await {
+            Ok(guid) => guid,
+            Err(e) => {
+                return match EtherscanError::find(&*e) {
+                    Some(EtherscanError::Api { result, .. }) => {
+                        match VerificationStatus::parse(result) {
+                            VerificationStatus::AlreadyVerified => {
+                                Ok(VerificationOutcome::AlreadyVerified)
+                            }
+                            _ if VerificationStatus::is_rejection(result) => {
+                                Ok(VerificationOutcome::Failed {
+                                    guid: None,
+                                    reason: result.clone(),
+                                })
+                            }
+                            _ => Err(e),
+                        }
+                    }
+                    _ => Err(e),
+                };
+            }
+        };
+
+        let started = Instant::now();
+        let mut delay = INITIAL_DELAY;
+
+        loop {
+            let elapsed = started.elapsed();
+
+            if let Some(timeout) = timeout {
+                if elapsed >= timeout {
+                    return Err(Box::new(EtherscanError::Timeout { elapsed }));
+                }
+            }
+
+            retry::sleep(
+                match timeout {
+                    Some(timeout) => delay.min(timeout - elapsed),
+                    None => delay,
+                },
+                self.cancellation(),
+            )
+            .await?;
+
+            match self.verification_status(&guid).await? {
+                VerificationStatus::Pending => delay = (delay * 2).min(MAX_DELAY),
+                VerificationStatus::Verified => return Ok(VerificationOutcome::Verified { guid }),
+                VerificationStatus::AlreadyVerified => {
+                    return Ok(VerificationOutcome::AlreadyVerified)
+                }
+                VerificationStatus::Failed(reason) => {
+                    return Ok(VerificationOutcome::Failed {
+                        guid: Some(guid),
+                        reason,
+                    })
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,585 @@
+use {
+    crate::{
+        publish, retry, CancellationToken, Cursor, EtherscanClient, EtherscanRequest,
+        EtherscanSort, HistoryEvent, NotificationSink, Paginated, PublishReport, SeenFilter,
+        TokenTransfer, Transaction, DEFAULT_SEEN_CAPACITY,
+    },
+    ethabi::ethereum_types::U256,
+    futures::{stream, Stream, TryStreamExt},
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    std::{
+        collections::{HashMap, HashSet, VecDeque},
+        error::Error,
+        time::Duration,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum WatchEvent {
+    Added(HistoryEvent),
+    Amended {
+        previous: Box<HistoryEvent>,
+        current: HistoryEvent,
+    },
+    Retracted(HistoryEvent),
+}
+
+#[derive(Clone, Debug)]
+pub struct AddressWatcher {
+    client: EtherscanClient,
+    address: U256,
+    interval: Duration,
+    startblock: Option<U256>,
+    transactions: bool,
+    token_transfers: bool,
+    offset: u64,
+    min_confirmations: u64,
+    recheck_window: u64,
+    dedup_capacity: usize,
+    shutdown: Option<CancellationToken>,
+}
+
+struct WatchState {
+    watcher: AddressWatcher,
+    next_block: Option<U256>,
+    head: U256,
+    emitted: HashMap<String, HistoryEvent>,
+    seen: SeenFilter<String>,
+    pending: VecDeque<WatchEvent>,
+    polled: bool,
+}
+
+impl AddressWatcher {
+    #[inline]
+    pub fn new(client: EtherscanClient, address: U256) -> Self {
+        Self {
+            client,
+            address,
+            interval: Duration::from_secs(15),
+            startblock: None,
+            transactions: true,
+            token_transfers: true,
+            offset: 1_000,
+            min_confirmations: 0,
+            recheck_window: 0,
+            dedup_capacity: DEFAULT_SEEN_CAPACITY,
+            shutdown: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[inline]
+    pub fn with_startblock(mut self, startblock: U256) -> Self {
+        self.startblock = Some(startblock);
+        self
+    }
+
+    #[inline]
+    pub fn with_transactions(mut self, transactions: bool) -> Self {
+        self.transactions = transactions;
+        self
+    }
+
+    #[inline]
+    pub fn with_token_transfers(mut self, token_transfers: bool) -> Self {
+        self.token_transfers = token_transfers;
+        self
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn with_min_confirmations(mut self, min_confirmations: u64) -> Self {
+        self.min_confirmations = min_confirmations;
+        self
+    }
+
+    #[inline]
+    pub fn with_recheck_window(mut self, blocks: u64) -> Self {
+        self.recheck_window = blocks;
+        self
+    }
+
+    #[inline]
+    pub fn with_dedup_capacity(mut self, capacity: usize) -> Self {
+        self.dedup_capacity = capacity;
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn address(&self) -> U256 {
+        self.address
+    }
+
+    #[inline]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    #[inline]
+    pub async fn publish<K: NotificationSink<WatchEvent> + ?Sized>(
+        self,
+        sink: &K,
+    ) -> Result<PublishReport, Box<dyn Error + Send + Sync>> {
+        publish(self.stream(), sink).await
+    }
+
+    pub fn stream(
+        self,
+    ) -> impl Stream<Item = Result<WatchEvent, Box<dyn Error + Send + Sync>>> + Send + 'static {
+        let state = WatchState {
+            next_block: self.startblock,
+            seen: SeenFilter::new(self.dedup_capacity),
+            watcher: self,
+            head: U256::zero(),
+            emitted: HashMap::new(),
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
+                }
+
+                if state.polled {
+                    let cancellation = state.watcher.client.cancellation();
+
+                    let shutdown = state.watcher.shutdown.as_ref();
+
+                    if retry::idle(state.watcher.interval, cancellation, shutdown)
+                        .await
+                        .is_err()
+                    {
+                        return None;
+                    }
+                }
+
+                state.polled = true;
+
+                if let Err(e) = state.poll().await {
+                    return Some((Err(e), state));
+                }
+            }
+        })
+    }
+
+    fn transaction_request(&self) -> EtherscanRequest {
+        EtherscanRequest::account_tx_list(self.address, None, None, None, None, None)
+    }
+
+    fn token_transfer_request(&self) -> EtherscanRequest {
+        EtherscanRequest::account_token_tx(None, Some(self.address), None, None, None, None, None)
+    }
+
+    async fn latest<T>(
+        &self,
+        mut request: EtherscanRequest,
+    ) -> Result<Vec<HistoryEvent>, Box<dyn Error + Send + Sync>>
+    where
+        T: DeserializeOwned + IntoEvent,
+    {
+        request.page = Some(U256::one());
+        request.offset = Some(U256::one());
+        request.sort = Some(EtherscanSort::Descending);
+
+        let items = self.client.fetch::<Vec<T>>(request).await?;
+        Ok(items.into_iter().map(IntoEvent::into_event).collect())
+    }
+
+    async fn since<T>(
+        &self,
+        request: EtherscanRequest,
+        block: U256,
+    ) -> Result<Vec<HistoryEvent>, Box<dyn Error + Send + Sync>>
+    where
+        T: DeserializeOwned + IntoEvent + Paginated + Send + 'static,
+    {
+        self.client
+            .paginate_from::<T>(request, self.offset, Cursor::new(block))
+            .map_ok(|page| stream::iter(page.items.into_iter().map(IntoEvent::into_event).map(Ok)))
+            .try_flatten()
+            .try_collect()
+            .await
+    }
+}
+
+trait IntoEvent {
+    fn into_event(self) -> HistoryEvent;
+}
+
+impl IntoEvent for Transaction {
+    #[inline]
+    fn into_event(self) -> HistoryEvent {
+        HistoryEvent::Transaction(self)
+    }
+}
+
+impl IntoEvent for TokenTransfer {
+    #[inline]
+    fn into_event(self) -> HistoryEvent {
+        HistoryEvent::Erc20(self)
+    }
+}
+
+fn transfer_identity(transfer: &TokenTransfer) -> String {
+    transfer_identity_of("transfer", transfer)
+}
+
+fn transfer_identity_of(kind: &str, transfer: &TokenTransfer) -> String {
+    format!(
+        "{kind}:{:x}:{:x}:{:x}:{:x}:{}:{}",
+        transfer.hash,
+        transfer.contract_address,
+        transfer.from,
+        transfer.to.unwrap_or_default(),
+        transfer.value,
+        transfer.token_id.as_deref().unwrap_or_default(),
+    )
+}
+
+fn identity(event: &HistoryEvent) -> String {
+    match event {
+        HistoryEvent::Transaction(tx) => format!("tx:{:x}", tx.hash),
+        HistoryEvent::Internal(tx) => format!(
+            "internal:{:x}:{}:{:x}:{:x}:{}",
+            tx.hash,
+            tx.trace_id,
+            tx.from,
+            tx.to.unwrap_or_default(),
+            tx.value,
+        ),
+        HistoryEvent::Erc20(transfer) => transfer_identity_of("erc20", transfer),
+        HistoryEvent::Erc721(transfer) => transfer_identity_of("erc721", transfer),
+        HistoryEvent::Erc1155(transfer) => transfer_identity_of("erc1155", transfer),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenTransferWatcher {
+    client: EtherscanClient,
+    contract: U256,
+    interval: Duration,
+    startblock: Option<U256>,
+    offset: u64,
+    dedup_capacity: usize,
+    shutdown: Option<CancellationToken>,
+}
+
+struct TokenTransferState {
+    watcher: TokenTransferWatcher,
+    next_block: Option<U256>,
+    seen: SeenFilter<String>,
+    pending: VecDeque<TokenTransfer>,
+    polled: bool,
+}
+
+impl TokenTransferWatcher {
+    #[inline]
+    pub fn new(client: EtherscanClient, contract: U256) -> Self {
+        Self {
+            client,
+            contract,
+            interval: Duration::from_secs(15),
+            startblock: None,
+            offset: 1_000,
+            dedup_capacity: DEFAULT_SEEN_CAPACITY,
+            shutdown: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[inline]
+    pub fn with_startblock(mut self, startblock: U256) -> Self {
+        self.startblock = Some(startblock);
+        self
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn with_dedup_capacity(mut self, capacity: usize) -> Self {
+        self.dedup_capacity = capacity;
+        self
+    }
+
+    #[inline]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    #[inline]
+    pub fn contract(&self) -> U256 {
+        self.contract
+    }
+
+    #[inline]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    #[inline]
+    pub async fn publish<K: NotificationSink<TokenTransfer> + ?Sized>(
+        self,
+        sink: &K,
+    ) -> Result<PublishReport, Box<dyn Error + Send + Sync>> {
+        publish(self.stream(), sink).await
+    }
+
+    pub fn stream(
+        self,
+    ) -> impl Stream<Item = Result<TokenTransfer, Box<dyn Error + Send + Sync>>> + Send + 'static
+    {
+        let state = TokenTransferState {
+            next_block: self.startblock,
+            seen: SeenFilter::new(self.dedup_capacity),
+            watcher: self,
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(transfer) = state.pending.pop_front() {
+                    return Some((Ok(transfer), state));
+                }
+
+                if state.polled {
+                    let cancellation = state.watcher.client.cancellation();
+
+                    let shutdown = state.watcher.shutdown.as_ref();
+
+                    if retry::idle(state.watcher.interval, cancellation, shutdown)
+                        .await
+                        .is_err()
+                    {
+                        return None;
+                    }
+                }
+
+                state.polled = true;
+
+                if let Err(e) = state.poll().await {
+                    return Some((Err(e), state));
+                }
+            }
+        })
+    }
+
+    fn request(&self) -> EtherscanRequest {
+        EtherscanRequest::account_token_tx(Some(self.contract), None, None, None, None, None, None)
+    }
+}
+
+impl TokenTransferState {
+    async fn poll(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let watcher = &self.watcher;
+
+        let Some(next_block) = self.next_block else {
+            let mut request = watcher.request();
+            request.page = Some(U256::one());
+            request.offset = Some(U256::one());
+            request.sort = Some(EtherscanSort::Descending);
+
+            let latest = watcher.client.fetch::<Vec<TokenTransfer>>(request).await?;
+
+            for transfer in &latest {
+                self.seen.insert(transfer_identity(transfer));
+            }
+
+            self.next_block = Some(
+                latest
+                    .iter()
+                    .map(|transfer| transfer.block_number)
+                    .max()
+                    .unwrap_or_default(),
+            );
+            return Ok(());
+        };
+
+        let transfers: Vec<TokenTransfer> = watcher
+            .client
+            .paginate_from::<TokenTransfer>(
+                watcher.request(),
+                watcher.offset,
+                Cursor::new(next_block),
+            )
+            .map_ok(|page| page.items)
+            .try_concat()
+            .await?;
+
+        let mut latest = next_block;
+
+        for transfer in transfers {
+            latest = latest.max(transfer.block_number);
+
+            if self.seen.insert(transfer_identity(&transfer)) {
+                self.pending.push_back(transfer);
+            }
+        }
+
+        self.next_block = Some(latest);
+        Ok(())
+    }
+}
+
+impl WatchState {
+    fn observe_head(&mut self, event: &HistoryEvent) {
+        let confirmations = event.confirmations().unwrap_or_default();
+        let head = (event.block_number() + confirmations).saturating_sub(U256::one());
+        self.head = self.head.max(head).max(event.block_number());
+    }
+
+    fn confirmations(&self, event: &HistoryEvent) -> U256 {
+        (self.head + U256::one()).saturating_sub(event.block_number())
+    }
+
+    async fn poll(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let watcher = self.watcher.clone();
+        let mut events = Vec::new();
+
+        let Some(next_block) = self.next_block else {
+            if watcher.transactions {
+                events.extend(
+                    watcher
+                        .latest::<Transaction>(watcher.transaction_request())
+                        .await?,
+                );
+            }
+
+            if watcher.token_transfers {
+                events.extend(
+                    watcher
+                        .latest::<TokenTransfer>(watcher.token_transfer_request())
+                        .await?,
+                );
+            }
+
+            let latest = events
+                .iter()
+                .map(HistoryEvent::block_number)
+                .max()
+                .unwrap_or_default();
+
+            for event in events {
+                self.observe_head(&event);
+                self.seen.insert(identity(&event));
+                self.emitted.insert(identity(&event), event);
+            }
+
+            self.next_block = Some(latest);
+            return Ok(());
+        };
+
+        let depth = watcher.recheck_window.max(watcher.min_confirmations);
+        let start = next_block.min(self.head.saturating_sub(U256::from(depth)));
+
+        if watcher.transactions {
+            events.extend(
+                watcher
+                    .since::<Transaction>(watcher.transaction_request(), start)
+                    .await?,
+            );
+        }
+
+        if watcher.token_transfers {
+            events.extend(
+                watcher
+                    .since::<TokenTransfer>(watcher.token_transfer_request(), start)
+                    .await?,
+            );
+        }
+
+        events.sort_by_key(HistoryEvent::block_number);
+
+        for event in &events {
+            self.observe_head(event);
+        }
+
+        let min_confirmations = U256::from(watcher.min_confirmations);
+        let mut observed = HashSet::new();
+        let mut latest = next_block;
+
+        for event in events {
+            let id = identity(&event);
+
+            if !observed.insert(id.clone()) {
+                continue;
+            }
+
+            match self.emitted.get(&id) {
+                Some(previous)
+                    if previous.block_number() != event.block_number()
+                        || previous.block_hash() != event.block_hash() =>
+                {
+                    self.pending.push_back(WatchEvent::Amended {
+                        previous: Box::new(previous.clone()),
+                        current: event.clone(),
+                    });
+                    self.emitted.insert(id, event);
+                }
+                Some(_) => {}
+                None if self.confirmations(&event) >= min_confirmations => {
+                    latest = latest.max(event.block_number());
+
+                    if self.seen.insert(id.clone()) {
+                        self.pending.push_back(WatchEvent::Added(event.clone()));
+                    }
+
+                    self.emitted.insert(id, event);
+                }
+                None => {}
+            }
+        }
+
+        let retracted = self
+            .emitted
+            .iter()
+            .filter(|(id, event)| event.block_number() >= start && !observed.contains(*id))
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+
+        for id in retracted {
+            self.seen.remove(&id);
+
+            if let Some(event) = self.emitted.remove(&id) {
+                self.pending.push_back(WatchEvent::Retracted(event));
+            }
+        }
+
+        let horizon = latest
+            .min(self.head.saturating_sub(U256::from(depth)))
+            .saturating_sub(U256::one());
+        self.emitted
+            .retain(|_, event| event.block_number() >= horizon);
+
+        self.next_block = Some(latest);
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use {
+    crate::{EtherscanClient, HistoryEvent, TokenTransfer, WatchEvent},
+    ethabi::ethereum_types::U256,
+    futures::{Stream, TryStreamExt},
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        error::Error,
+        sync::{Arc, Mutex},
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum WhaleThreshold {
+    Amount(U256),
+    SupplyShare(f64),
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WhaleAlert {
+    pub transfer: TokenTransfer,
+    pub threshold: WhaleThreshold,
+    pub total_supply: Option<U256>,
+    pub share: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct WhaleDetector {
+    client: EtherscanClient,
+    threshold: Option<WhaleThreshold>,
+    token_thresholds: HashMap<U256, WhaleThreshold>,
+    supplies: Arc<Mutex<HashMap<U256, U256>>>,
+}
+
+fn to_f64(value: U256) -> f64 {
+    value.to_string().parse::<f64>().unwrap_or(f64::MAX)
+}
+
+impl WhaleDetector {
+    #[inline]
+    pub fn new(client: EtherscanClient) -> Self {
+        Self {
+            client,
+            threshold: None,
+            token_thresholds: HashMap::new(),
+            supplies: Arc::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_threshold(mut self, threshold: WhaleThreshold) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    #[inline]
+    pub fn with_token_threshold(
+        mut self,
+        contract_address: U256,
+        threshold: WhaleThreshold,
+    ) -> Self {
+        self.token_thresholds.insert(contract_address, threshold);
+        self
+    }
+
+    async fn total_supply(
+        &self,
+        contract_address: U256,
+    ) -> Result<U256, Box<dyn Error + Send + Sync>> {
+        if let Some(supply) = self.supplies.lock().unwrap().get(&contract_address) {
+            return Ok(*supply);
+        }
+
+        let supply = self.client.token_supply(contract_address).await?;
+
+        self.supplies
+            .lock()
+            .unwrap()
+            .insert(contract_address, supply);
+
+        Ok(supply)
+    }
+
+    pub async fn check(
+        &self,
+        transfer: &TokenTransfer,
+    ) -> Result<Option<WhaleAlert>, Box<dyn Error + Send + Sync>> {
+        let Some(threshold) = self
+            .token_thresholds
+            .get(&transfer.contract_address)
+            .copied()
+            .or(self.threshold)
+        else {
+            return Ok(None);
+        };
+
+        let (exceeded, total_supply, share) = match threshold {
+            WhaleThreshold::Amount(amount) => (transfer.value >= amount, None, None),
+            WhaleThreshold::SupplyShare(min_share) => {
+                let supply = self.total_supply(transfer.contract_address).await?;
+
+                if supply.is_zero() {
+                    (false, Some(supply), None)
+                } else {
+                    let share = to_f64(transfer.value) / to_f64(supply);
+                    (share >= min_share, Some(supply), Some(share))
+                }
+            }
+        };
+
+        Ok(exceeded.then(|| WhaleAlert {
+            transfer: transfer.clone(),
+            threshold,
+            total_supply,
+            share,
+        }))
+    }
+
+    pub fn detect<S>(
+        &self,
+        events: S,
+    ) -> impl Stream<Item = Result<WhaleAlert, Box<dyn Error + Send + Sync>>> + Send + 'static
+    where
+        S: Stream<Item = Result<WatchEvent, Box<dyn Error + Send + Sync>>> + Send + 'static,
+    {
+        let detector = self.clone();
+
+        events.try_filter_map(move |event| {
+            let detector = detector.clone();
+
+            async move {
+                match event {
+                    WatchEvent::Added(HistoryEvent::Erc20(transfer)) => {
+                        detector.check(&transfer).await
+                    }
+                    _ => Ok(None),
+                }
+            }
+        })
+    }
+}
+
+impl EtherscanClient {
+    #[inline]
+    pub fn whale_detector(&self) -> WhaleDetector {
+        WhaleDetector::new(self.clone())
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    etherscan::{request_params, EtherscanClient, EtherscanRequest, MockTransport, StaticKey},
+    futures::executor::block_on,
+    serde_json::json,
+};
+
+#[test]
+fn request_key_overrides_client_and_provider_keys() {
+    let transport = MockTransport::new().with_result("proxy", "eth_blockNumber", json!("0x1"));
+
+    let client = EtherscanClient::default()
+        .with_transport(transport.clone())
+        .with_apikey("client".to_string())
+        .with_key_provider(StaticKey::new("provider"));
+
+    block_on(client.execute(EtherscanRequest::proxy_block_number().with_apikey("override")))
+        .unwrap();
+    block_on(client.execute(EtherscanRequest::proxy_block_number())).unwrap();
+
+    let keys = transport
+        .requests()
+        .iter()
+        .map(|request| {
+            request_params(request)
+                .into_iter()
+                .find(|(name, _)| name == "apikey")
+                .map(|(_, apikey)| apikey)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        keys,
+        [Some("override".to_string()), Some("provider".to_string())]
+    );
+}
@@ -0,0 +1,37 @@
+use {
+    etherscan::{EtherscanClient, EtherscanError, EtherscanRequest, HttpRequest, MockTransport},
+    futures::executor::block_on,
+    serde_json::json,
+    std::{
+        error::Error,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    },
+};
+
+#[test]
+fn vetoed_request_is_not_sent_or_retried() {
+    let transport = MockTransport::new().with_result("proxy", "eth_blockNumber", json!("0x1"));
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let client = EtherscanClient::default()
+        .with_transport(transport.clone())
+        .with_request_middleware({
+            let calls = calls.clone();
+            move |_: &mut HttpRequest| -> Result<(), Box<dyn Error + Send + Sync>> {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err("blocked".into())
+            }
+        });
+
+    let error = block_on(client.execute(EtherscanRequest::proxy_block_number())).unwrap_err();
+
+    assert!(matches!(
+        EtherscanError::find(error.as_ref()),
+        Some(EtherscanError::Vetoed { reason }) if reason == "blocked"
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(transport.requests().is_empty());
+}
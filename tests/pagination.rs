@@ -0,0 +1,61 @@
+use {
+    ethabi::ethereum_types::U256,
+    etherscan::{BeaconWithdrawal, EtherscanClient, EtherscanRequest, MockRoute, MockTransport},
+    futures::{executor::block_on, StreamExt},
+    serde_json::json,
+};
+
+fn withdrawal(index: u64) -> serde_json::Value {
+    json!({
+        "withdrawalIndex": index.to_string(),
+        "validatorIndex": "1",
+        "address": "0x0000000000000000000000000000000000000001",
+        "amount": "1",
+        "blockNumber": (100 + index).to_string(),
+        "timestamp": "1",
+    })
+}
+
+fn requested_pages(transport: &MockTransport) -> Vec<String> {
+    transport
+        .request_params()
+        .into_iter()
+        .filter_map(|params| {
+            params
+                .into_iter()
+                .find(|(name, _)| name == "page")
+                .map(|(_, page)| page)
+        })
+        .collect()
+}
+
+#[test]
+fn next_page_is_requested_before_current_page_is_consumed() {
+    let transport = MockTransport::new()
+        .with_route(
+            MockRoute::new("account", "txsBeaconWithdrawal")
+                .with_param("page", 1)
+                .with_result(json!([withdrawal(1), withdrawal(2)])),
+        )
+        .with_route(
+            MockRoute::new("account", "txsBeaconWithdrawal")
+                .with_param("page", 2)
+                .with_result(json!([withdrawal(3)])),
+        );
+
+    let client = EtherscanClient::default().with_transport(transport.clone());
+    let request =
+        EtherscanRequest::account_txs_beacon_withdrawal(U256::one(), None, None, None, None, None);
+
+    let mut pages = client.paginate_prefetched::<BeaconWithdrawal>(request, 2, 2);
+
+    let first = block_on(pages.next()).unwrap().unwrap();
+
+    assert_eq!(first.items.len(), 2);
+    assert!(requested_pages(&transport).contains(&"2".to_string()));
+
+    let second = block_on(pages.next()).unwrap().unwrap();
+
+    assert_eq!(second.items.len(), 1);
+    assert!(block_on(pages.next()).is_none());
+}